@@ -101,10 +101,7 @@ impl<E: Environment, I0: IntegerType, I1: IntegerType> Cast<Integer<E, I1>> for
             (true, true) => match I0::BITS <= I1::BITS {
                 // If the source type is smaller than or equal to the destination type, sign extend the source integer.
                 // Then instantiate the new integer from the bits.
-                true => {
-                    bits_le.resize(I1::BITS as usize, self.msb().clone());
-                    Integer::<E, I1>::from_bits_le(&bits_le)
-                }
+                true => Integer::<E, I1>::from_bits_le(&self.to_bits_le_sign_extended(I1::BITS as usize)),
                 // If the source type is larger than the destination type, check that the upper bits match the most significant bit.
                 // Then instantiate the new integer from the appropriate lower bits.
                 false => {
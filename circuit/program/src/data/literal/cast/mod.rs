@@ -40,7 +40,6 @@ use snarkvm_circuit_types::prelude::{
     ToField,
     ToGroup,
     Zero,
-    MSB,
 };
 
 #[cfg(test)]
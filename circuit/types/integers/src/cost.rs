@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_circuit_environment::Measurement;
+
+/// Returns the `(constants, public, private, constraints)` used by `shr_checked`, given the modes of `self` and `rhs`.
+///
+/// The counts are derived from `I::BITS` by the `Metrics` of `ShrChecked`, and are exact,
+/// except when `self` is constant and `rhs` is not, in which case they are upper bounds.
+pub fn shr_checked_cost<E: Environment, I: IntegerType, M: Magnitude>(
+    mode_a: Mode,
+    mode_b: Mode,
+) -> (usize, usize, usize, usize) {
+    let Count(constants, public, private, constraints) =
+        count!(Integer<E, I>, ShrChecked<Integer<E, M>, Output = Integer<E, I>>, &(mode_a, mode_b));
+    (upper_bound(constants), upper_bound(public), upper_bound(private), upper_bound(constraints))
+}
+
+/// Returns the largest value satisfying the given measurement.
+fn upper_bound(measurement: Measurement<u64>) -> usize {
+    match measurement {
+        Measurement::Exact(value) | Measurement::Range(_, value) | Measurement::UpperBound(value) => value as usize,
+    }
+}
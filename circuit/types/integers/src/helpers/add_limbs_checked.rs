@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the sum of the multi-limb integers `a` and `b`, and the final carry bit.
+    ///
+    /// Each multi-limb integer is given as a little-endian array of unsigned limbs
+    /// (i.e. `a[0]` is the least significant limb), and the limbs are added in order,
+    /// threading the carry bit from each limb into the next.
+    /// This method halts if `I` is signed, or if the arrays differ in length.
+    ///
+    /// For each limb pair that is not constant (or that receives a non-constant carry bit),
+    /// this method uses `I::BITS + 2` constraints (i.e. 66 constraints per pair of `u64` limbs).
+    /// For constant limbs with a constant carry bit, this method uses 0 constraints.
+    pub fn add_limbs_checked(a: &[Self], b: &[Self]) -> (Vec<Self>, Boolean<E>) {
+        // Ensure the limbs are unsigned.
+        if I::is_signed() {
+            E::halt(format!("Cannot add multi-limb integers with signed limbs of type '{}'", I::type_name()))
+        }
+        // Ensure the multi-limb integers have the same number of limbs.
+        if a.len() != b.len() {
+            E::halt(format!("Cannot add multi-limb integers with {} and {} limbs", a.len(), b.len()))
+        }
+
+        let mut carry = Boolean::constant(false);
+        let limbs = a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| {
+                // Add the limbs and the carry bit in the base field.
+                // Note: This is safe as the sum is less than 2^(I::BITS + 1), which is far smaller than the field.
+                let sum = a.to_field() + b.to_field() + Field::from_boolean(&carry);
+                // Extract the bits of the limb from the sum, with the carry bit for the next limb.
+                let mut bits_le = sum.to_lower_bits_le(I::BITS as usize + 1);
+                carry = match bits_le.pop() {
+                    Some(bit) => bit,
+                    // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                    None => E::halt("Malformed sum detected during multi-limb integer addition"),
+                };
+                Integer { bits_le, phantom: Default::default() }
+            })
+            .collect();
+        (limbs, carry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+    const MAX_NUM_LIMBS: usize = 4;
+
+    /// Returns the sum of the given multi-limb integers, and the final carry bit,
+    /// by adding their bits as a single big integer.
+    fn reference_add<I: IntegerType>(a: &[I], b: &[I]) -> (Vec<I>, bool) {
+        let a_bits = a.iter().flat_map(|limb| limb.to_bits_le()).collect::<Vec<_>>();
+        let b_bits = b.iter().flat_map(|limb| limb.to_bits_le()).collect::<Vec<_>>();
+        // Add the big integers with a ripple-carry adder.
+        let mut carry = false;
+        let mut sum_bits = Vec::with_capacity(a_bits.len());
+        for (a, b) in a_bits.into_iter().zip(b_bits) {
+            sum_bits.push(a ^ b ^ carry);
+            carry = (a & b) | (carry & (a ^ b));
+        }
+        let sum = sum_bits.chunks(I::BITS as usize).map(|bits| I::from_bits_le(bits).unwrap()).collect();
+        (sum, carry)
+    }
+
+    fn check_add_limbs<I: IntegerType>(name: &str, first: &[I], second: &[I], mode_a: Mode, mode_b: Mode) {
+        let new_limbs = |limbs: &[I], mode| {
+            limbs.iter().map(|limb| Integer::<Circuit, I>::new(mode, console::Integer::new(*limb))).collect::<Vec<_>>()
+        };
+        let (a, b) = (new_limbs(first, mode_a), new_limbs(second, mode_b));
+        let (expected, expected_carry) = reference_add(first, second);
+        Circuit::scope(name, || {
+            let (candidate, carry) = Integer::add_limbs_checked(&a, &b);
+            assert_eq!(expected, candidate.iter().map(|limb| *limb.eject_value()).collect::<Vec<_>>());
+            assert_eq!(expected_carry, carry.eject_value());
+            let num_limbs = first.len() as u64;
+            match mode_a.is_constant() && mode_b.is_constant() {
+                true => assert_scope!(num_limbs * (I::BITS + 1), 0, 0, 0),
+                false => assert_scope!(0, 0, num_limbs * (I::BITS + 1), num_limbs * (I::BITS + 2)),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        // Ensure signed limbs are not supported.
+        if I::is_signed() {
+            let a = vec![Integer::<Circuit, I>::new(mode_a, console::Integer::zero())];
+            let b = vec![Integer::<Circuit, I>::new(mode_b, console::Integer::zero())];
+            check_operation_halts(&a, &b, |a, b| Integer::add_limbs_checked(a, b));
+            Circuit::reset();
+            return;
+        }
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            for num_limbs in 0..=MAX_NUM_LIMBS {
+                let first = (0..num_limbs).map(|_| I::rand(&mut rng)).collect::<Vec<_>>();
+                let second = (0..num_limbs).map(|_| I::rand(&mut rng)).collect::<Vec<_>>();
+                let name = format!("AddLimbs: {mode_a} + {mode_b} {num_limbs} limbs {i}");
+                check_add_limbs::<I>(&name, &first, &second, mode_a, mode_b);
+            }
+        }
+
+        // Check that a carry ripples through every limb, and out of the final limb.
+        let max = vec![I::MAX; MAX_NUM_LIMBS];
+        let mut one = vec![I::zero(); MAX_NUM_LIMBS];
+        one[0] = I::one();
+        check_add_limbs::<I>("AddLimbs: MAX + 1", &max, &one, mode_a, mode_b);
+        check_add_limbs::<I>("AddLimbs: MAX + MAX", &max, &max, mode_a, mode_b);
+        let zero = vec![I::zero(); MAX_NUM_LIMBS];
+        check_add_limbs::<I>("AddLimbs: 0 + 0", &zero, &zero, mode_a, mode_b);
+
+        // Ensure arrays of different lengths halt.
+        let a = vec![Integer::<Circuit, I>::new(mode_a, console::Integer::zero()); 2];
+        let b = vec![Integer::<Circuit, I>::new(mode_b, console::Integer::zero()); 3];
+        check_operation_halts(&a, &b, |a, b| Integer::add_limbs_checked(a, b));
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, i64, add_limbs);
+
+    test_integer_binary!(run_test, u8, add_limbs);
+    test_integer_binary!(run_test, u16, add_limbs);
+    test_integer_binary!(run_test, u32, add_limbs);
+    test_integer_binary!(run_test, u64, add_limbs);
+    test_integer_binary!(run_test, u128, add_limbs);
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Asserts that the integer fits in the lower `num_bits` bits, and returns the integer.
+    ///
+    /// Every bit at or above index `num_bits` is constrained to be zero.
+    /// If `num_bits` is greater than or equal to `I::BITS`, this method is a no-op.
+    ///
+    /// For a constant integer that does not fit, this method halts.
+    /// Otherwise, this method uses `I::BITS - num_bits` constraints.
+    pub fn assert_fits_in_bits(&self, num_bits: usize) -> Self {
+        // If the bit width covers the integer, there is nothing to enforce.
+        if num_bits >= I::BITS as usize {
+            return self.clone();
+        }
+
+        // Retrieve the upper bits of the integer.
+        let upper_bits = &self.bits_le[num_bits..];
+
+        // If the integer is a constant, ensure the upper bits are zero.
+        if self.is_constant() {
+            if upper_bits.iter().any(|bit| bit.eject_value()) {
+                E::halt(format!("Constant integer does not fit in {num_bits} bits"))
+            }
+        } else {
+            // Enforce that each upper bit is zero.
+            for bit in upper_bits {
+                E::assert_eq(bit, E::zero());
+            }
+        }
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Samples a random integer whose set bits are all below `num_bits`, optionally setting one bit at or above it.
+    fn sample<I: IntegerType>(mode: Mode, num_bits: usize, overflow: bool, rng: &mut TestRng) -> Integer<Circuit, I> {
+        let mut bits_le = (0..I::BITS as usize).map(|i| i < num_bits && bool::rand(rng)).collect::<Vec<_>>();
+        if overflow {
+            let index = num_bits + (u64::rand(rng) % (I::BITS - num_bits as u64)) as usize;
+            bits_le[index] = true;
+        }
+        Integer::from_bits_le(&bits_le.into_iter().map(|bit| Boolean::new(mode, bit)).collect::<Vec<_>>())
+    }
+
+    fn check_assert_fits_in_bits<I: IntegerType + UnwindSafe>(mode: Mode, num_bits: usize) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Check an integer that fits.
+            let candidate = sample::<I>(mode, num_bits, false, &mut rng);
+            let expected = candidate.eject_value();
+
+            Circuit::scope(format!("{mode} {num_bits} {i}"), || {
+                let output = candidate.assert_fits_in_bits(num_bits);
+                assert_eq!(expected, output.eject_value());
+                match mode.is_constant() || num_bits >= I::BITS as usize {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, 0, I::BITS - num_bits as u64),
+                }
+            });
+            Circuit::reset();
+
+            // Check an integer that does not fit.
+            if num_bits < I::BITS as usize {
+                let candidate = sample::<I>(mode, num_bits, true, &mut rng);
+                match mode {
+                    Mode::Constant => {
+                        check_unary_operation_halts(candidate, |a: Integer<Circuit, I>| a.assert_fits_in_bits(num_bits))
+                    }
+                    _ => Circuit::scope(format!("{mode} {num_bits} {i} (overflow)"), || {
+                        let _output = candidate.assert_fits_in_bits(num_bits);
+                        assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                    }),
+                }
+                Circuit::reset();
+            }
+        }
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        for num_bits in [0, 1, I::BITS as usize / 2, I::BITS as usize - 1, I::BITS as usize, I::BITS as usize + 1] {
+            check_assert_fits_in_bits::<I>(mode, num_bits);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, assert_fits_in_bits);
+    test_integer_unary!(run_test, i16, assert_fits_in_bits);
+    test_integer_unary!(run_test, i32, assert_fits_in_bits);
+    test_integer_unary!(run_test, i64, assert_fits_in_bits);
+    test_integer_unary!(run_test, i128, assert_fits_in_bits);
+
+    test_integer_unary!(run_test, u8, assert_fits_in_bits);
+    test_integer_unary!(run_test, u16, assert_fits_in_bits);
+    test_integer_unary!(run_test, u32, assert_fits_in_bits);
+    test_integer_unary!(run_test, u64, assert_fits_in_bits);
+    test_integer_unary!(run_test, u128, assert_fits_in_bits);
+}
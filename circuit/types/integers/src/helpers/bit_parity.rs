@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `true` if the integer has an odd number of set bits, i.e. the XOR of all of its bits.
+    ///
+    /// Rather than XOR-ing the bits one at a time, which uses `I::BITS - 1` constraints,
+    /// this method sums the bits in the base field, and returns the least significant bit of the sum.
+    /// For a non-constant integer, this method uses `k + 1` constraints, where `k` is the bit length of `I::BITS`
+    /// (i.e. 5 constraints for 8-bit integers, up to 9 constraints for 128-bit integers).
+    /// For a constant integer, this method uses 0 constraints.
+    pub fn bit_parity(&self) -> Boolean<E> {
+        // If the integer is constant, compute the parity directly.
+        if self.is_constant() {
+            return Boolean::constant(self.eject_value().count_ones() % 2 == 1);
+        }
+
+        // Sum the bits in the base field.
+        let sum = sum_as_u32(self.bits_le.iter().map(Field::from_boolean), I::BITS);
+        match sum.bits_le.into_iter().next() {
+            // The parity is the least significant bit of the sum.
+            Some(parity) => parity,
+            // Note: `E::halt` should never be invoked as the sum has at least one bit.
+            None => E::halt("Malformed sum detected while computing the bit parity"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_bit_parity<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected parity as the XOR of the bits.
+        let expected = value.to_bits_le().into_iter().fold(false, |parity, bit| parity ^ bit);
+        assert_eq!(value.count_ones() % 2 == 1, expected);
+        Circuit::scope(name, || {
+            let candidate = a.bit_parity();
+            assert_eq!(expected, candidate.eject_value());
+            // Check the documented number of constraints, which is fewer than XOR-ing the bits.
+            match mode.is_constant() {
+                true => assert_scope!(0, 0, 0, 0),
+                false => {
+                    let num_bits = (u64::BITS - I::BITS.leading_zeros()) as u64;
+                    assert_scope!(0, 0, num_bits, num_bits + 1);
+                    assert!(num_bits + 1 < I::BITS - 1);
+                }
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("BitParity: {mode} {i}");
+            check_bit_parity::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        // Check the extremes, including the all-zero and all-one bit patterns.
+        let boundaries = [
+            console::Integer::zero(),
+            console::Integer::one(),
+            console::Integer::MIN,
+            console::Integer::MAX,
+            console::Integer::new(!I::zero()),
+        ];
+        for (i, value) in boundaries.into_iter().enumerate() {
+            check_bit_parity::<I>(&format!("BitParity: {mode} boundary {i}"), value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, bit_parity);
+    test_integer_unary!(run_test, i16, bit_parity);
+    test_integer_unary!(run_test, i32, bit_parity);
+    test_integer_unary!(run_test, i64, bit_parity);
+    test_integer_unary!(run_test, i128, bit_parity);
+
+    test_integer_unary!(run_test, u8, bit_parity);
+    test_integer_unary!(run_test, u16, bit_parity);
+    test_integer_unary!(run_test, u32, bit_parity);
+    test_integer_unary!(run_test, u64, bit_parity);
+    test_integer_unary!(run_test, u128, bit_parity);
+}
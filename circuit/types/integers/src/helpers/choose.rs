@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the bitwise choice `(a & b) ^ (!a & c)` of the three integers, as used in the `Ch` function of SHA-2.
+    /// That is, each bit of `a` chooses the corresponding bit of `b` if set, and of `c` otherwise.
+    ///
+    /// For non-constant integers, this method uses `I::BITS` constraints, compared to the `3 * I::BITS`
+    /// constraints of composing `&`, `!`, and `^`. Constant bits are folded, so constant integers use 0 constraints.
+    pub fn choose(a: &Self, b: &Self, c: &Self) -> Self {
+        let bits_le = a
+            .bits_le
+            .iter()
+            .zip_eq(&b.bits_le)
+            .zip_eq(&c.bits_le)
+            .map(|((a, b), c)| Boolean::ternary(a, b, c))
+            .collect();
+
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the reference `Ch` of SHA-2, computed on the native integers.
+    fn reference_choose<I: IntegerType>(a: I, b: I, c: I) -> I {
+        (a & b) ^ (!a & c)
+    }
+
+    fn check_choose<I: IntegerType>(name: &str, values: [I; 3], modes: [Mode; 3]) {
+        let [a, b, c] = [0, 1, 2].map(|i| Integer::<Circuit, I>::new(modes[i], console::Integer::new(values[i])));
+        let expected = reference_choose(values[0], values[1], values[2]);
+
+        let num_composed_constraints = Circuit::scope(format!("{name} (composed)"), || {
+            let candidate = (&a & &b) ^ (!&a & &c);
+            assert_eq!(expected, *candidate.eject_value());
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::scope(name, || {
+            let candidate = Integer::choose(&a, &b, &c);
+            assert_eq!(expected, *candidate.eject_value());
+            // Check the documented number of constraints, when the modes match.
+            if modes.iter().all(|mode| mode.is_constant()) {
+                assert_scope!(0, 0, 0, 0);
+            } else if modes.iter().all(|mode| !mode.is_constant()) {
+                assert_scope!(0, 0, I::BITS, I::BITS);
+            }
+            // Ensure the choice uses no more constraints than composing the individual operations.
+            assert!(Circuit::num_constraints_in_scope() <= num_composed_constraints);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let values = [0, 1, 2].map(|_| *console::Integer::<<Circuit as Environment>::Network, I>::rand(&mut rng));
+
+            // Check the choice with the same mode for every integer.
+            check_choose::<I>(&format!("Choose: {mode} {i}"), values, [mode; 3]);
+            // Check the choice with a constant chooser.
+            check_choose::<I>(&format!("Choose: {mode} constant {i}"), values, [Mode::Constant, mode, mode]);
+            // Check the choice with a constant second integer.
+            check_choose::<I>(&format!("Choose: {mode} constant second {i}"), values, [mode, mode, Mode::Constant]);
+        }
+
+        // Check the choice with a chooser of all zeros, and of all ones.
+        let [_, b, c] = [0, 1, 2].map(|_| *console::Integer::<<Circuit as Environment>::Network, I>::rand(&mut rng));
+        check_choose::<I>(&format!("Choose: {mode} zeros"), [I::zero(), b, c], [mode; 3]);
+        check_choose::<I>(&format!("Choose: {mode} ones"), [!I::zero(), b, c], [mode; 3]);
+    }
+
+    test_integer_unary!(run_test, i8, choose);
+    test_integer_unary!(run_test, i16, choose);
+    test_integer_unary!(run_test, i32, choose);
+    test_integer_unary!(run_test, i64, choose);
+    test_integer_unary!(run_test, i128, choose);
+
+    test_integer_unary!(run_test, u8, choose);
+    test_integer_unary!(run_test, u16, choose);
+    test_integer_unary!(run_test, u32, choose);
+    test_integer_unary!(run_test, u64, choose);
+    test_integer_unary!(run_test, u128, choose);
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the number of one bits in the integer.
+    ///
+    /// For a non-constant integer, this method uses `k + 1` constraints,
+    /// where `k` is the bit length of `I::BITS` (i.e. 5 constraints for 8-bit integers).
+    /// For a constant integer, this method uses 0 constraints.
+    pub fn count_ones(&self) -> Integer<E, u32> {
+        // If the integer is constant, compute the number of ones directly.
+        if self.is_constant() {
+            return Integer::constant(console::Integer::new(self.eject_value().count_ones()));
+        }
+
+        // Sum the bits in the base field.
+        sum_as_u32(self.bits_le.iter().map(Field::from_boolean), I::BITS)
+    }
+
+    /// Returns the number of zero bits in the integer, computed as `I::BITS - count_ones`.
+    ///
+    /// For a non-constant integer, this method uses `k + 1` constraints,
+    /// where `k` is the bit length of `I::BITS` (i.e. 5 constraints for 8-bit integers).
+    /// For a constant integer, this method uses 0 constraints.
+    pub fn count_zeros(&self) -> Integer<E, u32> {
+        // If the integer is constant, compute the number of zeros directly.
+        if self.is_constant() {
+            return Integer::constant(console::Integer::new(self.eject_value().count_zeros()));
+        }
+
+        // Sum the complemented bits in the base field, which is `I::BITS - count_ones`.
+        sum_as_u32(self.bits_le.iter().map(|bit| Field::from_boolean(&!bit)), I::BITS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_count_ones<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected_ones = value.count_ones();
+        let num_bits = (u64::BITS - I::BITS.leading_zeros()) as u64;
+
+        Circuit::scope(name, || {
+            let candidate = a.count_ones();
+            assert_eq!(expected_ones, *candidate.eject_value());
+            match mode.is_constant() {
+                true => assert_scope!(32, 0, 0, 0),
+                false => assert_scope!(0, 0, num_bits, num_bits + 1),
+            }
+        });
+        Circuit::scope(name, || {
+            let candidate = a.count_zeros();
+            assert_eq!(I::BITS as u32 - expected_ones, *candidate.eject_value());
+            match mode.is_constant() {
+                true => assert_scope!(32, 0, 0, 0),
+                false => assert_scope!(0, 0, num_bits, num_bits + 1),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("CountOnes: {mode} {i}");
+            check_count_ones::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        // Check the extremes, where `0` has no ones, and the all-ones value has `I::BITS` ones.
+        let boundaries = [I::zero(), I::one(), !I::zero(), I::MAX, I::MIN];
+        for (i, value) in boundaries.into_iter().enumerate() {
+            check_count_ones::<I>(&format!("CountOnes: {mode} boundary {i}"), console::Integer::new(value), mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, count_ones);
+    test_integer_unary!(run_test, i16, count_ones);
+    test_integer_unary!(run_test, i32, count_ones);
+    test_integer_unary!(run_test, i64, count_ones);
+    test_integer_unary!(run_test, i128, count_ones);
+
+    test_integer_unary!(run_test, u8, count_ones);
+    test_integer_unary!(run_test, u16, count_ones);
+    test_integer_unary!(run_test, u32, count_ones);
+    test_integer_unary!(run_test, u64, count_ones);
+    test_integer_unary!(run_test, u128, count_ones);
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the sum of the decimal digits of the unsigned integer.
+    ///
+    /// The integer is divided by 10 in a fixed number of `D - 1` steps, where `D` is the number of decimal digits
+    /// of `I::MAX`, summing the remainders and the final quotient, so the shape of the circuit is independent of
+    /// the integer. This method halts if `I` is signed.
+    ///
+    /// For a variable integer, where `2 * I::BITS < E::BaseField::size_in_data_bits()`, this method uses
+    /// `(D - 1) * (3 * I::BITS + 4) + k + 1` constraints, where `k` is the bit length of `9 * D`
+    /// (i.e. 62 constraints for 8-bit integers). For a constant integer, this method uses 0 constraints.
+    pub fn decimal_digit_sum(&self) -> Integer<E, u32> {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt(format!("Cannot sum the decimal digits of '{}', as it is signed", I::type_name()))
+        }
+
+        // If the integer is constant, compute the digit sum directly.
+        if self.is_constant() {
+            // Note: Unwrapping is safe, as an unsigned integer fits in a `u128`.
+            let mut value = self.eject_value().to_u128().unwrap();
+            let mut sum = 0u32;
+            while value > 0 {
+                sum += (value % 10) as u32;
+                value /= 10;
+            }
+            return Integer::constant(console::Integer::new(sum));
+        }
+
+        // Determine the number of decimal digits of `I::MAX`.
+        let num_digits = I::MAX.to_string().len();
+
+        // Divide the integer by 10 repeatedly, summing the remainders, which are the decimal digits.
+        let two = I::one() + I::one();
+        let ten = Self::constant(console::Integer::new((two + two) * two + two));
+        let mut quotient = self.clone();
+        let mut digits = Vec::with_capacity(num_digits);
+        for _ in 0..num_digits - 1 {
+            let (next_quotient, remainder) = quotient.div_rem_checked(&ten);
+            digits.push(remainder.to_field());
+            quotient = next_quotient;
+        }
+        // Add the final quotient, which is the leading decimal digit, as `I::MAX` has `D` decimal digits.
+        digits.push(quotient.to_field());
+
+        // Sum the decimal digits in the base field, which is at most `9 * D`.
+        sum_as_u32(digits, 9 * num_digits as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the sum of the decimal digits of the given value, from its decimal string.
+    fn reference_digit_sum<I: IntegerType>(value: I) -> u32 {
+        value.to_string().chars().map(|digit| digit.to_digit(10).unwrap()).sum()
+    }
+
+    fn check_decimal_digit_sum<I: IntegerType>(name: &str, value: I, mode: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::new(value));
+        Circuit::scope(name, || {
+            let candidate = a.decimal_digit_sum();
+            assert_eq!(reference_digit_sum(value), *candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            match mode.is_constant() {
+                true => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                // Check the documented number of constraints.
+                false if 2 * I::BITS < <Circuit as Environment>::BaseField::size_in_data_bits() as u64 => {
+                    let num_digits = I::MAX.to_string().len() as u64;
+                    let num_sum_bits = (u64::BITS - (9 * num_digits).leading_zeros()) as u64;
+                    let num_constraints = (num_digits - 1) * (3 * I::BITS + 4) + num_sum_bits + 1;
+                    assert_eq!(num_constraints, Circuit::num_constraints_in_scope());
+                }
+                false => {}
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("DecimalDigitSum: {mode} {i}");
+            let value: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            check_decimal_digit_sum::<I>(&name, *value, mode);
+        }
+
+        // Check the boundaries of the decimal digits, and the extremes of the integer type.
+        let two = I::one() + I::one();
+        let ten = (two + two) * two + two;
+        let hundred = ten * ten;
+        let boundaries =
+            [I::zero(), I::one(), ten - I::one(), ten, hundred - I::one(), hundred, hundred + hundred - I::one()];
+        for (i, value) in boundaries.into_iter().enumerate() {
+            check_decimal_digit_sum::<I>(&format!("DecimalDigitSum: {mode} boundary {i}"), value, mode);
+        }
+        check_decimal_digit_sum::<I>(&format!("DecimalDigitSum: {mode} MAX"), I::MAX, mode);
+        check_decimal_digit_sum::<I>(&format!("DecimalDigitSum: {mode} MAX - 1"), I::MAX - I::one(), mode);
+    }
+
+    fn run_signed_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        // Ensure that signed integers are not supported.
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::one());
+        check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.decimal_digit_sum());
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_signed_test, i8, decimal_digit_sum);
+    test_integer_unary!(run_signed_test, i16, decimal_digit_sum);
+    test_integer_unary!(run_signed_test, i32, decimal_digit_sum);
+    test_integer_unary!(run_signed_test, i64, decimal_digit_sum);
+    test_integer_unary!(run_signed_test, i128, decimal_digit_sum);
+
+    test_integer_unary!(run_test, u8, decimal_digit_sum);
+    test_integer_unary!(run_test, u16, decimal_digit_sum);
+    test_integer_unary!(run_test, u32, decimal_digit_sum);
+    test_integer_unary!(run_test, u64, decimal_digit_sum);
+    test_integer_unary!(run_test, u128, decimal_digit_sum);
+}
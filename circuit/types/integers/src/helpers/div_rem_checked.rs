@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the quotient and remainder of `self` divided by `other`, from a single constrained division.
+    ///
+    /// The quotient rounds towards zero, and the remainder takes on the sign of `self`,
+    /// matching `div_checked` and `rem_checked` respectively.
+    /// This method halts on a constant zero divisor or a constant overflow (`I::MIN / -1`),
+    /// and fails to satisfy the circuit otherwise.
+    ///
+    /// This method costs roughly one `div_checked`, rather than one `div_checked` plus one `rem_checked`:
+    /// for unsigned integers, the cost is that of `div_checked`, and for signed integers,
+    /// the cost is that of `div_checked`, plus one `ternary` and one `sub_wrapped` to sign the remainder.
+    pub fn div_rem_checked(&self, other: &Self) -> (Self, Self) {
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            // If `self` and `other` are constants, and other is not zero, then directly return the quotient and remainder.
+            (true, true) => {
+                match (
+                    self.eject_value().checked_div(&other.eject_value()),
+                    self.eject_value().checked_rem(&other.eject_value()),
+                ) {
+                    (Some(quotient), Some(remainder)) => (
+                        Integer::constant(console::Integer::new(quotient)),
+                        Integer::constant(console::Integer::new(remainder)),
+                    ),
+                    _ => E::halt("Overflow on division of two integer constants"),
+                }
+            }
+            // Handle the remaining cases.
+            // Note that `other` is either a constant and non-zero, or not a constant.
+            _ => {
+                if I::is_signed() {
+                    // Ensure that overflow cannot occur in this division.
+                    // Signed integer division overflows when the dividend is Integer::MIN and the divisor is -1.
+                    let min = Integer::constant(console::Integer::MIN);
+                    let neg_one = Integer::constant(-console::Integer::one());
+                    let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
+                    E::assert(!overflows);
+
+                    // Divide the absolute value of `self` and `other` in the base field.
+                    // Note that it is safe to use `abs_wrapped`, since the case for console::Integer::MIN is handled above.
+                    let unsigned_dividend = self.abs_wrapped().cast_as_dual();
+                    // Note that `unsigned_divisor` is zero iff `other` is zero.
+                    let unsigned_divisor = other.abs_wrapped().cast_as_dual();
+                    // Note that this division checks that `unsigned_divisor` is not zero.
+                    let (unsigned_quotient, unsigned_remainder) =
+                        unsigned_dividend.unsigned_division_via_witness(&unsigned_divisor);
+
+                    // Note that quotient <= |console::Integer::MIN|, since the dividend <= |console::Integer::MIN| and 0 <= quotient <= dividend.
+                    let signed_quotient = Self { bits_le: unsigned_quotient.bits_le, phantom: Default::default() };
+                    let signed_remainder = Self { bits_le: unsigned_remainder.bits_le, phantom: Default::default() };
+                    let operands_same_sign = &self.msb().is_equal(other.msb());
+
+                    // The quotient is negative iff the operands have different signs.
+                    let negated_quotient = Self::zero().sub_wrapped(&signed_quotient);
+                    let quotient = Self::ternary(operands_same_sign, &signed_quotient, &negated_quotient);
+                    // The remainder takes on the same sign as `self` because the division operation rounds towards zero.
+                    let negated_remainder = Self::zero().sub_wrapped(&signed_remainder);
+                    let remainder = Self::ternary(&!self.msb(), &signed_remainder, &negated_remainder);
+                    (quotient, remainder)
+                } else {
+                    // Return the quotient and remainder of `self` and `other`.
+                    // Note that this division checks that `other` is not zero.
+                    self.unsigned_division_via_witness(other)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_div_rem<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        if second == console::Integer::zero() {
+            match mode_b {
+                Mode::Constant => check_operation_halts(&a, &b, Integer::div_rem_checked),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.div_rem_checked(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            }
+        } else {
+            match (first.checked_div(&second), first.checked_rem(&second)) {
+                (Some(expected_quotient), Some(expected_remainder)) => {
+                    // Compute the number of constraints for the separate division and remainder.
+                    let num_separate_constraints = Circuit::scope(format!("{name} (separate)"), || {
+                        assert_eq!(expected_quotient, *a.div_checked(&b).eject_value());
+                        assert_eq!(expected_remainder, *a.rem_checked(&b).eject_value());
+                        Circuit::num_constraints_in_scope()
+                    });
+                    Circuit::reset();
+
+                    Circuit::scope(name, || {
+                        let (quotient, remainder) = a.div_rem_checked(&b);
+                        assert_eq!(expected_quotient, *quotient.eject_value());
+                        assert_eq!(expected_remainder, *remainder.eject_value());
+                        assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                        // Ensure the combined division is cheaper than the separate division and remainder.
+                        match mode_a.is_constant() && mode_b.is_constant() {
+                            true => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                            false => assert!(Circuit::num_constraints_in_scope() < num_separate_constraints),
+                        }
+                    });
+                }
+                _ => match (mode_a, mode_b) {
+                    (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::div_rem_checked),
+                    _ => Circuit::scope(name, || {
+                        let _candidate = a.div_rem_checked(&b);
+                        assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                    }),
+                },
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("DivRem: {mode_a} / {mode_b} {i}");
+            check_div_rem::<I>(&name, first, second, mode_a, mode_b);
+            check_div_rem::<I>(&name, second, first, mode_a, mode_b);
+
+            let name = format!("DivRem by small: {mode_a} / {mode_b} {i}");
+            let small = console::Integer::new(*second >> (I::BITS as usize - 4));
+            check_div_rem::<I>(&name, first, small, mode_a, mode_b);
+        }
+
+        // Check specific cases common to signed and unsigned integers.
+        let two = console::Integer::one() + console::Integer::one();
+        check_div_rem::<I>("0 / 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+        check_div_rem::<I>("1 / 0", console::Integer::one(), console::Integer::zero(), mode_a, mode_b);
+        check_div_rem::<I>("MAX / 2", console::Integer::MAX, two, mode_a, mode_b);
+        check_div_rem::<I>("MAX / MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_div_rem::<I>("MIN / 2", console::Integer::MIN, two, mode_a, mode_b);
+
+        // Check the sign conventions for signed integers.
+        if I::is_signed() {
+            let seven = two + two + two + console::Integer::one();
+            let neg_one = -console::Integer::one();
+            check_div_rem::<I>("7 / -2", seven, -two, mode_a, mode_b);
+            check_div_rem::<I>("-7 / 2", -seven, two, mode_a, mode_b);
+            check_div_rem::<I>("-7 / -2", -seven, -two, mode_a, mode_b);
+            check_div_rem::<I>("MIN / -1", console::Integer::MIN, neg_one, mode_a, mode_b);
+            check_div_rem::<I>("MIN / MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+            check_div_rem::<I>("-1 / MIN", neg_one, console::Integer::MIN, mode_a, mode_b);
+        }
+    }
+
+    test_integer_binary!(run_test, i8, div_rem);
+    test_integer_binary!(run_test, i16, div_rem);
+    test_integer_binary!(run_test, i32, div_rem);
+    test_integer_binary!(run_test, i64, div_rem);
+    test_integer_binary!(run_test, i128, div_rem);
+
+    test_integer_binary!(run_test, u8, div_rem);
+    test_integer_binary!(run_test, u16, div_rem);
+    test_integer_binary!(run_test, u32, div_rem);
+    test_integer_binary!(run_test, u64, div_rem);
+    test_integer_binary!(run_test, u128, div_rem);
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the bits `[lo, hi)` of the integer, shifted down to bit 0, as an integer of the same type.
+    ///
+    /// The upper `I::BITS - (hi - lo)` bits of the output are zero, so the extracted bits are never sign-extended.
+    /// This method halts if `lo > hi` or `hi > I::BITS`.
+    ///
+    /// This method uses 0 constraints, and the output is constant iff the integer is constant.
+    pub fn extract_bits(&self, lo: usize, hi: usize) -> Self {
+        // Ensure the bit range is valid.
+        if lo > hi || hi > I::BITS as usize {
+            E::halt(format!("Invalid bit range [{lo}, {hi}) for '{}'", I::type_name()))
+        }
+        // Select the bits in the range, padding the most significant bits with zeros.
+        Integer::from_bits_le(&self.bits_le[lo..hi])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_extract_bits<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        lo: usize,
+        hi: usize,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected value from the bits in the range.
+        let mut expected_bits_le = value.to_bits_le()[lo..hi].to_vec();
+        expected_bits_le.resize(I::BITS as usize, false);
+        let expected = console::Integer::<_, I>::from_bits_le(&expected_bits_le).unwrap();
+        Circuit::scope(name, || {
+            let candidate = a.extract_bits(lo, hi);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(mode.is_constant() || lo == hi, candidate.is_constant());
+            // Note: Padding the output with constant zero bits does not allocate any constants.
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+        let bits = I::BITS as usize;
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            // Check the low, middle, and high ranges.
+            check_extract_bits::<I>(&format!("ExtractBits: {mode} low {i}"), value, 0, bits / 4, mode);
+            check_extract_bits::<I>(&format!("ExtractBits: {mode} middle {i}"), value, bits / 4, 3 * bits / 4, mode);
+            check_extract_bits::<I>(&format!("ExtractBits: {mode} high {i}"), value, 3 * bits / 4, bits, mode);
+            // Check the single bit, empty, and full ranges.
+            check_extract_bits::<I>(&format!("ExtractBits: {mode} single {i}"), value, 1, 2, mode);
+            check_extract_bits::<I>(&format!("ExtractBits: {mode} empty {i}"), value, 3, 3, mode);
+            check_extract_bits::<I>(&format!("ExtractBits: {mode} full {i}"), value, 0, bits, mode);
+        }
+
+        // Check that the MSB of a signed integer is not sign-extended.
+        check_extract_bits::<I>("ExtractBits: MIN", console::Integer::MIN, bits - 1, bits, mode);
+        check_extract_bits::<I>("ExtractBits: MAX", console::Integer::MAX, 1, bits, mode);
+
+        // Check that invalid ranges halt.
+        let a = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut rng));
+        check_unary_operation_halts(a.clone(), move |a: Integer<Circuit, I>| a.extract_bits(0, bits + 1));
+        check_unary_operation_halts(a.clone(), move |a: Integer<Circuit, I>| a.extract_bits(bits + 1, bits + 1));
+        check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.extract_bits(2, 1));
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, extract_bits);
+    test_integer_unary!(run_test, i16, extract_bits);
+    test_integer_unary!(run_test, i32, extract_bits);
+    test_integer_unary!(run_test, i64, extract_bits);
+    test_integer_unary!(run_test, i128, extract_bits);
+
+    test_integer_unary!(run_test, u8, extract_bits);
+    test_integer_unary!(run_test, u16, extract_bits);
+    test_integer_unary!(run_test, u32, extract_bits);
+    test_integer_unary!(run_test, u64, extract_bits);
+    test_integer_unary!(run_test, u128, extract_bits);
+}
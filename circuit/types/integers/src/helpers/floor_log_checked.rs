@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `floor(log_base(self))`, i.e. the number of times `self` can be divided by `base`
+    /// before the quotient is less than `base`.
+    ///
+    /// The logarithm divides `self` by `base` in a fixed number of `I::BITS - 1` steps,
+    /// counting the non-zero quotients, so the shape of the circuit is independent of the operands.
+    /// This method halts if `I` is signed, or if the constant `self` is zero, or the constant `base` is less than 2.
+    /// Otherwise, the circuit is not satisfied if `self` is zero, or `base` is less than 2.
+    ///
+    /// For variable operands, where `2 * I::BITS < E::BaseField::size_in_data_bits()`, this method uses
+    /// `(I::BITS - 1) * (3 * I::BITS + 7) + I::BITS + 7 + k` constraints,
+    /// where `k` is the bit length of `I::BITS - 1` (i.e. 235 constraints for 8-bit integers).
+    /// For constant operands, this method uses 0 constraints.
+    pub fn floor_log_checked(&self, base: &Self) -> Integer<E, u32> {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt(format!("Cannot compute the logarithm of '{}', as it is signed", I::type_name()))
+        }
+
+        // If the operands are constant, compute the logarithm directly.
+        if self.is_constant() && base.is_constant() {
+            return match floor_log_native(*self.eject_value(), *base.eject_value()) {
+                Some(log) => Integer::constant(console::Integer::new(log)),
+                None => E::halt("Attempted to compute the logarithm of zero, or with a base less than 2."),
+            };
+        }
+
+        // Ensure that `self` is not zero.
+        match self.is_constant() {
+            true if self.eject_value().is_zero() => E::halt("Attempted to compute the logarithm of zero."),
+            true => (),
+            false => E::assert(self.is_not_equal(&Self::zero())),
+        }
+        // Ensure that `base` is at least 2.
+        match base.is_constant() {
+            true if *base.eject_value() <= I::one() => E::halt("Attempted to compute a logarithm with a base below 2."),
+            true => (),
+            false => E::assert(base.is_greater_than(&Self::one())),
+        }
+
+        // Divide `self` by `base` repeatedly, counting the non-zero quotients.
+        // Note: As `base` is at least 2 and `self` is less than 2^I::BITS,
+        // the quotient is zero after `I::BITS - 1` steps.
+        let mut quotient = self.clone();
+        let mut is_non_zero = Vec::with_capacity(I::BITS as usize - 1);
+        for _ in 0..I::BITS - 1 {
+            quotient = quotient.div_wrapped(base);
+            is_non_zero.push(quotient.is_not_equal(&Self::zero()));
+        }
+
+        // Sum the non-zero quotients in the base field.
+        sum_as_u32(is_non_zero.iter().map(Field::from_boolean), I::BITS - 1)
+    }
+}
+
+/// Returns `floor(log_base(value))`, or `None` if the value is zero or the base is less than 2.
+fn floor_log_native<I: IntegerType>(value: I, base: I) -> Option<u32> {
+    if value.is_zero() || base <= I::one() {
+        return None;
+    }
+    let (mut quotient, mut log) = (value, 0);
+    while quotient >= base {
+        quotient = quotient.wrapping_div(&base);
+        log += 1;
+    }
+    Some(log)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 4;
+
+    /// Returns the largest `k` such that `base^k <= value`, computed by exponentiation.
+    fn reference_log(value: u128, base: u128) -> u32 {
+        let mut log = 0;
+        let mut power = base;
+        while power <= value {
+            log += 1;
+            match power.checked_mul(base) {
+                Some(next) => power = next,
+                None => break,
+            }
+        }
+        log
+    }
+
+    fn check_floor_log<I: IntegerType + RefUnwindSafe>(name: &str, value: I, base: I, mode_a: Mode, mode_b: Mode) {
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::new(value));
+        let b = Integer::<Circuit, I>::new(mode_b, console::Integer::new(base));
+        match floor_log_native(value, base) {
+            Some(expected) => Circuit::scope(name, || {
+                assert_eq!(reference_log(value.to_u128().unwrap(), base.to_u128().unwrap()), expected);
+                let candidate = a.floor_log_checked(&b);
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                match (mode_a.is_constant(), mode_b.is_constant()) {
+                    (true, true) => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                    // Check the documented number of constraints, for variable operands.
+                    (false, false) if 2 * I::BITS < <Circuit as Environment>::BaseField::size_in_data_bits() as u64 => {
+                        let num_count_bits = (u64::BITS - (I::BITS - 1).leading_zeros()) as u64;
+                        let num_constraints = (I::BITS - 1) * (3 * I::BITS + 7) + I::BITS + 7 + num_count_bits;
+                        assert_eq!(num_constraints, Circuit::num_constraints_in_scope());
+                    }
+                    _ => {}
+                }
+            }),
+            None => match (mode_a, mode_b) {
+                (Mode::Constant, _) if value.is_zero() => check_operation_halts(&a, &b, Integer::floor_log_checked),
+                (_, Mode::Constant) if base <= I::one() => check_operation_halts(&a, &b, Integer::floor_log_checked),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.floor_log_checked(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let one = I::one();
+        let two = one + one;
+        let three = two + one;
+        let ten = three * three + one;
+
+        for i in 0..ITERATIONS {
+            let value: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            let base: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+
+            // Check the logarithm over several bases.
+            for (j, base) in [two, three, ten, I::MAX, *base].into_iter().enumerate() {
+                let name = format!("FloorLog: {mode_a} {mode_b} {i} {j}");
+                check_floor_log::<I>(&name, *value, base, mode_a, mode_b);
+            }
+        }
+
+        // Check the boundaries of the logarithm.
+        check_floor_log::<I>("FloorLog: 1 base 2", one, two, mode_a, mode_b);
+        check_floor_log::<I>("FloorLog: MAX base 2", I::MAX, two, mode_a, mode_b);
+        check_floor_log::<I>("FloorLog: 9 base 3", three * three, three, mode_a, mode_b);
+        check_floor_log::<I>("FloorLog: 8 base 3", three * three - one, three, mode_a, mode_b);
+        check_floor_log::<I>("FloorLog: MAX base MAX", I::MAX, I::MAX, mode_a, mode_b);
+
+        // Check that a zero value, or a base less than 2, fails.
+        check_floor_log::<I>("FloorLog: 0 base 2", I::zero(), two, mode_a, mode_b);
+        check_floor_log::<I>("FloorLog: 10 base 1", ten, one, mode_a, mode_b);
+        check_floor_log::<I>("FloorLog: 10 base 0", ten, I::zero(), mode_a, mode_b);
+    }
+
+    fn run_signed_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        // Ensure that signed integers are not supported.
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::one());
+        let b = Integer::<Circuit, I>::new(mode_b, console::Integer::one() + console::Integer::one());
+        check_operation_halts(&a, &b, Integer::floor_log_checked);
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_signed_test, i8, floor_log);
+    test_integer_binary!(run_signed_test, i16, floor_log);
+    test_integer_binary!(run_signed_test, i32, floor_log);
+    test_integer_binary!(run_signed_test, i64, floor_log);
+    test_integer_binary!(run_signed_test, i128, floor_log);
+
+    test_integer_binary!(run_test, u8, floor_log);
+    test_integer_binary!(run_test, u16, floor_log);
+    test_integer_binary!(run_test, u32, floor_log);
+    test_integer_binary!(run_test, u64, floor_log);
+    test_integer_binary!(run_test, u128, floor_log);
+}
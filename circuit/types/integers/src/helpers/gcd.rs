@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the greatest common divisor of `self` and `other`, where `gcd(0, x) == x`.
+    ///
+    /// This method halts if `I` is signed.
+    ///
+    /// For variable inputs, this method runs a binary GCD for a fixed `2 * I::BITS` iterations.
+    /// Each iteration removes at least one bit from the combined bit length of the operands, so these always suffice,
+    /// whereas `I::BITS` iterations do not: e.g. `gcd(2^(I::BITS - 1), 2^I::BITS - 1)` halves the first operand
+    /// `I::BITS - 1` times before reducing the second operand, and takes `2 * I::BITS - 2` iterations.
+    /// Each iteration selects its step via `ternary`, so the constraint count depends only on `I`:
+    /// per iteration, one comparison, two wrapped subtractions, two boolean operations, and seven integer ternaries,
+    /// followed by one `|` and one wrapped multiplication to restore the common powers of two.
+    ///
+    /// With `n = I::BITS`, this method uses `2n^2 + n` constants, `(39n^2 + 28n) / 2 + 1` private variables,
+    /// and `(39n^2 + 40n) / 2 + 2` constraints (e.g. 1410 constraints for `u8`, and 20610 for `u32`).
+    /// If one input is constant, some steps are resolved without constraints, so these counts are upper bounds.
+    pub fn gcd(&self, other: &Self) -> Self {
+        // Ensure the type is unsigned.
+        if I::is_signed() {
+            E::halt(format!("Cannot compute the GCD of signed integers of type '{}'", I::type_name()))
+        }
+
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the GCD and return the new constant.
+            witness!(|self, other| console::Integer::new(native_gcd(*self, *other)))
+        } else {
+            let mut a = self.clone();
+            let mut b = other.clone();
+            // Initialize the power of two shared by both operands.
+            let mut shift = Self::one();
+
+            for _ in 0..(2 * I::BITS) {
+                // Determine the parity of each operand.
+                let a_is_odd = &a.bits_le[0];
+                let b_is_odd = &b.bits_le[0];
+                let both_odd = a_is_odd & b_is_odd;
+                let both_even = !(a_is_odd | b_is_odd);
+
+                // Compute the halved operands.
+                let a_half = Self::from_bits_le(&a.bits_le[1..]);
+                let b_half = Self::from_bits_le(&b.bits_le[1..]);
+                // Compute the halved absolute difference, and the minimum, of the operands.
+                let a_is_greater_than_or_equal = a.is_greater_than_or_equal(&b);
+                let difference = Self::ternary(&a_is_greater_than_or_equal, &a.sub_wrapped(&b), &b.sub_wrapped(&a));
+                let difference_half = Self::from_bits_le(&difference.bits_le[1..]);
+                let minimum = Self::ternary(&a_is_greater_than_or_equal, &b, &a);
+
+                // If both are odd, set `(a, b) = (|a - b| / 2, min(a, b))`, otherwise halve each even operand.
+                let next_a = Self::ternary(&both_odd, &difference_half, &Self::ternary(a_is_odd, &a, &a_half));
+                let next_b = Self::ternary(&both_odd, &minimum, &Self::ternary(b_is_odd, &b, &b_half));
+                // If both are even, double the shared power of two.
+                // Note: This wraps only when both operands are zero, in which case the GCD is zero regardless.
+                let mut doubled = vec![Boolean::constant(false)];
+                doubled.extend_from_slice(&shift.bits_le[..(I::BITS as usize - 1)]);
+                shift = Self::ternary(&both_even, &Self::from_bits_le(&doubled), &shift);
+
+                a = next_a;
+                b = next_b;
+            }
+
+            // At least one operand is now zero, so the GCD is the other operand times the shared power of two.
+            (&a | &b).mul_wrapped(&shift)
+        }
+    }
+}
+
+/// Returns the greatest common divisor of `a` and `b`, via the Euclidean algorithm.
+fn native_gcd<I: IntegerType>(mut a: I, mut b: I) -> I {
+    while !b.is_zero() {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 4;
+
+    fn check_gcd<I: IntegerType + UnwindSafe>(name: &str, first: I, second: I, mode_a: Mode, mode_b: Mode) {
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::new(first));
+        let b = Integer::<Circuit, I>::new(mode_b, console::Integer::new(second));
+        let expected = native_gcd(first, second);
+        Circuit::scope(name, || {
+            let candidate = a.gcd(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            // Check the documented number of constants, private variables, and constraints.
+            let n = I::BITS;
+            let num_constants = 2 * n * n + n;
+            let num_public = 0;
+            let num_private = (39 * n * n + 28 * n) / 2 + 1;
+            let num_constraints = (39 * n * n + 40 * n) / 2 + 2;
+            match (mode_a.is_constant(), mode_b.is_constant()) {
+                (true, true) => assert_scope!(I::BITS, 0, 0, 0),
+                (false, false) => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                _ => assert_scope!(<=num_constants, <=num_public, <=num_private, <=num_constraints),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        // Check the sampled inputs.
+        for i in 0..ITERATIONS {
+            let name = format!("GCD: ({mode_a}, {mode_b}) {i}");
+            let first: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            let second: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            check_gcd::<I>(&name, *first, *second, mode_a, mode_b);
+            // Check inputs that share a power of two.
+            let shift = (i as usize) % (I::BITS as usize);
+            check_gcd::<I>(&name, *first << shift, *second << shift, mode_a, mode_b);
+        }
+
+        // Check the boundary inputs, where `gcd(2^(I::BITS - 1), 2^I::BITS - 1)` takes `2 * I::BITS - 2` iterations.
+        let boundary = [I::zero(), I::one(), I::MAX.wrapping_shr(1), I::MAX.wrapping_shr(1) + I::one(), I::MAX];
+        for (i, first) in boundary.iter().enumerate() {
+            for (j, second) in boundary.iter().enumerate() {
+                let name = format!("GCD: ({mode_a}, {mode_b}) boundary ({i}, {j})");
+                check_gcd::<I>(&name, *first, *second, mode_a, mode_b);
+            }
+        }
+    }
+
+    fn run_signed_test<I: IntegerType + UnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::one());
+        let b = Integer::<Circuit, I>::new(mode_b, console::Integer::one());
+        check_operation_halts(a, b, |a: Integer<Circuit, I>, b: Integer<Circuit, I>| a.gcd(&b));
+        Circuit::reset();
+    }
+
+    test_integer_binary!(run_test, u8, gcd);
+    test_integer_binary!(run_test, u16, gcd);
+    test_integer_binary!(run_test, u32, gcd);
+    test_integer_binary!(run_test, u64, gcd);
+    test_integer_binary!(run_test, u128, gcd);
+
+    test_integer_binary!(run_signed_test, i8, gcd);
+    test_integer_binary!(run_signed_test, i128, gcd);
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `true` if `lo <= self <= hi`, for signed and unsigned integers.
+    ///
+    /// In debug builds, this method panics if `lo > hi`, as the range is then empty.
+    /// If all of the integers are constant, this method computes the result directly, and uses 0 constraints.
+    /// Otherwise, this method uses the constraints of two comparisons, plus 1 constraint to combine them
+    /// if both comparisons are non-constant. For example, for non-constant integers, this method uses
+    /// `2 * I::BITS + 5` constraints for unsigned integers, and `2 * I::BITS + 11` constraints for signed integers.
+    pub fn in_range(&self, lo: &Self, hi: &Self) -> Boolean<E> {
+        debug_assert!(lo.eject_value() <= hi.eject_value(), "The lower bound of the range exceeds the upper bound");
+
+        // If all of the integers are constant, compute the result directly.
+        if self.is_constant() && lo.is_constant() && hi.is_constant() {
+            let value = self.eject_value();
+            return Boolean::constant(lo.eject_value() <= value && value <= hi.eject_value());
+        }
+        // Otherwise, compute `(self >= lo) & (self <= hi)`.
+        self.is_greater_than_or_equal(lo) & self.is_less_than_or_equal(hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the number of constraints of a comparison, as documented in the `Compare` metrics.
+    fn comparison_constraints<I: IntegerType>(mode_a: Mode, mode_b: Mode) -> u64 {
+        match (mode_a.is_constant(), mode_b.is_constant(), I::is_signed()) {
+            (true, true, _) => 0,
+            (_, _, false) => I::BITS + 2,
+            (true, false, true) | (false, true, true) => I::BITS + 3,
+            (false, false, true) => I::BITS + 5,
+        }
+    }
+
+    fn check_in_range<I: IntegerType>(name: &str, value: I, lo: I, hi: I, mode: Mode, bound_mode: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::new(value));
+        let lo_integer = Integer::<Circuit, I>::new(bound_mode, console::Integer::new(lo));
+        let hi_integer = Integer::<Circuit, I>::new(bound_mode, console::Integer::new(hi));
+        let expected = lo <= value && value <= hi;
+
+        Circuit::scope(name, || {
+            let candidate = a.in_range(&lo_integer, &hi_integer);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+
+            // Check the documented number of constraints.
+            let expected_num_constraints = match mode.is_constant() && bound_mode.is_constant() {
+                true => 0,
+                false => 2 * comparison_constraints::<I>(mode, bound_mode) + 1,
+            };
+            assert_eq!(expected_num_constraints, Circuit::num_constraints_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode, bound_mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let one = I::one();
+
+        for i in 0..ITERATIONS {
+            let [value, first, second] =
+                [0, 1, 2].map(|_| *console::Integer::<<Circuit as Environment>::Network, I>::rand(&mut rng));
+            let (lo, hi) = if first <= second { (first, second) } else { (second, first) };
+
+            // Check a random value.
+            check_in_range::<I>(&format!("InRange: {mode} {bound_mode} {i}"), value, lo, hi, mode, bound_mode);
+
+            // Check the values on the boundaries of the range.
+            check_in_range::<I>(&format!("InRange: {mode} {bound_mode} lo {i}"), lo, lo, hi, mode, bound_mode);
+            check_in_range::<I>(&format!("InRange: {mode} {bound_mode} hi {i}"), hi, lo, hi, mode, bound_mode);
+            check_in_range::<I>(&format!("InRange: {mode} {bound_mode} lo == hi {i}"), lo, lo, lo, mode, bound_mode);
+
+            // Check the values just outside the boundaries of the range.
+            if lo > I::MIN {
+                let name = format!("InRange: {mode} {bound_mode} lo - 1 {i}");
+                check_in_range::<I>(&name, lo - one, lo, hi, mode, bound_mode);
+            }
+            if hi < I::MAX {
+                let name = format!("InRange: {mode} {bound_mode} hi + 1 {i}");
+                check_in_range::<I>(&name, hi + one, lo, hi, mode, bound_mode);
+            }
+        }
+
+        // Check the extremes of the integer type.
+        for (i, value) in [I::MIN, I::zero(), I::MAX].into_iter().enumerate() {
+            let name = format!("InRange: {mode} {bound_mode} extremes {i}");
+            check_in_range::<I>(&name, value, I::MIN, I::MAX, mode, bound_mode);
+            check_in_range::<I>(&name, value, I::MIN, I::MIN, mode, bound_mode);
+            check_in_range::<I>(&name, value, I::MAX, I::MAX, mode, bound_mode);
+        }
+    }
+
+    test_integer_binary!(run_test, i8, in_range);
+    test_integer_binary!(run_test, i16, in_range);
+    test_integer_binary!(run_test, i32, in_range);
+    test_integer_binary!(run_test, i64, in_range);
+    test_integer_binary!(run_test, i128, in_range);
+
+    test_integer_binary!(run_test, u8, in_range);
+    test_integer_binary!(run_test, u16, in_range);
+    test_integer_binary!(run_test, u32, in_range);
+    test_integer_binary!(run_test, u64, in_range);
+    test_integer_binary!(run_test, u128, in_range);
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the number of redundant leading sign bits of the signed integer,
+    /// i.e. the number of leading bits after the sign bit that are equal to the sign bit.
+    ///
+    /// This method halts if `I` is unsigned.
+    /// For a non-constant integer, this method uses `2 * I::BITS - 2 + k` constraints,
+    /// where `k` is the bit length of `I::BITS - 1` (i.e. 17 constraints for 8-bit integers).
+    /// For a constant integer, this method uses 0 constraints.
+    pub fn leading_sign_bits(&self) -> Integer<E, u32> {
+        // Ensure the integer type is signed.
+        if !I::is_signed() {
+            E::halt(format!("Cannot count the leading sign bits of '{}', as it is unsigned", I::type_name()))
+        }
+
+        // If the integer is constant, compute the number of leading sign bits directly.
+        if self.is_constant() {
+            let value = *self.eject_value();
+            // Note: Complementing a negative value turns its leading sign bits into leading zeros.
+            let magnitude = if value < I::zero() { !value } else { value };
+            return Integer::constant(console::Integer::new(magnitude.leading_zeros() - 1));
+        }
+
+        // Scan the bits below the sign bit, from most to least significant,
+        // counting the bits in the leading run that matches the sign bit.
+        let msb = self.msb();
+        let mut is_leading: Vec<Boolean<E>> = Vec::with_capacity(I::BITS as usize - 1);
+        for bit in self.bits_le[..I::BITS as usize - 1].iter().rev() {
+            let is_sign_bit = !(bit ^ msb);
+            let next = match is_leading.last() {
+                Some(previous) => previous & is_sign_bit,
+                None => is_sign_bit,
+            };
+            is_leading.push(next);
+        }
+
+        // Sum the bits in the leading run in the base field.
+        sum_as_u32(is_leading.iter().map(Field::from_boolean), I::BITS - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_leading_sign_bits<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected count by scanning the bits below the sign bit.
+        let bits_le = value.to_bits_le();
+        let sign = bits_le[I::BITS as usize - 1];
+        let expected = bits_le[..I::BITS as usize - 1].iter().rev().take_while(|bit| **bit == sign).count() as u32;
+        Circuit::scope(name, || {
+            let candidate = a.leading_sign_bits();
+            assert_eq!(expected, *candidate.eject_value());
+            match mode.is_constant() {
+                true => assert_scope!(32, 0, 0, 0),
+                false => {
+                    let num_bits = (u64::BITS - (I::BITS - 1).leading_zeros()) as u64;
+                    assert_scope!(0, 0, 2 * I::BITS - 3 + num_bits, 2 * I::BITS - 2 + num_bits);
+                }
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        // Ensure unsigned integers are not supported.
+        if !I::is_signed() {
+            let a = Integer::<Circuit, I>::new(mode, console::Integer::zero());
+            check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.leading_sign_bits());
+            Circuit::reset();
+            return;
+        }
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("LeadingSignBits: {mode} {i}");
+            check_leading_sign_bits::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        // Check the extremes, where `0` and `-1` have `I::BITS - 1` sign bits, and `I::MIN` and `I::MAX` have none.
+        let boundaries = [
+            console::Integer::zero(),
+            console::Integer::one(),
+            -console::Integer::one(),
+            console::Integer::MIN,
+            console::Integer::MAX,
+            console::Integer::new(I::MAX.wrapping_shr(1)),
+            console::Integer::new(I::MIN.wrapping_shr(1)),
+        ];
+        for (i, value) in boundaries.into_iter().enumerate() {
+            check_leading_sign_bits::<I>(&format!("LeadingSignBits: {mode} boundary {i}"), value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, leading_sign_bits);
+    test_integer_unary!(run_test, i16, leading_sign_bits);
+    test_integer_unary!(run_test, i32, leading_sign_bits);
+    test_integer_unary!(run_test, i64, leading_sign_bits);
+    test_integer_unary!(run_test, i128, leading_sign_bits);
+
+    test_integer_unary!(run_test, u8, leading_sign_bits);
+    test_integer_unary!(run_test, u16, leading_sign_bits);
+    test_integer_unary!(run_test, u32, leading_sign_bits);
+    test_integer_unary!(run_test, u64, leading_sign_bits);
+    test_integer_unary!(run_test, u128, leading_sign_bits);
+}
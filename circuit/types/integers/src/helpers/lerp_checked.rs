@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use super::mul_div_checked::truncate_native;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the linear interpolation `self + (b - self) * t / scale`, where the intermediate values
+    /// are computed in the base field without overflow, and only the result is range checked.
+    ///
+    /// The quotient `(b - self) * t / scale` rounds towards negative infinity, so `t == 0` returns `self`,
+    /// and `t == scale` returns `b`. The operands may be signed, and `t` may lie outside of `[0, scale]`
+    /// to extrapolate, as long as the result fits in `I`.
+    /// This method halts on a constant scale that is not positive, or a constant overflow of the result,
+    /// and fails to satisfy the circuit otherwise.
+    ///
+    /// This method should only be used when 2 * I::BITS < E::BaseField::size_in_data_bits(), and halts otherwise.
+    /// For variable operands, this method uses `3 * I::BITS + 5` constraints for unsigned integers,
+    /// and `3 * I::BITS + 9` constraints for signed integers.
+    pub fn lerp_checked(&self, b: &Self, t: &Self, scale: &Self) -> Self {
+        // Ensure the product of two integers fits in the base field.
+        if 2 * I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            E::halt(format!("Linear interpolation of integers of size {} is not supported", I::BITS))
+        }
+
+        // If the scale is a constant and is not positive, then halt.
+        if scale.is_constant() && *scale.eject_value() <= I::zero() {
+            E::halt("Attempted to interpolate with a scale that is not positive.")
+        }
+
+        // Eject the operands, to compute the result and remainder.
+        let values = lerp_native(*self.eject_value(), *b.eject_value(), *t.eject_value(), *scale.eject_value());
+
+        if self.is_constant() && b.is_constant() && t.is_constant() && scale.is_constant() {
+            // Return the result as a new constant.
+            match values {
+                Some((value, _)) => Integer::constant(console::Integer::new(value)),
+                None => E::halt("Integer overflow on linear interpolation of constants"),
+            }
+        } else {
+            // Note: If the scale is not positive, or the result overflows, the witnesses are zero,
+            // and fail the checks below.
+            let (result, remainder) = values.unwrap_or((I::zero(), I::zero()));
+            // Note: Initializing the result as an integer is the only range check on the result.
+            let result = Integer::new(Mode::Private, console::Integer::new(result));
+            let remainder = Integer::new(Mode::Private, console::Integer::new(remainder));
+
+            // Ensure that the remainder is non-negative, and less than the scale.
+            // Note that if this check is satisfied, then the scale is positive.
+            if I::is_signed() {
+                E::assert_eq(remainder.msb(), E::zero());
+            }
+            E::assert(remainder.is_less_than(scale));
+
+            // Ensure that `(b - self) * t == (result - self) * scale + remainder` holds in the base field.
+            // Note that both sides are less than 2^(2 * I::BITS + 2) in absolute value, so neither side wraps around
+            // the modulus. In particular, a result that overflows `I` cannot satisfy this equation.
+            let product = (b.to_signed_field() - self.to_signed_field()) * t.to_signed_field();
+            E::enforce(|| {
+                (result.to_signed_field() - self.to_signed_field(), scale.to_field(), product - remainder.to_field())
+            });
+
+            result
+        }
+    }
+
+    /// Returns the value of the integer as a field element, where a negative integer `-x` is `p - x`.
+    /// This method uses 0 constraints.
+    fn to_signed_field(&self) -> Field<E> {
+        match I::is_signed() {
+            // Subtract `2^I::BITS` if the integer is negative.
+            true => {
+                let modulus = Field::constant(console::Field::from_u128(1u128 << I::BITS));
+                self.to_field() - Field::from_boolean(self.msb()) * modulus
+            }
+            false => self.to_field(),
+        }
+    }
+}
+
+/// Returns `(a + floor((b - a) * t / scale), remainder)`, computed in 128-bit integers,
+/// or `None` if the scale is not positive or the result does not fit in `I`.
+/// This method assumes `I::BITS <= 64`, so that the magnitude of the product cannot overflow.
+fn lerp_native<I: IntegerType>(a: I, b: I, t: I, scale: I) -> Option<(I, I)> {
+    let (a, b, t, scale) = (a.to_i128()?, b.to_i128()?, t.to_i128()?, scale.to_i128()?);
+    if scale <= 0 {
+        return None;
+    }
+    // Compute the product as a sign and magnitude, as it may not fit in a signed 128-bit integer.
+    let difference = b - a;
+    let is_negative = (difference < 0) != (t < 0);
+    let magnitude = difference.unsigned_abs().checked_mul(t.unsigned_abs())?;
+    let scale = scale as u128;
+    // Compute the floored quotient, and the non-negative remainder.
+    let (quotient, remainder) = match is_negative && magnitude % scale != 0 {
+        true => (magnitude / scale + 1, scale - magnitude % scale),
+        false => (magnitude / scale, magnitude % scale),
+    };
+    let quotient = i128::try_from(quotient).ok()?;
+    let quotient = if is_negative { -quotient } else { quotient };
+    // Ensure the result fits in `I`.
+    let result = a.checked_add(quotient)?;
+    if result < I::MIN.to_i128()? || result > I::MAX.to_i128()? {
+        return None;
+    }
+    // Note: This cast preserves the two's complement representation of the result.
+    Some((truncate_native::<I>(result as u128), truncate_native::<I>(remainder)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns `a + floor((b - a) * t / scale)`, computed directly in a signed 128-bit integer,
+    /// or `None` if the product overflows, the scale is not positive, or the result does not fit in `I`.
+    fn reference_lerp<I: IntegerType>(a: I, b: I, t: I, scale: I) -> Option<I> {
+        let (a, b, t, scale) = (a.to_i128()?, b.to_i128()?, t.to_i128()?, scale.to_i128()?);
+        if scale <= 0 {
+            return None;
+        }
+        let result = a.checked_add((b - a).checked_mul(t)?.div_euclid(scale))?;
+        if result < I::MIN.to_i128()? || result > I::MAX.to_i128()? {
+            return None;
+        }
+        Some(truncate_native::<I>(result as u128))
+    }
+
+    fn check_lerp<I: IntegerType + RefUnwindSafe>(name: &str, values: [I; 4], mode: Mode, scale_mode: Mode) {
+        let [a, b, t, scale] = values;
+        let expected = lerp_native(a, b, t, scale).map(|(result, _)| result);
+        // Ensure the expected result matches the direct computation, when the product fits.
+        if (b.to_i128().unwrap() - a.to_i128().unwrap()).checked_mul(t.to_i128().unwrap()).is_some() {
+            assert_eq!(reference_lerp(a, b, t, scale), expected);
+        }
+
+        let [a, b, t] = [a, b, t].map(|value| Integer::<Circuit, I>::new(mode, console::Integer::new(value)));
+        let scale = Integer::<Circuit, I>::new(scale_mode, console::Integer::new(scale));
+        match expected {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.lerp_checked(&b, &t, &scale);
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                // Check the documented number of constraints.
+                match (mode, scale_mode) {
+                    (Mode::Constant, Mode::Constant) => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                    (Mode::Constant, _) | (_, Mode::Constant) => {}
+                    (_, _) => {
+                        let expected_num_constraints = if I::is_signed() { 3 * I::BITS + 9 } else { 3 * I::BITS + 5 };
+                        assert_eq!(expected_num_constraints, Circuit::num_constraints_in_scope());
+                    }
+                }
+            }),
+            None => match (mode, scale_mode) {
+                (Mode::Constant, Mode::Constant) => {
+                    check_operation_halts((&a, &b), (&t, &scale), |(a, b), (t, scale)| a.lerp_checked(b, t, scale))
+                }
+                (_, Mode::Constant) if *scale.eject_value() <= I::zero() => {
+                    check_operation_halts((&a, &b), (&t, &scale), |(a, b), (t, scale)| a.lerp_checked(b, t, scale))
+                }
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.lerp_checked(&b, &t, &scale);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode, scale_mode: Mode) {
+        // Ensure that integers whose product does not fit in the base field are not supported.
+        if 2 * I::BITS >= <Circuit as Environment>::BaseField::size_in_data_bits() as u64 {
+            let a = Integer::<Circuit, I>::new(mode, console::Integer::one());
+            let scale = Integer::<Circuit, I>::new(scale_mode, console::Integer::one());
+            check_operation_halts((&a, &a), (&a, &scale), |(a, b), (t, scale)| a.lerp_checked(b, t, scale));
+            Circuit::reset();
+            return;
+        }
+
+        let mut rng = TestRng::default();
+
+        let one = I::one();
+        let two = one + one;
+        let ten = two * two * two + two;
+
+        for i in 0..ITERATIONS {
+            let [a, b, t, scale] = [0, 1, 2, 3].map(|_| *console::Integer::<CurrentNetwork, I>::rand(&mut rng));
+
+            // Check random operands, which typically overflow.
+            let name = format!("Lerp: {mode} {scale_mode} {i}");
+            check_lerp::<I>(&name, [a, b, t, scale], mode, scale_mode);
+
+            // Check the endpoints and the midpoint, with a positive scale.
+            if scale > I::zero() {
+                let name = format!("Lerp: {mode} {scale_mode} t == 0 {i}");
+                check_lerp::<I>(&name, [a, b, I::zero(), scale], mode, scale_mode);
+                let name = format!("Lerp: {mode} {scale_mode} t == scale {i}");
+                check_lerp::<I>(&name, [a, b, scale, scale], mode, scale_mode);
+                let name = format!("Lerp: {mode} {scale_mode} midpoint {i}");
+                check_lerp::<I>(&name, [a, b, scale.wrapping_shr(1), scale], mode, scale_mode);
+            }
+        }
+
+        // Check the endpoints and the midpoint, with small operands.
+        check_lerp::<I>("Lerp: 0 to 10 at 0 / 2", [I::zero(), ten, I::zero(), two], mode, scale_mode);
+        check_lerp::<I>("Lerp: 0 to 10 at 2 / 2", [I::zero(), ten, two, two], mode, scale_mode);
+        check_lerp::<I>("Lerp: 0 to 10 at 1 / 2", [I::zero(), ten, one, two], mode, scale_mode);
+        check_lerp::<I>("Lerp: 10 to 0 at 1 / 2", [ten, I::zero(), one, two], mode, scale_mode);
+        // Check the extremes of the integer type.
+        check_lerp::<I>("Lerp: MIN to MAX at 0 / MAX", [I::MIN, I::MAX, I::zero(), I::MAX], mode, scale_mode);
+        check_lerp::<I>("Lerp: MIN to MAX at MAX / MAX", [I::MIN, I::MAX, I::MAX, I::MAX], mode, scale_mode);
+        check_lerp::<I>("Lerp: MAX to MIN at MAX / MAX", [I::MAX, I::MIN, I::MAX, I::MAX], mode, scale_mode);
+        check_lerp::<I>("Lerp: MIN to MAX at 1 / 2", [I::MIN, I::MAX, one, two], mode, scale_mode);
+
+        // Check that extrapolating past the end of the integer type fails.
+        check_lerp::<I>("Lerp: MIN to MAX at 2 / 1", [I::MIN, I::MAX, two, one], mode, scale_mode);
+        // Check that a zero scale fails.
+        check_lerp::<I>("Lerp: 0 to 10 at 1 / 0", [I::zero(), ten, one, I::zero()], mode, scale_mode);
+
+        // Check the rounding and extrapolation for signed integers.
+        if I::is_signed() {
+            let neg_one = I::zero().wrapping_sub(&one);
+            let neg_three = I::zero().wrapping_sub(&(two + one));
+            let neg_ten = I::zero().wrapping_sub(&ten);
+            check_lerp::<I>("Lerp: -10 to 10 at 1 / 2", [neg_ten, ten, one, two], mode, scale_mode);
+            check_lerp::<I>("Lerp: 0 to -3 at 1 / 2", [I::zero(), neg_three, one, two], mode, scale_mode);
+            check_lerp::<I>("Lerp: 0 to 10 at -1 / 2", [I::zero(), ten, neg_one, two], mode, scale_mode);
+            // Check that a negative scale fails.
+            check_lerp::<I>("Lerp: 0 to 10 at 1 / -1", [I::zero(), ten, one, neg_one], mode, scale_mode);
+        }
+    }
+
+    test_integer_binary!(run_test, i8, lerp);
+    test_integer_binary!(run_test, i16, lerp);
+    test_integer_binary!(run_test, i32, lerp);
+    test_integer_binary!(run_test, i64, lerp);
+    test_integer_binary!(run_test, i128, lerp);
+
+    test_integer_binary!(run_test, u8, lerp);
+    test_integer_binary!(run_test, u16, lerp);
+    test_integer_binary!(run_test, u32, lerp);
+    test_integer_binary!(run_test, u64, lerp);
+    test_integer_binary!(run_test, u128, lerp);
+}
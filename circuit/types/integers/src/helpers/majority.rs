@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the bitwise majority `(a & b) ^ (a & c) ^ (b & c)` of the three integers,
+    /// as used in the `Maj` function of SHA-2.
+    ///
+    /// Each output bit is computed as `if a ^ b { c } else { a }`, which uses 2 constraints per bit.
+    /// For non-constant integers, this method uses `2 * I::BITS` constraints, compared to the `5 * I::BITS`
+    /// constraints of composing `&` and `^`. If one of the bits is constant, the output bit uses 1 constraint,
+    /// and if all of the integers are constant, this method uses 0 constraints.
+    pub fn majority(a: &Self, b: &Self, c: &Self) -> Self {
+        let bits_le = a
+            .bits_le
+            .iter()
+            .zip_eq(&b.bits_le)
+            .zip_eq(&c.bits_le)
+            .map(|((a, b), c)| {
+                // If any bit is constant, the majority is the AND (if `false`) or the OR (if `true`) of the others.
+                match [(a, b, c), (b, a, c), (c, a, b)].into_iter().find(|(bit, ..)| bit.is_constant()) {
+                    Some((constant, x, y)) => match constant.eject_value() {
+                        true => x | y,
+                        false => x & y,
+                    },
+                    // Otherwise, if the bits of `a` and `b` differ, the bit of `c` decides the majority.
+                    None => Boolean::ternary(&(a ^ b), c, a),
+                }
+            })
+            .collect();
+
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the reference `Maj` of SHA-2, computed on the native integers.
+    fn reference_majority<I: IntegerType>(a: I, b: I, c: I) -> I {
+        (a & b) ^ (a & c) ^ (b & c)
+    }
+
+    fn check_majority<I: IntegerType>(name: &str, values: [I; 3], modes: [Mode; 3]) {
+        let [a, b, c] = [0, 1, 2].map(|i| Integer::<Circuit, I>::new(modes[i], console::Integer::new(values[i])));
+        let expected = reference_majority(values[0], values[1], values[2]);
+
+        let num_composed_constraints = Circuit::scope(format!("{name} (composed)"), || {
+            let candidate = (&a & &b) ^ (&a & &c) ^ (&b & &c);
+            assert_eq!(expected, *candidate.eject_value());
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::scope(name, || {
+            let candidate = Integer::majority(&a, &b, &c);
+            assert_eq!(expected, *candidate.eject_value());
+            // Check the documented number of constraints, when the modes match.
+            if modes.iter().all(|mode| mode.is_constant()) {
+                assert_scope!(0, 0, 0, 0);
+            } else if modes.iter().all(|mode| !mode.is_constant()) {
+                assert_scope!(0, 0, 2 * I::BITS, 2 * I::BITS);
+            }
+            // Ensure the majority uses no more constraints than composing the individual operations.
+            assert!(Circuit::num_constraints_in_scope() <= num_composed_constraints);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let values = [0, 1, 2].map(|_| *console::Integer::<<Circuit as Environment>::Network, I>::rand(&mut rng));
+
+            // Check the majority with the same mode for every integer.
+            check_majority::<I>(&format!("Majority: {mode} {i}"), values, [mode; 3]);
+            // Check the majority with a constant third integer.
+            check_majority::<I>(&format!("Majority: {mode} constant {i}"), values, [mode, mode, Mode::Constant]);
+            // Check the majority when two of the integers are equal.
+            let [a, _, c] = values;
+            check_majority::<I>(&format!("Majority: {mode} equal {i}"), [a, a, c], [mode; 3]);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, majority);
+    test_integer_unary!(run_test, i16, majority);
+    test_integer_unary!(run_test, i32, majority);
+    test_integer_unary!(run_test, i64, majority);
+    test_integer_unary!(run_test, i128, majority);
+
+    test_integer_unary!(run_test, u8, majority);
+    test_integer_unary!(run_test, u16, majority);
+    test_integer_unary!(run_test, u32, majority);
+    test_integer_unary!(run_test, u64, majority);
+    test_integer_unary!(run_test, u128, majority);
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a list of integers from the given little-endian bits, where each integer is initialized
+    /// from a contiguous chunk of `I::BITS` bits. This is the inverse of concatenating `to_bits_le` over each integer.
+    ///
+    /// This method returns an error if the number of bits is not a multiple of `I::BITS`.
+    /// Otherwise, this method uses 0 constraints.
+    pub fn many_from_bits_le(bits_le: &[Boolean<E>]) -> Result<Vec<Self>> {
+        // Ensure the number of bits is a multiple of the integer size.
+        ensure!(
+            bits_le.len() as u64 % I::BITS == 0,
+            "Cannot initialize '{}' integers from {} bits, as it is not a multiple of {}",
+            I::type_name(),
+            bits_le.len(),
+            I::BITS
+        );
+        // Initialize an integer from each chunk of bits.
+        Ok(bits_le
+            .chunks(I::BITS as usize)
+            .map(|bits| Self { bits_le: bits.to_vec(), phantom: Default::default() })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a list of random integers.
+            let expected = (0..i % 8).map(|_| Uniform::rand(&mut rng)).collect::<Vec<console::Integer<_, I>>>();
+            let given_bits = expected
+                .iter()
+                .flat_map(|value| Integer::<Circuit, I>::new(mode, *value).to_bits_le())
+                .collect::<Vec<_>>();
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidates = Integer::<Circuit, I>::many_from_bits_le(&given_bits).unwrap();
+                assert_eq!(expected, candidates.iter().map(|candidate| candidate.eject_value()).collect::<Vec<_>>());
+                // Ensure each integer round-trips to its chunk of bits.
+                for (candidate, bits) in candidates.iter().zip_eq(given_bits.chunks(I::BITS as usize)) {
+                    assert_eq!(bits.eject_value(), candidate.to_bits_le().eject_value());
+                }
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+
+        // Ensure a number of bits that is not a multiple of the integer size is rejected.
+        let bits = vec![Boolean::<Circuit>::new(mode, false); 2 * I::BITS as usize + 1];
+        for num_bits in [1, I::BITS as usize - 1, I::BITS as usize + 1, 2 * I::BITS as usize + 1] {
+            assert!(Integer::<Circuit, I>::many_from_bits_le(&bits[..num_bits]).is_err());
+        }
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, many_from_bits_le);
+    test_integer_unary!(run_test, i16, many_from_bits_le);
+    test_integer_unary!(run_test, i32, many_from_bits_le);
+    test_integer_unary!(run_test, i64, many_from_bits_le);
+    test_integer_unary!(run_test, i128, many_from_bits_le);
+
+    test_integer_unary!(run_test, u8, many_from_bits_le);
+    test_integer_unary!(run_test, u16, many_from_bits_le);
+    test_integer_unary!(run_test, u32, many_from_bits_le);
+    test_integer_unary!(run_test, u64, many_from_bits_le);
+    test_integer_unary!(run_test, u128, many_from_bits_le);
+}
@@ -14,13 +14,56 @@
 
 use super::*;
 
+pub mod add_limbs_checked;
+pub mod assert_fits_in_bits;
+pub mod bit_parity;
+pub mod choose;
+pub mod count_ones;
+pub mod decimal_digit_sum;
+pub mod div_rem_checked;
+pub mod extract_bits;
+pub mod floor_log_checked;
 pub mod from_bits;
 pub mod from_field;
 pub mod from_field_lossy;
+pub mod gcd;
+pub mod in_range;
+pub mod leading_sign_bits;
+pub mod lerp_checked;
+pub mod majority;
+pub mod many_from_bits;
 pub mod msb;
+pub mod mul_by_constant_checked;
+pub mod mul_div_checked;
 pub mod one;
+pub mod parity;
+pub mod permute_bits;
+pub mod popcount_cmp;
+pub mod rotate_left_with_feedback;
+pub mod saturating_cast;
+pub mod shr_with_carry;
+pub mod sigma;
+pub mod signum;
+pub mod sum_wrapping_ct;
+pub mod sum_wrapping_flagged;
 pub mod to_bits;
+pub mod to_bits_sign_extended;
+pub mod to_dual_checked;
 pub mod to_field;
 pub mod to_fields;
 pub mod to_scalar;
+pub mod trailing_ones;
+pub mod twos_complement;
+pub mod with_field_set;
 pub mod zero;
+
+/// Returns the sum of the given field elements as a `u32` integer, given that the sum is at most `max_sum`.
+///
+/// The sum is computed in the base field, where it cannot wrap, as `max_sum` is far smaller than the field.
+/// For a non-constant sum, this method uses `k + 1` constraints, where `k` is the bit length of `max_sum`.
+pub(crate) fn sum_as_u32<E: Environment>(terms: impl IntoIterator<Item = Field<E>>, max_sum: u64) -> Integer<E, u32> {
+    let sum = terms.into_iter().fold(Field::zero(), |sum, term| sum + term);
+    // Extract the bits of the sum, which has at most the bit length of `max_sum`.
+    let num_bits = (u64::BITS - max_sum.leading_zeros()) as usize;
+    Integer::from_bits_le(&sum.to_lower_bits_le(num_bits))
+}
@@ -0,0 +1,251 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the product of `self` and the constant `c`, halting (for a constant `self`)
+    /// or failing to satisfy the circuit (for a variable `self`) on overflow.
+    ///
+    /// The product is computed as a shift-and-add over the set bits of `c`, which is a free
+    /// linear combination in the base field, followed by a single overflow check.
+    /// For an unsigned variable `self`, and `n` the bit length of `c`, this method uses:
+    ///   - `0` constraints if `c` is `0` or `1`,
+    ///   - `1` constraint if `c` is any other power of two (e.g. `c = 2^8`),
+    ///   - `I::BITS + 1` constraints if `I::BITS + n` fits in the base field (e.g. `u128` by `10` uses 129,
+    ///     versus 195 for `mul_checked`),
+    ///   - `I::BITS + 2` constraints otherwise, as `self` is first bounded so that the product fits.
+    ///
+    /// For a signed variable `self`, the product of the absolute values is computed as above,
+    /// and the sign is restored as in `mul_checked`.
+    pub fn mul_by_constant_checked(&self, c: I) -> Self {
+        // Determine the variable mode.
+        if self.is_constant() {
+            // Compute the product and return the new constant.
+            match self.eject_value().checked_mul(&c) {
+                Some(value) => Integer::new(Mode::Constant, console::Integer::new(value)),
+                None => E::halt("Integer overflow on multiplication of a constant by a constant"),
+            }
+        } else if I::is_signed() {
+            // Compute the product of `abs(self)` and `abs(c)`, while checking for an overflow.
+            // Note: it is safe to use `wrapping_abs` as we want `Integer::MIN` to be interpreted as an unsigned number.
+            let product = Self::mul_by_magnitude_and_check(&self.abs_wrapped(), &c.wrapping_abs().to_bits_le());
+
+            // Determine if the operands have the same sign.
+            let operands_same_sign = &match c < I::zero() {
+                true => self.msb().clone(),
+                false => !self.msb(),
+            };
+
+            // If the product should be positive, then it cannot exceed the signed maximum.
+            let positive_product_overflows = operands_same_sign & product.msb();
+            E::assert_eq(positive_product_overflows, E::zero());
+
+            // If the product should be negative, then it cannot exceed the absolute value of the signed minimum.
+            let negative_product_underflows = {
+                let lower_product_bits_nonzero =
+                    product.bits_le[..(I::BITS as usize - 1)].iter().fold(Boolean::constant(false), |a, b| a | b);
+                let negative_product_lt_or_eq_signed_min =
+                    !product.msb() | (product.msb() & !lower_product_bits_nonzero);
+                !operands_same_sign & !negative_product_lt_or_eq_signed_min
+            };
+            E::assert_eq(negative_product_underflows, E::zero());
+
+            // Note that the relevant overflow cases are checked independently above.
+            // Return the product of `self` and `c` with the appropriate sign.
+            Self::ternary(operands_same_sign, &product, &Self::zero().sub_wrapped(&product))
+        } else {
+            // Compute the product of `self` and `c`, while checking for an overflow.
+            Self::mul_by_magnitude_and_check(self, &c.to_bits_le())
+        }
+    }
+
+    /// Multiply the integer bits of `this` by the constant `magnitude`, while checking for an overflow.
+    /// This function assumes that `this` and `magnitude` are non-negative.
+    #[inline]
+    fn mul_by_magnitude_and_check(this: &Integer<E, I>, magnitude_bits_le: &[bool]) -> Integer<E, I> {
+        // Compute the bit length of the magnitude.
+        let num_bits = match magnitude_bits_le.iter().rposition(|bit| *bit) {
+            Some(index) => index + 1,
+            // If the magnitude is zero, the product is zero.
+            None => return Self::zero(),
+        };
+
+        // Case 1 - The magnitude is a power of two, so the product is a left shift of `this`.
+        if magnitude_bits_le.iter().filter(|bit| **bit).count() == 1 {
+            let shift = num_bits - 1;
+            // Check that the bits shifted out are zero.
+            Boolean::assert_bits_are_zero(&this.bits_le[(I::BITS as usize - shift)..]);
+            // Return the shifted bits.
+            let mut bits_le = vec![Boolean::constant(false); shift];
+            bits_le.extend_from_slice(&this.bits_le[..(I::BITS as usize - shift)]);
+            return Integer::from_bits_le(&bits_le);
+        }
+
+        // Case 2 - The product may not fit in 1 field element, so bound `this` to ensure it does.
+        // If any bit of `this` at or above `I::BITS - num_bits + 1` is set, then the product is at least `2^I::BITS`.
+        if I::BITS as usize + num_bits >= E::BaseField::size_in_bits() - 1 {
+            Boolean::assert_bits_are_zero(&this.bits_le[(I::BITS as usize - num_bits + 1)..]);
+        }
+
+        // Compute the product as a shift-and-add over the set bits of the magnitude, in the base field.
+        let this = this.to_field();
+        let mut product = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in magnitude_bits_le {
+            if *bit {
+                product += &this * &coefficient;
+            }
+            coefficient = coefficient.double();
+        }
+
+        // Check that the product fits in `I::BITS` bits, and return it.
+        Integer::from_bits_le(&product.to_lower_bits_le(I::BITS as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the documented number of constraints to multiply a variable unsigned integer by `c`.
+    fn expected_num_constraints<I: IntegerType>(c: I) -> u64 {
+        let num_bits = I::BITS - c.leading_zeros() as u64;
+        if c <= I::one() {
+            0
+        } else if c.count_ones() == 1 {
+            1
+        } else if I::BITS + num_bits >= <Circuit as Environment>::BaseField::size_in_bits() as u64 - 1 {
+            I::BITS + 2
+        } else {
+            I::BITS + 1
+        }
+    }
+
+    fn check_mul_by_constant<I: IntegerType + UnwindSafe>(name: &str, first: I, c: I, mode: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::new(first));
+        match first.checked_mul(&c) {
+            Some(expected) => {
+                // Compute the number of constraints for the general multiplier.
+                let b = Integer::<Circuit, I>::constant(console::Integer::new(c));
+                let num_general_constraints = Circuit::scope(format!("{name} (general)"), || {
+                    assert_eq!(expected, *a.mul_checked(&b).eject_value());
+                    Circuit::num_constraints_in_scope()
+                });
+                Circuit::reset();
+
+                Circuit::scope(name, || {
+                    let candidate = a.mul_by_constant_checked(c);
+                    assert_eq!(expected, *candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                    // Ensure the constant multiplier is no more expensive than the general multiplier.
+                    assert!(Circuit::num_constraints_in_scope() <= num_general_constraints);
+                    if mode.is_constant() {
+                        assert_scope!(I::BITS, 0, 0, 0);
+                    } else if !I::is_signed() {
+                        assert_eq!(expected_num_constraints(c), Circuit::num_constraints_in_scope());
+                    }
+                });
+            }
+            None => match mode {
+                Mode::Constant => {
+                    check_unary_operation_halts(a, move |a: Integer<Circuit, I>| a.mul_by_constant_checked(c))
+                }
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.mul_by_constant_checked(c);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample the representative constants.
+        let two = I::one() + I::one();
+        let ten = (two + two) * two + two;
+        let mut constants = vec![I::zero(), I::one(), two, ten, I::one() << 7, I::MAX, I::MIN];
+        if I::is_signed() {
+            constants.extend([I::zero() - I::one(), I::zero() - ten, I::MIN + I::one()]);
+        }
+
+        for i in 0..ITERATIONS {
+            let value: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            let c: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            // Reduce the value to avoid overflowing in most cases.
+            let small = *value >> (I::BITS as usize / 2);
+
+            let name = format!("MulByConstant: {mode} {i}");
+            check_mul_by_constant::<I>(&name, *value, *c, mode);
+            for constant in &constants {
+                check_mul_by_constant::<I>(&name, *value, *constant, mode);
+                check_mul_by_constant::<I>(&name, small, *constant, mode);
+            }
+        }
+
+        // Check the boundary values.
+        for value in [I::zero(), I::one(), I::MAX, I::MIN] {
+            for constant in &constants {
+                check_mul_by_constant::<I>("MulByConstant: boundary", value, *constant, mode);
+            }
+        }
+    }
+
+    /// Returns the number of constraints used to compute `value * c` with the given multiplier.
+    fn count_constraints(value: u128, c: u128, multiply: impl Fn(&U128<Circuit>, u128) -> U128<Circuit>) -> u64 {
+        let a = U128::<Circuit>::new(Mode::Private, console::Integer::new(value));
+        let num_constraints = Circuit::scope("count", || {
+            assert_eq!(value * c, *multiply(&a, c).eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+        num_constraints
+    }
+
+    #[test]
+    fn test_u128_representative_constants() {
+        let mul_by_constant = |a: &U128<Circuit>, c: u128| a.mul_by_constant_checked(c);
+        let mul_checked = |a: &U128<Circuit>, c: u128| a.mul_checked(&U128::constant(console::Integer::new(c)));
+
+        // Ensure a power of two uses 1 constraint.
+        assert_eq!(1, count_constraints(12345, 1 << 8, mul_by_constant));
+        // Ensure multiplying by 10 uses 129 constraints, versus 195 for `mul_checked`.
+        assert_eq!(129, count_constraints(12345, 10, mul_by_constant));
+        assert_eq!(195, count_constraints(12345, 10, mul_checked));
+        // Ensure a constant that requires bounding `self` uses `I::BITS + 2` constraints.
+        assert_eq!(130, count_constraints(1, u128::MAX, mul_by_constant));
+    }
+
+    test_integer_unary!(run_test, i8, mul_by_constant_checked);
+    test_integer_unary!(run_test, i16, mul_by_constant_checked);
+    test_integer_unary!(run_test, i32, mul_by_constant_checked);
+    test_integer_unary!(run_test, i64, mul_by_constant_checked);
+    test_integer_unary!(run_test, i128, mul_by_constant_checked);
+
+    test_integer_unary!(run_test, u8, mul_by_constant_checked);
+    test_integer_unary!(run_test, u16, mul_by_constant_checked);
+    test_integer_unary!(run_test, u32, mul_by_constant_checked);
+    test_integer_unary!(run_test, u64, mul_by_constant_checked);
+    test_integer_unary!(run_test, u128, mul_by_constant_checked);
+}
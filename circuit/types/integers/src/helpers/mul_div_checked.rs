@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self * numerator / denominator`, where the product is computed without intermediate overflow.
+    ///
+    /// The quotient rounds towards zero, matching `div_checked`.
+    /// Unlike `self.mul_checked(numerator).div_checked(denominator)`, this method succeeds
+    /// whenever the final quotient fits in `I`, even if the product does not.
+    /// This method halts on a constant zero denominator or a constant overflow of the quotient,
+    /// and fails to satisfy the circuit otherwise.
+    ///
+    /// This method should only be used when 2 * I::BITS < E::BaseField::size_in_data_bits(), and halts otherwise.
+    /// For unsigned integers with variable operands, this method uses `3 * I::BITS + 6` constraints,
+    /// and signed integers additionally pay for the `abs_wrapped` of each operand and the sign correction.
+    pub fn mul_div_checked(&self, numerator: &Self, denominator: &Self) -> Self {
+        // Ensure the product of two integers fits in the base field.
+        if 2 * I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            E::halt(format!("Multiplication and division of integers of size {} is not supported", I::BITS))
+        }
+
+        // If the denominator is a constant and is zero, then halt.
+        if denominator.is_constant() && denominator.eject_value().is_zero() {
+            E::halt("Attempted to divide by zero.")
+        }
+
+        if self.is_constant() && numerator.is_constant() && denominator.is_constant() {
+            // Compute the quotient in a wider integer, and return the new constant.
+            match mul_div_native(*self.eject_value(), *numerator.eject_value(), *denominator.eject_value()) {
+                Some(value) => Integer::constant(console::Integer::new(value)),
+                None => E::halt("Integer overflow on multiplication and division of constants"),
+            }
+        } else if I::is_signed() {
+            // Compute the quotient of the absolute values, as unsigned integers.
+            // Note: it is safe to use `abs_wrapped` as we want `Integer::MIN` to be interpreted as an unsigned number.
+            let unsigned_self = self.abs_wrapped().cast_as_dual();
+            let unsigned_numerator = numerator.abs_wrapped().cast_as_dual();
+            // Note that `unsigned_denominator` is zero iff `denominator` is zero.
+            let unsigned_denominator = denominator.abs_wrapped().cast_as_dual();
+            let unsigned_quotient =
+                unsigned_self.unsigned_mul_div_via_witness(&unsigned_numerator, &unsigned_denominator);
+            let quotient = Self { bits_le: unsigned_quotient.bits_le, phantom: Default::default() };
+
+            // The quotient is positive iff an even number of the operands are negative.
+            let is_positive = !(self.msb() ^ numerator.msb() ^ denominator.msb());
+
+            // If the quotient should be positive, then it cannot exceed the signed maximum.
+            let positive_quotient_overflows = &is_positive & quotient.msb();
+            E::assert_eq(positive_quotient_overflows, E::zero());
+
+            // If the quotient should be negative, then it cannot exceed the absolute value of the signed minimum.
+            let negative_quotient_underflows = {
+                let lower_quotient_bits_nonzero =
+                    quotient.bits_le[..(I::BITS as usize - 1)].iter().fold(Boolean::constant(false), |a, b| a | b);
+                !&is_positive & quotient.msb() & lower_quotient_bits_nonzero
+            };
+            E::assert_eq(negative_quotient_underflows, E::zero());
+
+            // Return the quotient with the appropriate sign.
+            Self::ternary(&is_positive, &quotient, &Self::zero().sub_wrapped(&quotient))
+        } else {
+            // Note that this checks that `denominator` is not zero, and that the quotient does not overflow.
+            self.unsigned_mul_div_via_witness(numerator, denominator)
+        }
+    }
+
+    /// Returns `self * numerator / denominator`, via witnesses.
+    /// This method should only be used when 2 * I::BITS < E::BaseField::size_in_data_bits().
+    /// This method assumes the operands are unsigned integers.
+    fn unsigned_mul_div_via_witness(&self, numerator: &Self, denominator: &Self) -> Self {
+        // Eject the operands, to compute the quotient and remainder as witnesses.
+        let (quotient, remainder) = match (
+            self.eject_value().to_u128(),
+            numerator.eject_value().to_u128(),
+            denominator.eject_value().to_u128(),
+        ) {
+            (Some(first), Some(second), Some(divisor)) => {
+                // Note: The divisor is replaced with 1 when it is 0, to prevent a panic.
+                // This case is rejected by the remainder check below.
+                let divisor = divisor.max(1);
+                let product = first * second;
+                (truncate_native::<I>(product / divisor), truncate_native::<I>(product % divisor))
+            }
+            _ => E::halt("Failed to widen the operands of an unsigned multiplication and division"),
+        };
+        let quotient = Integer::new(Mode::Private, console::Integer::new(quotient));
+        let remainder = Integer::new(Mode::Private, console::Integer::new(remainder));
+
+        // Ensure that `self * numerator == quotient * denominator + remainder` holds in the base field.
+        // Note that both sides are less than 2^(2 * I::BITS + 1), so neither side wraps around the modulus.
+        // In particular, a quotient that overflows `I` cannot satisfy this equation.
+        E::assert_eq(
+            self.to_field() * numerator.to_field(),
+            quotient.to_field() * denominator.to_field() + remainder.to_field(),
+        );
+
+        // Ensure that the remainder is less than the denominator.
+        // Note that if this check is satisfied, then `denominator` is not zero.
+        E::assert(remainder.is_less_than(denominator));
+
+        quotient
+    }
+}
+
+/// Returns `first * second / divisor`, computed in a 128-bit integer,
+/// or `None` if the divisor is zero or the quotient does not fit in `I`.
+/// This method assumes `I::BITS <= 64`, so that the product cannot overflow.
+fn mul_div_native<I: IntegerType>(first: I, second: I, divisor: I) -> Option<I> {
+    let quotient = match I::is_signed() {
+        true => {
+            let quotient = first.to_i128()?.checked_mul(second.to_i128()?)?.checked_div(divisor.to_i128()?)?;
+            if quotient < I::MIN.to_i128()? || quotient > I::MAX.to_i128()? {
+                return None;
+            }
+            // Note: This cast preserves the two's complement representation of the quotient.
+            quotient as u128
+        }
+        false => {
+            let quotient = first.to_u128()?.checked_mul(second.to_u128()?)?.checked_div(divisor.to_u128()?)?;
+            if quotient > I::MAX.to_u128()? {
+                return None;
+            }
+            quotient
+        }
+    };
+    Some(truncate_native::<I>(quotient))
+}
+
+/// Returns the lower `I::BITS` bits of the given value, as an `I`.
+pub(super) fn truncate_native<I: IntegerType>(value: u128) -> I {
+    let bits_le = (0..I::BITS).map(|i| (value >> i) & 1 == 1).collect::<Vec<_>>();
+    // Note: `from_bits_le` does not fail, as exactly `I::BITS` bits are provided.
+    I::from_bits_le(&bits_le).unwrap_or_else(|_| I::zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_mul_div<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: I,
+        second: I,
+        divisor: I,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::new(first));
+        let b = Integer::<Circuit, I>::new(mode_a, console::Integer::new(second));
+        let d = Integer::<Circuit, I>::new(mode_b, console::Integer::new(divisor));
+        match mul_div_native(first, second, divisor) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.mul_div_checked(&b, &d);
+                assert_eq!(expected, *candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                match (mode_a, mode_b) {
+                    (Mode::Constant, Mode::Constant) => assert_scope!(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) => {}
+                    (_, Mode::Constant) => {}
+                    // Pin the cost of the unsigned case, with variable operands.
+                    (_, _) if !I::is_signed() => assert_scope!(I::BITS, 0, 3 * I::BITS + 3, 3 * I::BITS + 6),
+                    (_, _) => {}
+                }
+            }),
+            None => match (mode_a, mode_b) {
+                (Mode::Constant, Mode::Constant) => {
+                    check_operation_halts((&a, &b), &d, |(a, b), d| a.mul_div_checked(b, d))
+                }
+                (_, Mode::Constant) if divisor.is_zero() => {
+                    check_operation_halts((&a, &b), &d, |(a, b), d| a.mul_div_checked(b, d))
+                }
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.mul_div_checked(&b, &d);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        // Ensure that integers whose product does not fit in the base field are not supported.
+        if 2 * I::BITS >= <Circuit as Environment>::BaseField::size_in_data_bits() as u64 {
+            let a = Integer::<Circuit, I>::new(mode_a, console::Integer::one());
+            let d = Integer::<Circuit, I>::new(mode_b, console::Integer::one());
+            check_operation_halts((&a, &a), &d, |(a, b), d| a.mul_div_checked(b, d));
+            Circuit::reset();
+            return;
+        }
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            let second: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            let divisor: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+
+            let name = format!("MulDiv: {mode_a} * {mode_a} / {mode_b} {i}");
+            check_mul_div::<I>(&name, *first, *second, *divisor, mode_a, mode_b);
+
+            // Divide by the first operand, so that the quotient fits even though the product may not.
+            let name = format!("MulDiv by operand: {mode_a} * {mode_a} / {mode_b} {i}");
+            check_mul_div::<I>(&name, *first, *second, *first, mode_a, mode_b);
+        }
+
+        // Check that the quotient fits even when the product exceeds `I::MAX`.
+        let two = I::one() + I::one();
+        check_mul_div::<I>("MAX * MAX / MAX", I::MAX, I::MAX, I::MAX, mode_a, mode_b);
+        check_mul_div::<I>("MAX * 2 / 2", I::MAX, two, two, mode_a, mode_b);
+        check_mul_div::<I>("MAX * 2 / 3", I::MAX, two, two + I::one(), mode_a, mode_b);
+
+        // Check that a quotient exceeding `I::MAX` fails.
+        check_mul_div::<I>("MAX * 2 / 1", I::MAX, two, I::one(), mode_a, mode_b);
+
+        // Check that a zero denominator fails.
+        check_mul_div::<I>("1 * 1 / 0", I::one(), I::one(), I::zero(), mode_a, mode_b);
+        check_mul_div::<I>("0 * 0 / 0", I::zero(), I::zero(), I::zero(), mode_a, mode_b);
+
+        // Check the sign conventions and boundaries for signed integers.
+        if I::is_signed() {
+            let neg_one = I::zero().wrapping_sub(&I::one());
+            let neg_two = I::zero().wrapping_sub(&two);
+            let neg_seven = I::zero().wrapping_sub(&(two * two + two + I::one()));
+            check_mul_div::<I>("MIN * MIN / MIN", I::MIN, I::MIN, I::MIN, mode_a, mode_b);
+            check_mul_div::<I>("MIN * 2 / -2", I::MIN, two, neg_two, mode_a, mode_b);
+            check_mul_div::<I>("MIN * -1 / 1", I::MIN, neg_one, I::one(), mode_a, mode_b);
+            check_mul_div::<I>("MIN * 1 / -1", I::MIN, I::one(), neg_one, mode_a, mode_b);
+            check_mul_div::<I>("MIN * -1 / -1", I::MIN, neg_one, neg_one, mode_a, mode_b);
+            check_mul_div::<I>("MAX * -1 / 1", I::MAX, neg_one, I::one(), mode_a, mode_b);
+            check_mul_div::<I>("-7 * 3 / 2", neg_seven, two + I::one(), two, mode_a, mode_b);
+            check_mul_div::<I>("-7 * 3 / -2", neg_seven, two + I::one(), neg_two, mode_a, mode_b);
+        }
+    }
+
+    test_integer_binary!(run_test, i8, mul_div);
+    test_integer_binary!(run_test, i16, mul_div);
+    test_integer_binary!(run_test, i32, mul_div);
+    test_integer_binary!(run_test, i64, mul_div);
+    test_integer_binary!(run_test, i128, mul_div);
+
+    test_integer_binary!(run_test, u8, mul_div);
+    test_integer_binary!(run_test, u16, mul_div);
+    test_integer_binary!(run_test, u32, mul_div);
+    test_integer_binary!(run_test, u64, mul_div);
+    test_integer_binary!(run_test, u128, mul_div);
+}
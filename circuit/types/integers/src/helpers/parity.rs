@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `true` if the integer is odd.
+    ///
+    /// This method uses 0 constraints, and the output has the same mode as the least significant bit.
+    pub fn is_odd(&self) -> Boolean<E> {
+        match self.bits_le.first() {
+            Some(lsb) => lsb.clone(),
+            // Note: `E::halt` should never be invoked as `self.bits_le.len()` is greater than zero.
+            None => E::halt("Malformed integer detected while retrieving the LSB"),
+        }
+    }
+
+    /// Returns `true` if the integer is even.
+    ///
+    /// This method uses 0 constraints, and the output is constant iff the integer is constant.
+    pub fn is_even(&self) -> Boolean<E> {
+        !self.is_odd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_parity<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected_is_odd = *value % (I::one() + I::one()) != I::zero();
+        Circuit::scope(name, || {
+            let is_odd = a.is_odd();
+            let is_even = a.is_even();
+            assert_eq!(expected_is_odd, is_odd.eject_value());
+            assert_eq!(!expected_is_odd, is_even.eject_value());
+            assert_eq!(mode, is_odd.eject_mode());
+            assert_eq!(mode.is_constant(), is_even.is_constant());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Parity: {mode} {i}");
+            check_parity::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        let one = console::Integer::one();
+        check_parity::<I>("Parity: 0", console::Integer::zero(), mode);
+        check_parity::<I>("Parity: 1", one, mode);
+        check_parity::<I>("Parity: 2", one + one, mode);
+        check_parity::<I>("Parity: MAX", console::Integer::MAX, mode);
+        check_parity::<I>("Parity: MIN", console::Integer::MIN, mode);
+        if I::is_signed() {
+            check_parity::<I>("Parity: -1", -one, mode);
+            check_parity::<I>("Parity: -2", -(one + one), mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, parity);
+    test_integer_unary!(run_test, i16, parity);
+    test_integer_unary!(run_test, i32, parity);
+    test_integer_unary!(run_test, i64, parity);
+    test_integer_unary!(run_test, i128, parity);
+
+    test_integer_unary!(run_test, u8, parity);
+    test_integer_unary!(run_test, u16, parity);
+    test_integer_unary!(run_test, u32, parity);
+    test_integer_unary!(run_test, u64, parity);
+    test_integer_unary!(run_test, u128, parity);
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the integer with its bits permuted, where bit `i` of the output is bit `perm[i]` of `self`.
+    ///
+    /// This method halts if `perm` is not a permutation of `0..I::BITS`.
+    ///
+    /// As the integer is represented by its bits, this method is a permutation of wires, and uses 0 constraints.
+    /// The output is constant iff the integer is constant, in which case the permuted bits are the constant bits.
+    pub fn permute_bits(&self, perm: &[usize]) -> Self {
+        // Ensure the permutation is a permutation of `0..I::BITS`.
+        let mut is_used = vec![false; I::BITS as usize];
+        if perm.len() != I::BITS as usize
+            || !perm.iter().all(|index| *index < I::BITS as usize && !core::mem::replace(&mut is_used[*index], true))
+        {
+            E::halt(format!("Invalid bit permutation {perm:?} for '{}'", I::type_name()))
+        }
+
+        // Rewire the bits of the integer according to the permutation.
+        let bits_le = perm.iter().map(|index| self.bits_le[*index].clone()).collect();
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns a uniformly random permutation of `0..num_bits`, using a Fisher-Yates shuffle.
+    fn sample_permutation(num_bits: usize, rng: &mut TestRng) -> Vec<usize> {
+        let mut perm = (0..num_bits).collect::<Vec<_>>();
+        for i in (1..num_bits).rev() {
+            let j = (<u64 as Uniform>::rand(rng) % (i as u64 + 1)) as usize;
+            perm.swap(i, j);
+        }
+        perm
+    }
+
+    fn check_permute_bits<I: IntegerType>(name: &str, value: I, perm: &[usize], mode: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::new(value));
+        // Compute the expected value from the permuted bits.
+        let bits_le = console::Integer::<<Circuit as Environment>::Network, I>::new(value).to_bits_le();
+        let expected_bits_le = perm.iter().map(|index| bits_le[*index]).collect::<Vec<_>>();
+        let expected = console::Integer::<_, I>::from_bits_le(&expected_bits_le).unwrap();
+        Circuit::scope(name, || {
+            let candidate = a.permute_bits(perm);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(mode.is_constant(), candidate.is_constant());
+            // Ensure the permutation uses no constraints.
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+        let bits = I::BITS as usize;
+
+        // Check the identity, the bit reversal, and a rotation by one bit.
+        let identity = (0..bits).collect::<Vec<_>>();
+        let reversal = (0..bits).rev().collect::<Vec<_>>();
+        let rotation = (0..bits).map(|i| (i + bits - 1) % bits).collect::<Vec<_>>();
+        for (i, value) in [I::zero(), I::one(), I::MAX, I::MIN].into_iter().enumerate() {
+            for (j, perm) in [&identity, &reversal, &rotation].into_iter().enumerate() {
+                check_permute_bits::<I>(&format!("PermuteBits: {mode} boundary {i} {j}"), value, perm, mode);
+            }
+        }
+
+        // Check random permutations of random values.
+        for i in 0..ITERATIONS {
+            let value: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            let perm = sample_permutation(bits, &mut rng);
+            check_permute_bits::<I>(&format!("PermuteBits: {mode} {i}"), *value, &perm, mode);
+        }
+
+        // Ensure invalid permutations halt, for a short, a long, an out of range, and a repeated permutation.
+        let mut repeated = identity.clone();
+        repeated[1] = 0;
+        let mut out_of_range = identity.clone();
+        out_of_range[0] = bits;
+        let invalid = [identity[1..].to_vec(), [identity.clone(), vec![0]].concat(), out_of_range, repeated];
+        for perm in invalid {
+            let a = Integer::<Circuit, I>::new(mode, console::Integer::zero());
+            check_unary_operation_halts(a, move |a: Integer<Circuit, I>| a.permute_bits(&perm));
+            Circuit::reset();
+        }
+    }
+
+    test_integer_unary!(run_test, i8, permute_bits);
+    test_integer_unary!(run_test, i16, permute_bits);
+    test_integer_unary!(run_test, i32, permute_bits);
+    test_integer_unary!(run_test, i64, permute_bits);
+    test_integer_unary!(run_test, i128, permute_bits);
+
+    test_integer_unary!(run_test, u8, permute_bits);
+    test_integer_unary!(run_test, u16, permute_bits);
+    test_integer_unary!(run_test, u32, permute_bits);
+    test_integer_unary!(run_test, u64, permute_bits);
+    test_integer_unary!(run_test, u128, permute_bits);
+}
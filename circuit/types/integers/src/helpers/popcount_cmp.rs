@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::cmp::Ordering;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Compares the number of set bits of `self` and `other`, returning `(less, equal, greater)`,
+    /// where exactly one of the three booleans is `true`.
+    ///
+    /// Rather than computing each count, this method computes `count(self) - count(other) + I::BITS`
+    /// in the base field, which is in `[0, 2 * I::BITS]`, and compares it to `I::BITS`.
+    /// If both integers are constant, this method uses 0 constraints.
+    /// Otherwise, this method uses `log2(I::BITS) + 7` constraints
+    /// (i.e. 10 constraints for 8-bit integers, up to 14 constraints for 128-bit integers).
+    pub fn popcount_cmp(&self, other: &Self) -> (Boolean<E>, Boolean<E>, Boolean<E>) {
+        // If both integers are constant, compare the counts directly.
+        if self.is_constant() && other.is_constant() {
+            let ordering = self.eject_value().count_ones().cmp(&other.eject_value().count_ones());
+            return (
+                Boolean::constant(ordering == Ordering::Less),
+                Boolean::constant(ordering == Ordering::Equal),
+                Boolean::constant(ordering == Ordering::Greater),
+            );
+        }
+
+        // Compute `count(self) - count(other) + I::BITS` in the base field.
+        // Note: This is safe as the difference is in `[0, 2 * I::BITS]`, which is far smaller than the field.
+        let bits = Field::constant(console::Field::from_u64(I::BITS));
+        let difference = self
+            .bits_le
+            .iter()
+            .zip_eq(&other.bits_le)
+            .fold(bits.clone(), |difference, (a, b)| difference + Field::from_boolean(a) - Field::from_boolean(b));
+
+        // As `I::BITS` is `2^m`, the difference fits in `m + 2` bits.
+        let m = I::BITS.trailing_zeros() as usize;
+        let difference_bits = difference.to_lower_bits_le(m + 2);
+        // The difference is less than `2^m` iff bits `m` and `m + 1` are both unset.
+        let less = !(&difference_bits[m] | &difference_bits[m + 1]);
+        let equal = difference.is_equal(&bits);
+        let greater = !(&less | &equal);
+        (less, equal, greater)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_popcount_cmp<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let ordering = first.count_ones().cmp(&second.count_ones());
+
+        Circuit::scope(name, || {
+            let (less, equal, greater) = a.popcount_cmp(&b);
+            assert_eq!(ordering == Ordering::Less, less.eject_value());
+            assert_eq!(ordering == Ordering::Equal, equal.eject_value());
+            assert_eq!(ordering == Ordering::Greater, greater.eject_value());
+
+            // Check the documented number of constraints.
+            match mode_a.is_constant() && mode_b.is_constant() {
+                true => assert_scope!(0, 0, 0, 0),
+                false => {
+                    let m = I::BITS.trailing_zeros() as u64;
+                    // The constant `I::BITS` is the only constant, as the bits of a constant integer are summed for free.
+                    assert_scope!(1, 0, m + 6, m + 7);
+                }
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("PopcountCmp: {mode_a} {mode_b} {i}");
+            check_popcount_cmp::<I>(&name, first, second, mode_a, mode_b);
+            check_popcount_cmp::<I>(&name, second, first, mode_a, mode_b);
+
+            // Check integers with equal weights, by reversing the bits of the integer.
+            let mut bits_le = first.to_bits_le();
+            bits_le.reverse();
+            let reversed = console::Integer::from_bits_le(&bits_le).unwrap();
+            let name = format!("PopcountCmp: {mode_a} {mode_b} reversed {i}");
+            check_popcount_cmp::<I>(&name, first, reversed, mode_a, mode_b);
+            check_popcount_cmp::<I>(&name, first, first, mode_a, mode_b);
+        }
+
+        // Check the extremes, including the all-zero and all-one bit patterns.
+        let boundaries = [
+            console::Integer::zero(),
+            console::Integer::one(),
+            console::Integer::MIN,
+            console::Integer::MAX,
+            console::Integer::new(!I::zero()),
+        ];
+        for (i, first) in boundaries.into_iter().enumerate() {
+            for (j, second) in boundaries.into_iter().enumerate() {
+                let name = format!("PopcountCmp: {mode_a} {mode_b} boundary {i} {j}");
+                check_popcount_cmp::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, popcount_cmp);
+    test_integer_binary!(run_test, i16, popcount_cmp);
+    test_integer_binary!(run_test, i32, popcount_cmp);
+    test_integer_binary!(run_test, i64, popcount_cmp);
+    test_integer_binary!(run_test, i128, popcount_cmp);
+
+    test_integer_binary!(run_test, u8, popcount_cmp);
+    test_integer_binary!(run_test, u16, popcount_cmp);
+    test_integer_binary!(run_test, u32, popcount_cmp);
+    test_integer_binary!(run_test, u64, popcount_cmp);
+    test_integer_binary!(run_test, u128, popcount_cmp);
+}
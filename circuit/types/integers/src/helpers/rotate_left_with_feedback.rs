@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self` shifted left by one bit, with `feedback` inserted as the least significant bit,
+    /// discarding the most significant bit of `self`, as in one step of a linear-feedback shift register.
+    ///
+    /// As the integer is represented by its bits, this method is a permutation of wires, and uses 0 constraints.
+    pub fn rotate_left_with_feedback(&self, feedback: &Boolean<E>) -> Self {
+        // If the operands are constant, compute the result directly.
+        if self.is_constant() && feedback.is_constant() {
+            let shifted = self.eject_value().wrapping_shl(1);
+            return match feedback.eject_value() {
+                true => Integer::constant(console::Integer::new(shifted.wrapping_add(&I::one()))),
+                false => Integer::constant(console::Integer::new(shifted)),
+            };
+        }
+
+        // Insert the feedback bit below the lower `I::BITS - 1` bits of `self`.
+        let bits_le =
+            core::iter::once(feedback).chain(self.bits_le.iter().take(I::BITS as usize - 1)).cloned().collect();
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+    const NUM_STEPS: usize = 16;
+
+    /// Returns the next state of the reference LFSR, which feeds back the XOR of the top bit and the middle bit.
+    fn lfsr_step<I: IntegerType>(state: console::Integer<<Circuit as Environment>::Network, I>) -> (I, bool) {
+        let bits_le = state.to_bits_le();
+        let feedback = bits_le[I::BITS as usize - 1] ^ bits_le[I::BITS as usize / 2];
+        let shifted = state.wrapping_shl(1);
+        match feedback {
+            true => (shifted.wrapping_add(&I::one()), feedback),
+            false => (shifted, feedback),
+        }
+    }
+
+    fn check_rotate_left_with_feedback<I: IntegerType>(name: &str, value: I, mode: Mode, feedback_mode: Mode) {
+        // Compute the states and feedback bits of the reference LFSR.
+        let mut expected = vec![console::Integer::new(value)];
+        let mut feedbacks = Vec::with_capacity(NUM_STEPS);
+        for _ in 0..NUM_STEPS {
+            let (next, feedback) = lfsr_step(*expected.last().unwrap());
+            expected.push(console::Integer::new(next));
+            feedbacks.push(Boolean::<Circuit>::new(feedback_mode, feedback));
+        }
+
+        let mut state = Integer::<Circuit, I>::new(mode, expected[0]);
+        Circuit::scope(name, || {
+            for (expected, feedback) in expected[1..].iter().zip_eq(&feedbacks) {
+                // Compute the next state of the LFSR in the circuit.
+                let candidate = state.rotate_left_with_feedback(feedback);
+                assert_eq!(**expected, *candidate.eject_value());
+                if mode.is_constant() && feedback_mode.is_constant() {
+                    assert!(candidate.is_constant());
+                }
+                state = candidate;
+            }
+            // Ensure the rotations use no constraints.
+            assert_eq!(0, Circuit::num_constraints_in_scope());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for feedback_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let name = format!("RotateLeftWithFeedback: {mode} {feedback_mode} {i}");
+                let value: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+                check_rotate_left_with_feedback::<I>(&name, *value, mode, feedback_mode);
+            }
+
+            // Check the extremes of the integer type.
+            for (i, value) in [I::zero(), I::one(), I::MAX, I::MIN].into_iter().enumerate() {
+                let name = format!("RotateLeftWithFeedback: {mode} {feedback_mode} boundary {i}");
+                check_rotate_left_with_feedback::<I>(&name, value, mode, feedback_mode);
+            }
+        }
+    }
+
+    test_integer_unary!(run_test, i8, rotate_left_with_feedback);
+    test_integer_unary!(run_test, i16, rotate_left_with_feedback);
+    test_integer_unary!(run_test, i32, rotate_left_with_feedback);
+    test_integer_unary!(run_test, i64, rotate_left_with_feedback);
+    test_integer_unary!(run_test, i128, rotate_left_with_feedback);
+
+    test_integer_unary!(run_test, u8, rotate_left_with_feedback);
+    test_integer_unary!(run_test, u16, rotate_left_with_feedback);
+    test_integer_unary!(run_test, u32, rotate_left_with_feedback);
+    test_integer_unary!(run_test, u64, rotate_left_with_feedback);
+    test_integer_unary!(run_test, u128, rotate_left_with_feedback);
+}
@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Casts the integer to the integer type `J`, clamping to `J::MIN` or `J::MAX` if the value is out of range.
+    ///
+    /// For example, a large `u64` casts to `u8::MAX`, and a negative `i32` casts to `0u16`.
+    /// For a constant integer, this method uses 0 constraints.
+    ///
+    /// For a variable integer, let `m_I` and `m_J` be the number of bits excluding the sign bit, of `I` and `J`.
+    /// This method uses `c_o + c_u + min(m_I, m_J) * (f_o + f_u)` constraints, where:
+    ///  - `c_o` is `m_I - m_J` (minus 1 if `I` is unsigned) if `m_I > m_J`, and 0 otherwise,
+    ///  - `c_u` is `I::BITS - J::BITS` if `I` and `J` are signed and `I::BITS > J::BITS`, and 0 otherwise,
+    ///  - `f_o` is 1 if `m_I > m_J`, and `f_u` is 1 if `I` is signed and `J` is unsigned or `c_u > 0`.
+    ///
+    /// In particular, a cast to a type that holds every value of `I` uses 0 constraints.
+    pub fn saturating_cast<J: IntegerType>(&self) -> Integer<E, J> {
+        // If the integer is constant, compute the result directly.
+        if self.is_constant() {
+            return Integer::constant(console::Integer::new(saturating_cast_native::<I, J>(*self.eject_value())));
+        }
+
+        // Retrieve the sign bit, which is `false` for an unsigned integer.
+        let sign = match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        };
+        // Determine the number of bits excluding the sign bit, for `I` and `J`.
+        let source_bits = (I::BITS - I::is_signed() as u64) as usize;
+        let target_bits = (J::BITS - J::is_signed() as u64) as usize;
+        let magnitude_bits = &self.bits_le[..source_bits];
+
+        // The integer overflows if it is non-negative, and has a bit set above the bits of `J`.
+        let overflow = magnitude_bits[target_bits.min(source_bits)..]
+            .iter()
+            .fold(Boolean::constant(false), |is_set, bit| is_set | bit)
+            & !&sign;
+        // The integer underflows if it is negative, and either `J` is unsigned,
+        // or the bits above the sign bit of `J` are not all set.
+        let underflow = match (I::is_signed(), J::is_signed()) {
+            (true, false) => sign.clone(),
+            (true, true) => {
+                let all_set = magnitude_bits[(J::BITS as usize - 1).min(source_bits)..]
+                    .iter()
+                    .fold(Boolean::constant(true), |all_set, bit| all_set & bit);
+                &sign & !all_set
+            }
+            (false, _) => Boolean::constant(false),
+        };
+
+        // Extend the integer with its sign bit if both types are signed, and with zeros otherwise.
+        // Note: An in-range signed integer that casts to an unsigned type is non-negative.
+        let extension = match I::is_signed() && J::is_signed() {
+            true => sign.clone(),
+            false => Boolean::constant(false),
+        };
+        // Set the bits on overflow, and clear the bits on underflow.
+        let mut bits_le = (0..target_bits)
+            .map(|i| (magnitude_bits.get(i).unwrap_or(&extension) | &overflow) & !&underflow)
+            .collect::<Vec<_>>();
+        // Note: The sign bit of the result is the sign bit of the integer, which holds on overflow and underflow.
+        if J::is_signed() {
+            bits_le.push(sign);
+        }
+        Integer::from_bits_le(&bits_le)
+    }
+}
+
+/// Returns the given value cast to `J`, clamped to `J::MIN` or `J::MAX` if the value is out of range.
+fn saturating_cast_native<I: IntegerType, J: IntegerType>(value: I) -> J {
+    let bytes = match value < I::zero() {
+        // Note: Unwrapping is safe, as negative values and `J::MIN` fit in an `i128`.
+        true => match value.to_i128().unwrap() {
+            value if value < J::MIN.to_i128().unwrap() => return J::MIN,
+            value => value.to_le_bytes(),
+        },
+        // Note: Unwrapping is safe, as non-negative values and `J::MAX` fit in a `u128`.
+        false => match value.to_u128().unwrap() {
+            value if value > J::MAX.to_u128().unwrap() => return J::MAX,
+            value => value.to_le_bytes(),
+        },
+    };
+    // Note: Unwrapping is safe, as the value is in the range of `J`, so its lower bytes represent it in `J`.
+    J::from_bytes_le(&bytes[..J::BITS as usize / 8]).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 16;
+
+    /// Returns the value cast to `J` if it is in range, and otherwise clamps to `J::MIN` or `J::MAX` by its sign.
+    fn reference_saturating_cast<I: IntegerType, J: IntegerType>(value: I) -> J {
+        match value.to_string().parse::<J>() {
+            Ok(value) => value,
+            Err(_) if value < I::zero() => J::MIN,
+            Err(_) => J::MAX,
+        }
+    }
+
+    /// Returns the documented number of constraints, to cast a variable `I` to `J`.
+    fn expected_num_constraints<I: IntegerType, J: IntegerType>() -> u64 {
+        let source_bits = I::BITS - I::is_signed() as u64;
+        let target_bits = J::BITS - J::is_signed() as u64;
+        let overflow_constraints = match source_bits > target_bits {
+            true => source_bits - target_bits - !I::is_signed() as u64,
+            false => 0,
+        };
+        let underflow_constraints = match I::is_signed() && J::is_signed() {
+            true => I::BITS.saturating_sub(J::BITS),
+            false => 0,
+        };
+        let is_overflow_variable = (source_bits > target_bits) as u64;
+        let is_underflow_variable = (I::is_signed() && (!J::is_signed() || underflow_constraints > 0)) as u64;
+        overflow_constraints
+            + underflow_constraints
+            + source_bits.min(target_bits) * (is_overflow_variable + is_underflow_variable)
+    }
+
+    fn check_saturating_cast<I: IntegerType, J: IntegerType>(name: &str, value: I, mode: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::new(value));
+        let expected = reference_saturating_cast::<I, J>(value);
+        Circuit::scope(name, || {
+            let candidate = a.saturating_cast::<J>();
+            assert_eq!(expected, *candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            match mode.is_constant() {
+                true => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                false => assert_eq!(expected_num_constraints::<I, J>(), Circuit::num_constraints_in_scope()),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test_to<I: IntegerType, J: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("SaturatingCast: {} to {} {mode} {i}", I::type_name(), J::type_name());
+            let value: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            check_saturating_cast::<I, J>(&name, *value, mode);
+        }
+
+        // Check the extremes of the source type, and the values around the extremes of the target type.
+        let mut values = vec![I::zero(), I::one(), I::zero().wrapping_sub(&I::one()), I::MAX, I::MIN];
+        for extreme in [J::MAX.to_i128(), J::MIN.to_i128()].into_iter().flatten() {
+            for offset in [-1i128, 0, 1] {
+                if let Some(value) = extreme.checked_add(offset).and_then(|value| value.to_string().parse::<I>().ok()) {
+                    values.push(value);
+                }
+            }
+        }
+        for (i, value) in values.into_iter().enumerate() {
+            let name = format!("SaturatingCast: {} to {} {mode} boundary {i}", I::type_name(), J::type_name());
+            check_saturating_cast::<I, J>(&name, value, mode);
+        }
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        run_test_to::<I, i8>(mode);
+        run_test_to::<I, i16>(mode);
+        run_test_to::<I, i32>(mode);
+        run_test_to::<I, i64>(mode);
+        run_test_to::<I, i128>(mode);
+
+        run_test_to::<I, u8>(mode);
+        run_test_to::<I, u16>(mode);
+        run_test_to::<I, u32>(mode);
+        run_test_to::<I, u64>(mode);
+        run_test_to::<I, u128>(mode);
+    }
+
+    #[test]
+    fn test_saturating_cast_examples() {
+        // Ensure a large `u64` casts to `u8::MAX`.
+        let a = Integer::<Circuit, u64>::new(Mode::Private, console::Integer::new(1000));
+        assert_eq!(u8::MAX, *a.saturating_cast::<u8>().eject_value());
+        // Ensure a negative `i32` casts to `0u16`.
+        let a = Integer::<Circuit, i32>::new(Mode::Private, console::Integer::new(-5));
+        assert_eq!(0, *a.saturating_cast::<u16>().eject_value());
+        // Ensure a small negative `i64` casts to `i8::MIN`.
+        let a = Integer::<Circuit, i64>::new(Mode::Private, console::Integer::new(-1000));
+        assert_eq!(i8::MIN, *a.saturating_cast::<i8>().eject_value());
+        // Ensure an in-range `u128` casts exactly.
+        let a = Integer::<Circuit, u128>::new(Mode::Private, console::Integer::new(100));
+        assert_eq!(100, *a.saturating_cast::<i8>().eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, saturating_cast);
+    test_integer_unary!(run_test, i16, saturating_cast);
+    test_integer_unary!(run_test, i32, saturating_cast);
+    test_integer_unary!(run_test, i64, saturating_cast);
+    test_integer_unary!(run_test, i128, saturating_cast);
+
+    test_integer_unary!(run_test, u8, saturating_cast);
+    test_integer_unary!(run_test, u16, saturating_cast);
+    test_integer_unary!(run_test, u32, saturating_cast);
+    test_integer_unary!(run_test, u64, saturating_cast);
+    test_integer_unary!(run_test, u128, saturating_cast);
+}
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `(self >> amount, carry)`, where `carry` holds the bits shifted out of `self`,
+    /// packed into the least significant `amount` bits, i.e. `self & (2^amount - 1)`.
+    ///
+    /// As with `shr_checked`, this method halts if the constant shift amount is at least `I::BITS`,
+    /// and the circuit is not satisfied if the variable shift amount is at least `I::BITS`.
+    /// In addition to the constraints of `shr_checked`, the carry uses 0 constraints for a constant shift amount.
+    /// Otherwise, the carry uses `I::BITS + log2(I::BITS)` constraints,
+    /// plus `I::BITS` constraints if `self` is not constant.
+    pub fn shr_with_carry<M: Magnitude>(&self, amount: &Integer<E, M>) -> (Self, Self) {
+        // Compute the shift result, which enforces that the shift amount is less than `I::BITS`.
+        let result = self.shr_checked(amount);
+
+        // Retrieve the index of the first upper bit of the shift amount, which is zero for a valid shift amount.
+        let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
+
+        let bits_le = match amount.is_constant() {
+            // If the shift amount is constant, select the lower bits of `self` directly.
+            true => {
+                // Note: Casting `amount` to `usize` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+                let shift = amount.eject_value().to_usize().unwrap();
+                self.bits_le
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| match i < shift {
+                        true => bit.clone(),
+                        false => Boolean::constant(false),
+                    })
+                    .collect()
+            }
+            // Otherwise, mask the lower bits of `self` with the bits of `2^amount - 1`.
+            false => {
+                // Compute `2^amount` in the field, from the lower bits of the shift amount.
+                let mut power = Field::one();
+                for (i, bit) in amount.bits_le[..first_upper_bit_index].iter().enumerate() {
+                    // Note that instantiating the field from a u128 is safe since `i` is at most 6.
+                    let constant = Field::constant(console::Field::from_u128(2u128.pow(1 << i)));
+                    power = Field::ternary(bit, &(&power * &constant), &power);
+                }
+                // Since the shift amount is less than `I::BITS`, the mask `2^amount - 1` fits in `I::BITS` bits.
+                let mask = (power - Field::one()).to_lower_bits_le(I::BITS as usize);
+                self.bits_le.iter().zip(mask.iter()).map(|(bit, mask_bit)| bit & mask_bit).collect()
+            }
+        };
+
+        (result, Self { bits_le, phantom: Default::default() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_shr_with_carry<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        match first.checked_shr(second.to_u32().unwrap()) {
+            Some(expected) => {
+                // Compute the expected carry, as the lower bits of `first` that are cleared by shifting back.
+                let (value, shift) = (*first, second.to_usize().unwrap());
+                let expected_carry = value.wrapping_sub(&((value >> shift) << shift));
+
+                // Count the constraints of the shift alone.
+                let num_shr_constraints = Circuit::scope("ShrChecked", || {
+                    let _candidate = a.shr_checked(&b);
+                    Circuit::num_constraints_in_scope()
+                });
+                Circuit::reset();
+
+                Circuit::scope(name, || {
+                    let (candidate, carry) = a.shr_with_carry(&b);
+                    assert_eq!(expected, *candidate.eject_value());
+                    assert_eq!(expected_carry, *carry.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+
+                    // Check the number of constraints for the carry.
+                    let num_bits = I::BITS;
+                    let expected_num_constraints = match (mode_a.is_constant(), mode_b.is_constant()) {
+                        (_, true) => 0,
+                        (true, false) => num_bits + num_bits.trailing_zeros() as u64,
+                        (false, false) => 2 * num_bits + num_bits.trailing_zeros() as u64,
+                    };
+                    assert_eq!(num_shr_constraints + expected_num_constraints, Circuit::num_constraints_in_scope());
+                });
+            }
+            None => match (mode_a, mode_b) {
+                (_, Mode::Constant) => check_operation_halts(&a, &b, Integer::shr_with_carry),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.shr_with_carry(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        };
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+
+            // Check the representative shift amounts, including zero and `I::BITS - 1`.
+            for shift in [0, 1, I::BITS / 2, I::BITS - 1] {
+                let name = format!("ShrWithCarry: {mode_a} >> {shift} ({mode_b}) {i}");
+                let second = console::Integer::new(num_traits::cast::<_, M>(shift).unwrap());
+                check_shr_with_carry::<I, M>(&name, first, second, mode_a, mode_b);
+            }
+
+            // Check a random shift amount, which is usually out of range.
+            let name = format!("ShrWithCarry: {mode_a} >> {mode_b} {i}");
+            check_shr_with_carry::<I, M>(&name, first, Uniform::rand(&mut rng), mode_a, mode_b);
+        }
+    }
+
+    test_integer_binary!(run_test, i8, u8, shr_with_carry);
+    test_integer_binary!(run_test, i16, u8, shr_with_carry);
+    test_integer_binary!(run_test, i32, u16, shr_with_carry);
+    test_integer_binary!(run_test, i64, u32, shr_with_carry);
+    test_integer_binary!(run_test, i128, u8, shr_with_carry);
+
+    test_integer_binary!(run_test, u8, u8, shr_with_carry);
+    test_integer_binary!(run_test, u16, u8, shr_with_carry);
+    test_integer_binary!(run_test, u32, u16, shr_with_carry);
+    test_integer_binary!(run_test, u64, u32, shr_with_carry);
+    test_integer_binary!(run_test, u128, u8, shr_with_carry);
+}
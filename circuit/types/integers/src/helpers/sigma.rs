@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `rotate_right(self, rots[0]) ^ rotate_right(self, rots[1]) ^ (self >> shift)`,
+    /// as used in the message schedule and round functions of SHA-2.
+    ///
+    /// The rotations are taken modulo `I::BITS`, and the shift is a logical (zero-filling) shift on the bits,
+    /// for both signed and unsigned integers. A shift of at least `I::BITS` contributes zero.
+    ///
+    /// As the rotations and the shift are permutations of the bits, only the XORs use constraints.
+    /// For a non-constant integer, this method uses `I::BITS + (I::BITS - min(shift, I::BITS))` constraints,
+    /// which matches composing `shr_wrapped`, `shl_wrapped`, `|` and `^` with constant amounts,
+    /// without allocating the intermediate integers and constants. A constant integer uses 0 constraints.
+    pub fn sigma(&self, rots: [u32; 2], shift: u32) -> Self {
+        let num_bits = I::BITS as usize;
+        // Reduce the rotations modulo the number of bits.
+        let (first_rot, second_rot) = (rots[0] as usize % num_bits, rots[1] as usize % num_bits);
+        let shift = shift as usize;
+
+        // Compute each output bit from the rotated and shifted input bits.
+        let bits_le = (0..num_bits)
+            .map(|i| {
+                let output = &self.bits_le[(i + first_rot) % num_bits] ^ &self.bits_le[(i + second_rot) % num_bits];
+                // If the shifted bit is beyond the most significant bit, it is zero, and the XOR is skipped.
+                match i.checked_add(shift) {
+                    Some(j) if j < num_bits => output ^ &self.bits_le[j],
+                    _ => output,
+                }
+            })
+            .collect();
+
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the reference sigma, computed on the native integer.
+    fn reference_sigma<I: IntegerType>(value: I, rots: [u32; 2], shift: u32) -> I {
+        let shifted = match shift < I::BITS as u32 {
+            true => value.unsigned_shr(shift),
+            false => I::zero(),
+        };
+        value.rotate_right(rots[0] % I::BITS as u32) ^ value.rotate_right(rots[1] % I::BITS as u32) ^ shifted
+    }
+
+    /// Returns the composed sigma, computed from the rotation, shift, and XOR gadgets.
+    fn composed_sigma<I: IntegerType>(a: &Integer<Circuit, I>, rots: [u32; 2], shift: u32) -> Integer<Circuit, I> {
+        let amount = |n: u32| U32::<Circuit>::constant(console::Integer::new(n));
+        let rotate_right = |n: u32| a.shr_wrapped(&amount(n)) | a.shl_wrapped(&amount(I::BITS as u32 - n));
+        rotate_right(rots[0]) ^ rotate_right(rots[1]) ^ a.shr_wrapped(&amount(shift))
+    }
+
+    fn check_sigma<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        rots: [u32; 2],
+        shift: u32,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = reference_sigma(*value, rots, shift);
+        Circuit::scope(name, || {
+            let candidate = a.sigma(rots, shift);
+            assert_eq!(expected, *candidate.eject_value());
+            // Check the documented number of constraints.
+            let num_constraints = match mode.is_constant() {
+                true => 0,
+                false => 2 * I::BITS - core::cmp::min(shift as u64, I::BITS),
+            };
+            assert_scope!(0, 0, num_constraints, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+        let num_bits = I::BITS as u32;
+
+        // Select the rotations and shifts, including those of SHA-256 and SHA-512 where applicable.
+        let mut parameters = vec![([1, 3], 2), ([0, num_bits / 2], num_bits - 1), ([num_bits + 1, 5], num_bits)];
+        if num_bits == 32 {
+            parameters.extend([([7, 18], 3), ([17, 19], 10), ([2, 13], 22), ([6, 11], 25)]);
+        } else if num_bits == 64 {
+            parameters.extend([([1, 8], 7), ([19, 61], 6), ([28, 34], 39), ([14, 18], 41)]);
+        }
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            for (rots, shift) in &parameters {
+                let name = format!("Sigma: {mode} {rots:?} {shift} {i}");
+                check_sigma::<I>(&name, value, *rots, *shift, mode);
+            }
+        }
+
+        // Ensure the fused gadget uses as many constraints as the composed gadgets, for unsigned integers.
+        if !I::is_signed() && !mode.is_constant() {
+            // Note: The composed gadgets are only equivalent for nonzero rotations and in-range shifts.
+            let is_composable =
+                |rots: &[u32; 2], shift: u32| rots.iter().all(|r| (1..num_bits).contains(r)) && shift < num_bits;
+            for (rots, shift) in parameters.into_iter().filter(|(rots, shift)| is_composable(rots, *shift)) {
+                let value: console::Integer<_, I> = Uniform::rand(&mut rng);
+                let a = Integer::<Circuit, I>::new(mode, value);
+
+                let num_composed_constraints = Circuit::scope("Sigma (composed)", || {
+                    assert_eq!(reference_sigma(*value, rots, shift), *composed_sigma(&a, rots, shift).eject_value());
+                    Circuit::num_constraints_in_scope()
+                });
+                let num_fused_constraints = Circuit::scope("Sigma (fused)", || {
+                    let _candidate = a.sigma(rots, shift);
+                    Circuit::num_constraints_in_scope()
+                });
+                assert_eq!(num_composed_constraints, num_fused_constraints);
+                Circuit::reset();
+            }
+        }
+    }
+
+    test_integer_unary!(run_test, i8, sigma);
+    test_integer_unary!(run_test, i16, sigma);
+    test_integer_unary!(run_test, i32, sigma);
+    test_integer_unary!(run_test, i64, sigma);
+    test_integer_unary!(run_test, i128, sigma);
+
+    test_integer_unary!(run_test, u8, sigma);
+    test_integer_unary!(run_test, u16, sigma);
+    test_integer_unary!(run_test, u32, sigma);
+    test_integer_unary!(run_test, u64, sigma);
+    test_integer_unary!(run_test, u128, sigma);
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the sign of the integer, matching Rust's `signum`:
+    /// `-1` if the integer is negative, `0` if it is zero, and `1` if it is positive.
+    /// For unsigned integers, this method returns `0` or `1`.
+    ///
+    /// Rather than selecting between the three constants with `ternary`, the output bits are assembled directly:
+    /// the least significant bit is set iff the integer is non-zero, and the remaining bits are the sign bit.
+    /// For a non-constant integer, this method uses 2 constraints, to check if the integer is non-zero.
+    /// For a constant integer, this method uses 0 constraints.
+    pub fn signum(&self) -> Self {
+        // If the integer is constant, compute the sign directly.
+        if self.is_constant() {
+            let value = *self.eject_value();
+            let signum = match value.is_zero() {
+                true => I::zero(),
+                false => match value < I::zero() {
+                    true => I::zero().wrapping_sub(&I::one()),
+                    false => I::one(),
+                },
+            };
+            return Integer::constant(console::Integer::new(signum));
+        }
+
+        // Determine if the integer is non-zero.
+        let is_nonzero = self.to_field().is_not_equal(&Field::zero());
+        // Determine the upper bits, which are set iff the integer is negative.
+        let upper_bit = match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        };
+        // Assemble the bits as `0b0...00`, `0b0...01`, or `0b1...11`.
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        bits_le.push(is_nonzero);
+        bits_le.resize(I::BITS as usize, upper_bit);
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_signum<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected sign, as `-1`, `0`, or `1`.
+        let expected = match (*value).cmp(&I::zero()) {
+            core::cmp::Ordering::Less => I::zero().wrapping_sub(&I::one()),
+            core::cmp::Ordering::Equal => I::zero(),
+            core::cmp::Ordering::Greater => I::one(),
+        };
+        Circuit::scope(name, || {
+            let candidate = a.signum();
+            assert_eq!(expected, *candidate.eject_value());
+            match mode.is_constant() {
+                true => assert_scope!(I::BITS, 0, 0, 0),
+                false => assert_scope!(0, 0, 2, 2),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Signum: {mode} {i}");
+            check_signum::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        // Check negative, zero, and positive values, including the extremes.
+        let boundaries = [
+            console::Integer::MIN,
+            console::Integer::new(I::MIN + I::one()),
+            console::Integer::new(I::zero().wrapping_sub(&I::one())),
+            console::Integer::zero(),
+            console::Integer::one(),
+            console::Integer::new(I::MAX - I::one()),
+            console::Integer::MAX,
+        ];
+        for (i, value) in boundaries.into_iter().enumerate() {
+            check_signum::<I>(&format!("Signum: {mode} boundary {i}"), value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, signum);
+    test_integer_unary!(run_test, i16, signum);
+    test_integer_unary!(run_test, i32, signum);
+    test_integer_unary!(run_test, i64, signum);
+    test_integer_unary!(run_test, i128, signum);
+
+    test_integer_unary!(run_test, u8, signum);
+    test_integer_unary!(run_test, u16, signum);
+    test_integer_unary!(run_test, u32, signum);
+    test_integer_unary!(run_test, u64, signum);
+    test_integer_unary!(run_test, u128, signum);
+}
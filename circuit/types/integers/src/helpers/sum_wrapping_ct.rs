@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the wrapping sum of the given integers, folding with `add_wrapped` from left to right.
+    ///
+    /// The constraints depend only on the number of integers and their modes, and never on their values,
+    /// so the constraint shape is constant for a fixed slice length. If there are no integers, this returns zero.
+    /// If all of the integers are constant, no constraints are used.
+    /// Otherwise, for `n` non-constant integers, this method uses `(n - 1) * (I::BITS + 2)` constraints.
+    pub fn sum_wrapping_ct(values: &[Integer<E, I>]) -> Self {
+        match values.split_first() {
+            Some((first, rest)) => rest.iter().fold(first.clone(), |sum, value| sum.add_wrapped(value)),
+            None => Integer::zero(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_sum_wrapping_ct<I: IntegerType>(
+        name: &str,
+        values: &[console::Integer<<Circuit as Environment>::Network, I>],
+        mode: Mode,
+    ) -> u64 {
+        // Compute the expected sum.
+        let expected = values.iter().fold(I::zero(), |sum, value| sum.wrapping_add(&**value));
+
+        let candidates = values.iter().map(|value| Integer::<Circuit, I>::new(mode, *value)).collect::<Vec<_>>();
+        let num_constraints = Circuit::scope(name, || {
+            let candidate = Integer::sum_wrapping_ct(&candidates);
+            assert_eq!(expected, *candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+
+            // Check the documented number of constraints.
+            let n = values.len() as u64;
+            let expected_num_constraints = match mode.is_constant() || n <= 1 {
+                true => 0,
+                false => (n - 1) * (I::BITS + 2),
+            };
+            assert_eq!(expected_num_constraints, Circuit::num_constraints_in_scope());
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+        num_constraints
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for n in [0, 1, 2, 3, 4, 5, 8, 9] {
+            // Sum random integers.
+            let mut num_constraints = Vec::new();
+            for i in 0..ITERATIONS {
+                let values = (0..n).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+                let name = format!("SumWrappingCT: {mode} {n} {i}");
+                num_constraints.push(check_sum_wrapping_ct::<I>(&name, &values, mode));
+            }
+
+            // Sum integers that never wrap, and integers that always wrap.
+            let zeros = vec![console::Integer::zero(); n];
+            num_constraints.push(check_sum_wrapping_ct::<I>(&format!("SumWrappingCT: {mode} {n} zeros"), &zeros, mode));
+            let maxes = vec![console::Integer::MAX; n];
+            num_constraints.push(check_sum_wrapping_ct::<I>(&format!("SumWrappingCT: {mode} {n} MAX"), &maxes, mode));
+
+            // Ensure the number of constraints is independent of the values.
+            assert!(num_constraints.windows(2).all(|pair| pair[0] == pair[1]));
+        }
+
+        let one = console::Integer::one();
+
+        // Check a sequence that wraps.
+        check_sum_wrapping_ct::<I>("MAX + 1", &[console::Integer::MAX, one], mode);
+        if I::is_signed() {
+            check_sum_wrapping_ct::<I>("MIN + (-1)", &[console::Integer::MIN, -one], mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, sum_wrapping_ct);
+    test_integer_unary!(run_test, i16, sum_wrapping_ct);
+    test_integer_unary!(run_test, i32, sum_wrapping_ct);
+    test_integer_unary!(run_test, i64, sum_wrapping_ct);
+    test_integer_unary!(run_test, i128, sum_wrapping_ct);
+
+    test_integer_unary!(run_test, u8, sum_wrapping_ct);
+    test_integer_unary!(run_test, u16, sum_wrapping_ct);
+    test_integer_unary!(run_test, u32, sum_wrapping_ct);
+    test_integer_unary!(run_test, u64, sum_wrapping_ct);
+    test_integer_unary!(run_test, u128, sum_wrapping_ct);
+}
@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the wrapping sum of the given integers, and a flag that is `true`
+    /// iff the running sum overflowed or underflowed at any point.
+    ///
+    /// If all of the integers are constant (or there are none), the sum and flag are constant,
+    /// and no constraints are used.
+    /// Otherwise, for `n > 1` non-constant integers, this method uses:
+    ///   - `I::BITS + 2 * k` constraints for unsigned integers, where `k` is the bit length of `n - 1`, and
+    ///   - `(n - 1) * (I::BITS + 6) - 1` constraints for signed integers.
+    pub fn sum_wrapping_flagged(values: &[Integer<E, I>]) -> (Self, Boolean<E>) {
+        // If all of the integers are constant, compute the sum and flag directly.
+        if values.iter().all(|value| value.is_constant()) {
+            let (sum, is_wrapped) = values.iter().fold((I::zero(), false), |(sum, is_wrapped), value| {
+                let value = *value.eject_value();
+                match sum.checked_add(&value) {
+                    Some(sum) => (sum, is_wrapped),
+                    None => (sum.wrapping_add(&value), true),
+                }
+            });
+            return (Integer::constant(console::Integer::new(sum)), Boolean::constant(is_wrapped));
+        }
+
+        // Note: `E::halt` should never be invoked, as at least one integer is not constant.
+        let (first, rest) = match values.split_first() {
+            Some((first, rest)) => (first, rest),
+            None => E::halt("Missing integers in the sum"),
+        };
+        // If there is only one integer, return it with no wrapping.
+        if rest.is_empty() {
+            return (first.clone(), Boolean::constant(false));
+        }
+
+        if I::is_signed() {
+            // As the running sum is not monotonic, check each partial sum for overflow or underflow.
+            let mut sum = first.clone();
+            let mut is_wrapped = Boolean::constant(false);
+            for value in rest {
+                // Add the integers in the base field, ignoring the carry bit as it is not relevant for signed addition.
+                let bits_le = (sum.to_field() + value.to_field()).to_lower_bits_le(I::BITS as usize + 1);
+                let next = match bits_le.split_last() {
+                    Some((_, bits_le)) => Integer::from_bits_le(bits_le),
+                    // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                    None => E::halt("Malformed sum detected during integer addition"),
+                };
+                // The partial sum wraps iff both operands have the same sign, and the result has the other sign.
+                let is_same_sign = sum.msb().is_equal(value.msb());
+                is_wrapped |= is_same_sign & next.msb().is_not_equal(sum.msb());
+                sum = next;
+            }
+            (sum, is_wrapped)
+        } else {
+            // As the running sum is monotonic, it wraps iff the total sum exceeds `I::MAX`.
+            // Sum all of the integers at once in the base field.
+            // Note: This is safe as the sum of `n` integers is less than `2^(I::BITS + k)`,
+            // where `k` is the bit length of `n - 1`, which is far smaller than the field.
+            let mut sum = first.to_field();
+            rest.iter().for_each(|value| sum += value.to_field());
+
+            // Extract the integer bits from the field element, with `k` carry bits.
+            let num_carry_bits = (usize::BITS - (values.len() - 1).leading_zeros()) as usize;
+            let mut bits_le = sum.to_lower_bits_le(I::BITS as usize + num_carry_bits);
+            let carry_bits = bits_le.split_off(I::BITS as usize);
+
+            // The sum wraps iff any of the carry bits is set.
+            let is_wrapped = carry_bits.iter().fold(Boolean::constant(false), |is_wrapped, bit| is_wrapped | bit);
+            (Integer { bits_le, phantom: Default::default() }, is_wrapped)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_sum_wrapping_flagged<I: IntegerType>(
+        name: &str,
+        values: &[console::Integer<<Circuit as Environment>::Network, I>],
+        mode: Mode,
+    ) {
+        // Compute the expected sum and flag.
+        let (expected_sum, expected_is_wrapped) =
+            values.iter().fold((I::zero(), false), |(sum, is_wrapped), value| match sum.checked_add(&**value) {
+                Some(sum) => (sum, is_wrapped),
+                None => (sum.wrapping_add(&**value), true),
+            });
+
+        let candidates = values.iter().map(|value| Integer::<Circuit, I>::new(mode, *value)).collect::<Vec<_>>();
+        Circuit::scope(name, || {
+            let (sum, is_wrapped) = Integer::sum_wrapping_flagged(&candidates);
+            assert_eq!(expected_sum, *sum.eject_value());
+            assert_eq!(expected_is_wrapped, is_wrapped.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+
+            // Check the documented number of constraints.
+            let n = values.len() as u64;
+            let expected_num_constraints = match (mode.is_constant() || n <= 1, I::is_signed()) {
+                (true, _) => 0,
+                (false, true) => (n - 1) * (I::BITS + 6) - 1,
+                (false, false) => I::BITS + 2 * (u64::BITS - (n - 1).leading_zeros()) as u64,
+            };
+            assert_eq!(expected_num_constraints, Circuit::num_constraints_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            for n in [0, 1, 2, 3, 4, 5, 8, 9] {
+                let values = (0..n).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+                let name = format!("SumWrappingFlagged: {mode} {n} {i}");
+                check_sum_wrapping_flagged::<I>(&name, &values, mode);
+            }
+        }
+
+        let one = console::Integer::one();
+        let two = one + one;
+        let three = two + one;
+
+        // Check a sequence that does not wrap.
+        check_sum_wrapping_flagged::<I>("1 + 2 + 3", &[one, two, three], mode);
+        check_sum_wrapping_flagged::<I>("(MAX - 3) + 1 + 2", &[console::Integer::MAX - three, one, two], mode);
+
+        // Check a sequence that wraps.
+        check_sum_wrapping_flagged::<I>("MAX + 1", &[console::Integer::MAX, one], mode);
+        check_sum_wrapping_flagged::<I>("MAX + MAX + MAX", &[console::Integer::MAX; 3], mode);
+
+        if I::is_signed() {
+            // Check a sequence that does not wrap, with mixed signs.
+            let values = [console::Integer::MAX, console::Integer::MIN, one];
+            check_sum_wrapping_flagged::<I>("MAX + MIN + 1", &values, mode);
+            // Check a sequence that wraps and returns into range, which is still flagged.
+            check_sum_wrapping_flagged::<I>("MAX + 1 + (-1)", &[console::Integer::MAX, one, -one], mode);
+            check_sum_wrapping_flagged::<I>("MIN + (-1) + 1", &[console::Integer::MIN, -one, one], mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, sum_wrapping_flagged);
+    test_integer_unary!(run_test, i16, sum_wrapping_flagged);
+    test_integer_unary!(run_test, i32, sum_wrapping_flagged);
+    test_integer_unary!(run_test, i64, sum_wrapping_flagged);
+    test_integer_unary!(run_test, i128, sum_wrapping_flagged);
+
+    test_integer_unary!(run_test, u8, sum_wrapping_flagged);
+    test_integer_unary!(run_test, u16, sum_wrapping_flagged);
+    test_integer_unary!(run_test, u32, sum_wrapping_flagged);
+    test_integer_unary!(run_test, u64, sum_wrapping_flagged);
+    test_integer_unary!(run_test, u128, sum_wrapping_flagged);
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the little-endian bits of the integer, extended to `target_bits` bits.
+    ///
+    /// A signed integer is extended by repeating its most significant bit, and an unsigned integer
+    /// is extended with zeros, so the output bits represent the same value in any width of at least `I::BITS`.
+    /// This method halts if `target_bits < I::BITS`.
+    ///
+    /// This method uses 0 constraints and 0 constants.
+    pub fn to_bits_le_sign_extended(&self, target_bits: usize) -> Vec<Boolean<E>> {
+        // Ensure the target width is at least the width of the integer.
+        if target_bits < I::BITS as usize {
+            E::halt(format!("Cannot extend '{}' to {target_bits} bits, as it has {} bits", I::type_name(), I::BITS))
+        }
+        // Determine the bit to extend the integer with.
+        let extension = match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        };
+        // Extend the bits of the integer.
+        let mut bits_le = Vec::with_capacity(target_bits);
+        bits_le.extend_from_slice(&self.bits_le);
+        bits_le.resize(target_bits, extension);
+        bits_le
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the bits of the value, manually sign-extended (or zero-extended) to `target_bits` bits.
+    fn sign_extend<I: IntegerType>(value: I, target_bits: usize) -> Vec<bool> {
+        // Widen the value to 128 bits, which sign-extends a signed value and zero-extends an unsigned value.
+        let wide = match I::is_signed() {
+            true => value.to_i128().unwrap() as u128,
+            false => value.to_u128().unwrap(),
+        };
+        // Extend the wide value beyond 128 bits with its sign.
+        let is_negative = value < I::zero();
+        (0..target_bits).map(|i| if i < 128 { (wide >> i) & 1 == 1 } else { is_negative }).collect()
+    }
+
+    fn check_to_bits_le_sign_extended<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        target_bits: usize,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = sign_extend(*value, target_bits);
+        Circuit::scope(name, || {
+            let candidate = a.to_bits_le_sign_extended(target_bits);
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+        let bits = I::BITS as usize;
+
+        // Select the target widths, including each integer width of at least `I::BITS`.
+        let mut target_bits = vec![bits, bits + 1, 2 * bits, 253];
+        target_bits.extend([8, 16, 32, 64, 128].into_iter().filter(|width| *width > bits));
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            for target_bits in &target_bits {
+                let name = format!("ToBitsLeSignExtended: {mode} {target_bits} {i}");
+                check_to_bits_le_sign_extended::<I>(&name, value, *target_bits, mode);
+            }
+        }
+
+        // Check the extremes of the integer type.
+        let boundaries = [console::Integer::zero(), console::Integer::MIN, console::Integer::MAX];
+        for (i, value) in boundaries.into_iter().enumerate() {
+            let name = format!("ToBitsLeSignExtended: {mode} boundary {i}");
+            check_to_bits_le_sign_extended::<I>(&name, value, 2 * bits, mode);
+        }
+
+        // Check that a target width less than the width of the integer halts.
+        let a = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut rng));
+        check_unary_operation_halts(a.clone(), move |a: Integer<Circuit, I>| a.to_bits_le_sign_extended(bits - 1));
+        check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.to_bits_le_sign_extended(0));
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, to_bits_le_sign_extended);
+    test_integer_unary!(run_test, i16, to_bits_le_sign_extended);
+    test_integer_unary!(run_test, i32, to_bits_le_sign_extended);
+    test_integer_unary!(run_test, i64, to_bits_le_sign_extended);
+    test_integer_unary!(run_test, i128, to_bits_le_sign_extended);
+
+    test_integer_unary!(run_test, u8, to_bits_le_sign_extended);
+    test_integer_unary!(run_test, u16, to_bits_le_sign_extended);
+    test_integer_unary!(run_test, u32, to_bits_le_sign_extended);
+    test_integer_unary!(run_test, u64, to_bits_le_sign_extended);
+    test_integer_unary!(run_test, u128, to_bits_le_sign_extended);
+}
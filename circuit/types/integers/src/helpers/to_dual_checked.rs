@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the signed integer as its same-width unsigned dual, if the value is non-negative.
+    ///
+    /// This method halts if `I` is unsigned, or if the constant integer is negative.
+    /// Otherwise, this method uses 1 constraint (0 for a constant integer).
+    pub fn to_unsigned_checked(&self) -> Integer<E, I::Dual> {
+        // Ensure the source type is signed.
+        if !I::is_signed() {
+            E::halt(format!("Cannot convert '{}' to unsigned, as it is already unsigned", I::type_name()))
+        }
+        self.to_dual_checked()
+    }
+
+    /// Returns the unsigned integer as its same-width signed dual, if the value is at most the signed maximum.
+    ///
+    /// This method halts if `I` is signed, or if the constant integer exceeds the signed maximum.
+    /// Otherwise, this method uses 1 constraint (0 for a constant integer).
+    pub fn to_signed_checked(&self) -> Integer<E, I::Dual> {
+        // Ensure the source type is unsigned.
+        if I::is_signed() {
+            E::halt(format!("Cannot convert '{}' to signed, as it is already signed", I::type_name()))
+        }
+        self.to_dual_checked()
+    }
+
+    /// Reinterprets the bits of the integer as its dual, enforcing that the value is representable in both types.
+    fn to_dual_checked(&self) -> Integer<E, I::Dual> {
+        if self.is_constant() {
+            // Ensure the constant value is within the range of both types.
+            let value = *self.eject_value();
+            if value < I::zero() || value.into_dual() < I::Dual::zero() {
+                E::halt(format!("Constant integer '{value}' is not representable as '{}'", I::Dual::type_name()))
+            }
+        } else {
+            // Enforce that the most significant bit is zero.
+            E::assert_eq(self.msb(), E::zero());
+        }
+        Integer::from_bits_le(&self.bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_to_dual_checked<I: IntegerType + UnwindSafe>(name: &str, value: I, mode: Mode) {
+        // Select the conversion for the source type.
+        let convert = |a: &Integer<Circuit, I>| match I::is_signed() {
+            true => a.to_unsigned_checked(),
+            false => a.to_signed_checked(),
+        };
+
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::new(value));
+        let expected = value.into_dual();
+        match value >= I::zero() && expected >= I::Dual::zero() {
+            true => Circuit::scope(name, || {
+                let candidate = convert(&a);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_eq!(a.to_bits_le().eject_value(), candidate.to_bits_le().eject_value());
+                match mode.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, 0, 1),
+                }
+            }),
+            false => match mode {
+                Mode::Constant => check_unary_operation_halts(a, move |a: Integer<Circuit, I>| convert(&a)),
+                _ => Circuit::scope(name, || {
+                    let _candidate = convert(&a);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("ToDualChecked: {mode} {i}");
+            let value: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            check_to_dual_checked::<I>(&name, *value, mode);
+        }
+
+        // Check the sign boundary, and the extremes of the source type.
+        let half = I::MAX.wrapping_shr(1);
+        for (i, value) in [I::zero(), I::one(), half, half + I::one(), I::MAX, I::MIN].into_iter().enumerate() {
+            let name = format!("ToDualChecked: {mode} boundary {i}");
+            check_to_dual_checked::<I>(&name, value, mode);
+        }
+
+        // Check that converting to the same signedness halts.
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::zero());
+        match I::is_signed() {
+            true => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.to_signed_checked()),
+            false => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.to_unsigned_checked()),
+        }
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, to_unsigned_checked);
+    test_integer_unary!(run_test, i16, to_unsigned_checked);
+    test_integer_unary!(run_test, i32, to_unsigned_checked);
+    test_integer_unary!(run_test, i64, to_unsigned_checked);
+    test_integer_unary!(run_test, i128, to_unsigned_checked);
+
+    test_integer_unary!(run_test, u8, to_signed_checked);
+    test_integer_unary!(run_test, u16, to_signed_checked);
+    test_integer_unary!(run_test, u32, to_signed_checked);
+    test_integer_unary!(run_test, u64, to_signed_checked);
+    test_integer_unary!(run_test, u128, to_signed_checked);
+}
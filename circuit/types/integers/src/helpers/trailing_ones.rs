@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the number of trailing one bits of the integer, which is `I::BITS` if every bit is set.
+    ///
+    /// For a non-constant integer, this method uses `I::BITS + k` constraints,
+    /// where `k` is the bit length of `I::BITS` (i.e. 12 constraints for 8-bit integers).
+    /// For a constant integer, this method uses 0 constraints.
+    pub fn trailing_ones(&self) -> Integer<E, u32> {
+        // If the integer is constant, compute the number of trailing ones directly.
+        if self.is_constant() {
+            return Integer::constant(console::Integer::new(self.eject_value().trailing_ones()));
+        }
+
+        // Scan the bits from least to most significant, counting the bits in the trailing run of ones.
+        let mut is_trailing: Vec<Boolean<E>> = Vec::with_capacity(I::BITS as usize);
+        for bit in &self.bits_le {
+            let next = match is_trailing.last() {
+                Some(previous) => previous & bit,
+                None => bit.clone(),
+            };
+            is_trailing.push(next);
+        }
+
+        // Sum the bits in the trailing run in the base field.
+        sum_as_u32(is_trailing.iter().map(Field::from_boolean), I::BITS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_trailing_ones<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Compute the expected count by scanning the bits from least to most significant.
+        let expected = value.to_bits_le().into_iter().take_while(|bit| *bit).count() as u32;
+        Circuit::scope(name, || {
+            let candidate = a.trailing_ones();
+            assert_eq!(expected, *candidate.eject_value());
+            match mode.is_constant() {
+                true => assert_scope!(32, 0, 0, 0),
+                false => {
+                    let num_bits = (u64::BITS - I::BITS.leading_zeros()) as u64;
+                    assert_scope!(0, 0, I::BITS - 1 + num_bits, I::BITS + num_bits);
+                }
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("TrailingOnes: {mode} {i}");
+            check_trailing_ones::<I>(&name, Uniform::rand(&mut rng), mode);
+        }
+
+        // Check the extremes, where `0` has no trailing ones, and the all-ones value has `I::BITS` trailing ones.
+        let all_ones = !I::zero();
+        let boundaries = [
+            I::zero(),
+            I::one(),
+            all_ones,
+            all_ones << 1,
+            all_ones.wrapping_shr(1),
+            I::MAX,
+            I::MIN,
+            I::MAX - I::one(),
+            (I::one() << 3) - I::one(),
+        ];
+        for (i, value) in boundaries.into_iter().enumerate() {
+            check_trailing_ones::<I>(&format!("TrailingOnes: {mode} boundary {i}"), console::Integer::new(value), mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, trailing_ones);
+    test_integer_unary!(run_test, i16, trailing_ones);
+    test_integer_unary!(run_test, i32, trailing_ones);
+    test_integer_unary!(run_test, i64, trailing_ones);
+    test_integer_unary!(run_test, i128, trailing_ones);
+
+    test_integer_unary!(run_test, u8, trailing_ones);
+    test_integer_unary!(run_test, u16, trailing_ones);
+    test_integer_unary!(run_test, u32, trailing_ones);
+    test_integer_unary!(run_test, u64, trailing_ones);
+    test_integer_unary!(run_test, u128, trailing_ones);
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the two's complement representation of the integer, as exactly `I::BITS` little-endian bits.
+    ///
+    /// The first bit is the least significant bit, and for signed integers, the last bit is the sign bit,
+    /// so `-1` is all ones, and `I::MIN` is all zeros except for the last bit.
+    /// For unsigned integers, this is the binary representation of the integer.
+    /// This method uses 0 constraints.
+    pub fn twos_complement_bits_le(&self) -> Vec<Boolean<E>> {
+        self.bits_le.clone()
+    }
+
+    /// Initializes an integer from its two's complement representation, as exactly `I::BITS` little-endian bits.
+    /// This is the inverse of `twos_complement_bits_le`.
+    ///
+    /// This method returns an error if the number of bits is not `I::BITS`.
+    /// Otherwise, this method uses 0 constraints.
+    pub fn from_twos_complement_bits_le(bits_le: &[Boolean<E>]) -> Result<Self> {
+        // Ensure the number of bits is exactly the integer size.
+        ensure!(
+            bits_le.len() as u64 == I::BITS,
+            "Cannot initialize '{}' from {} bits, as it requires exactly {} bits",
+            I::type_name(),
+            bits_le.len(),
+            I::BITS
+        );
+        Ok(Self { bits_le: bits_le.to_vec(), phantom: Default::default() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_twos_complement<I: IntegerType>(name: &str, value: I, mode: Mode) {
+        let expected = console::Integer::<<Circuit as Environment>::Network, I>::new(value);
+        let a = Integer::<Circuit, I>::new(mode, expected);
+
+        Circuit::scope(name, || {
+            // Ensure the bits are the little-endian bits of the integer.
+            let bits_le = a.twos_complement_bits_le();
+            assert_eq!(I::BITS as usize, bits_le.len());
+            assert_eq!(a.to_bits_le().eject_value(), bits_le.eject_value());
+
+            // Ensure the bits round-trip to the integer.
+            let candidate = Integer::<Circuit, I>::from_twos_complement_bits_le(&bits_le).unwrap();
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value: console::Integer<<Circuit as Environment>::Network, I> = Uniform::rand(&mut rng);
+            check_twos_complement::<I>(&format!("TwosComplement: {mode} {i}"), *value, mode);
+        }
+
+        // Check the extremes of the integer type.
+        for (i, value) in [I::zero(), I::one(), I::MIN, I::MAX].into_iter().enumerate() {
+            check_twos_complement::<I>(&format!("TwosComplement: {mode} boundary {i}"), value, mode);
+        }
+
+        if I::is_signed() {
+            let bits = |value: I| {
+                Integer::<Circuit, I>::new(mode, console::Integer::new(value)).twos_complement_bits_le().eject_value()
+            };
+            let num_bits = I::BITS as usize;
+
+            // Ensure `-1` is all ones.
+            let negative_one = I::zero().wrapping_sub(&I::one());
+            check_twos_complement::<I>(&format!("TwosComplement: {mode} -1"), negative_one, mode);
+            assert_eq!(vec![true; num_bits], bits(negative_one));
+            // Ensure `I::MIN` is all zeros, except for the sign bit.
+            let mut expected = vec![false; num_bits];
+            expected[num_bits - 1] = true;
+            assert_eq!(expected, bits(I::MIN));
+            // Ensure `I::MAX` is all ones, except for the sign bit.
+            let mut expected = vec![true; num_bits];
+            expected[num_bits - 1] = false;
+            assert_eq!(expected, bits(I::MAX));
+            Circuit::reset();
+        }
+
+        // Ensure initializing from an incorrect number of bits fails.
+        let bits_le = Integer::<Circuit, I>::new(mode, console::Integer::zero()).twos_complement_bits_le();
+        assert!(Integer::<Circuit, I>::from_twos_complement_bits_le(&bits_le[1..]).is_err());
+        assert!(Integer::<Circuit, I>::from_twos_complement_bits_le(&[bits_le.clone(), bits_le].concat()).is_err());
+        assert!(Integer::<Circuit, I>::from_twos_complement_bits_le(&[]).is_err());
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, twos_complement);
+    test_integer_unary!(run_test, i16, twos_complement);
+    test_integer_unary!(run_test, i32, twos_complement);
+    test_integer_unary!(run_test, i64, twos_complement);
+    test_integer_unary!(run_test, i128, twos_complement);
+
+    test_integer_unary!(run_test, u8, twos_complement);
+    test_integer_unary!(run_test, u16, twos_complement);
+    test_integer_unary!(run_test, u32, twos_complement);
+    test_integer_unary!(run_test, u64, twos_complement);
+    test_integer_unary!(run_test, u128, twos_complement);
+}
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the integer with the bits `[lo, lo + width)` replaced by the low `width` bits of `value`.
+    ///
+    /// The bits of `value` at or above index `width` are constrained to be zero (see `assert_fits_in_bits`),
+    /// so that `extract_bits(lo, lo + width)` on the output returns `value`.
+    /// This method halts if `lo + width > I::BITS`, or if `value` is a constant that does not fit in `width` bits.
+    ///
+    /// This method uses `I::BITS - width` constraints if `value` is not constant, and 0 constraints otherwise.
+    pub fn with_field_set(&self, lo: usize, width: usize, value: &Integer<E, I>) -> Self {
+        // Ensure the bit range is valid.
+        let hi = match lo.checked_add(width) {
+            Some(hi) if hi <= I::BITS as usize => hi,
+            _ => E::halt(format!("Invalid bit range [{lo}, {lo} + {width}) for '{}'", I::type_name())),
+        };
+        // Ensure the value fits in the field.
+        let value = value.assert_fits_in_bits(width);
+
+        // Replace the bits in the range with the low bits of the value.
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        bits_le.extend_from_slice(&self.bits_le[..lo]);
+        bits_le.extend_from_slice(&value.bits_le[..width]);
+        bits_le.extend_from_slice(&self.bits_le[hi..]);
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns a random integer that fits in the lower `width` bits.
+    fn sample_field<I: IntegerType>(width: usize, rng: &mut TestRng) -> console::Integer<CurrentNetwork, I> {
+        let mut bits_le = console::Integer::<CurrentNetwork, I>::rand(rng).to_bits_le();
+        bits_le[width..].iter_mut().for_each(|bit| *bit = false);
+        console::Integer::from_bits_le(&bits_le).unwrap()
+    }
+
+    fn check_with_field_set<I: IntegerType>(
+        name: &str,
+        first: console::Integer<CurrentNetwork, I>,
+        fields: &[(usize, usize, console::Integer<CurrentNetwork, I>)],
+        mode: Mode,
+    ) {
+        // Compute the expected value, by replacing the bits of each field.
+        let mut expected_bits_le = first.to_bits_le();
+        for (lo, width, value) in fields {
+            expected_bits_le[*lo..*lo + *width].copy_from_slice(&value.to_bits_le()[..*width]);
+        }
+        let expected = console::Integer::<_, I>::from_bits_le(&expected_bits_le).unwrap();
+
+        let a = Integer::<Circuit, I>::new(mode, first);
+        let values = fields.iter().map(|(_, _, value)| Integer::<Circuit, I>::new(mode, *value)).collect::<Vec<_>>();
+        Circuit::scope(name, || {
+            // Set each field in turn.
+            let mut candidate = a.clone();
+            for ((lo, width, _), value) in fields.iter().zip_eq(&values) {
+                candidate = candidate.with_field_set(*lo, *width, value);
+            }
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure each field is read back by `extract_bits`.
+            for (lo, width, value) in fields {
+                assert_eq!(*value, candidate.extract_bits(*lo, *lo + *width).eject_value());
+            }
+            // Check the constraints, as the upper bits of each value are constrained to be zero.
+            let num_constraints = match mode.is_constant() {
+                true => 0,
+                false => fields.iter().map(|(_, width, _)| I::BITS - *width as u64).sum::<u64>(),
+            };
+            assert_scope!(0, 0, 0, num_constraints);
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+        let bits = I::BITS as usize;
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+
+            // Check setting the low, middle, and high fields.
+            let fields = [(0, bits / 4), (bits / 4, bits / 2), (3 * bits / 4, bits / 4)]
+                .map(|(lo, width)| (lo, width, sample_field::<I>(width, &mut rng)));
+            for field in &fields {
+                check_with_field_set::<I>(&format!("WithFieldSet: {mode} {field:?} {i}"), first, &[*field], mode);
+            }
+            // Check setting the non-overlapping fields together.
+            check_with_field_set::<I>(&format!("WithFieldSet: {mode} packed {i}"), first, &fields, mode);
+
+            // Check the single bit, empty, and full fields.
+            let single = (bits - 1, 1, sample_field::<I>(1, &mut rng));
+            check_with_field_set::<I>(&format!("WithFieldSet: {mode} single {i}"), first, &[single], mode);
+            let empty = (3, 0, console::Integer::zero());
+            check_with_field_set::<I>(&format!("WithFieldSet: {mode} empty {i}"), first, &[empty], mode);
+            let full = (0, bits, Uniform::rand(&mut rng));
+            check_with_field_set::<I>(&format!("WithFieldSet: {mode} full {i}"), first, &[full], mode);
+        }
+
+        // Check that a value that does not fit in the field halts, or is not satisfied.
+        let a = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut rng));
+        let value = Integer::<Circuit, I>::new(mode, console::Integer::new(I::one() << 4));
+        match mode.is_constant() {
+            true => {
+                let set = |a: Integer<Circuit, I>, v: Integer<Circuit, I>| a.with_field_set(0, 4, &v);
+                check_operation_halts(a.clone(), value, set)
+            }
+            false => Circuit::scope("WithFieldSet: overflow", || {
+                let _candidate = a.with_field_set(0, 4, &value);
+                assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+            }),
+        }
+        Circuit::reset();
+
+        // Check that invalid ranges halt.
+        let value = Integer::<Circuit, I>::new(mode, console::Integer::zero());
+        let set = |lo: usize, width: usize| {
+            move |a: Integer<Circuit, I>, v: Integer<Circuit, I>| a.with_field_set(lo, width, &v)
+        };
+        check_operation_halts(a.clone(), value.clone(), set(1, bits));
+        check_operation_halts(a.clone(), value.clone(), set(bits + 1, 0));
+        check_operation_halts(a, value, set(usize::MAX, 2));
+        Circuit::reset();
+    }
+
+    test_integer_unary!(run_test, i8, with_field_set);
+    test_integer_unary!(run_test, i16, with_field_set);
+    test_integer_unary!(run_test, i32, with_field_set);
+    test_integer_unary!(run_test, i64, with_field_set);
+    test_integer_unary!(run_test, i128, with_field_set);
+
+    test_integer_unary!(run_test, u8, with_field_set);
+    test_integer_unary!(run_test, u16, with_field_set);
+    test_integer_unary!(run_test, u32, with_field_set);
+    test_integer_unary!(run_test, u64, with_field_set);
+    test_integer_unary!(run_test, u128, with_field_set);
+}
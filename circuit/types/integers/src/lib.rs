@@ -24,6 +24,7 @@ pub mod add_checked;
 pub mod add_wrapped;
 pub mod and;
 pub mod compare;
+pub mod cost;
 pub mod div_checked;
 pub mod div_wrapped;
 pub mod equal;
@@ -37,6 +38,8 @@ pub mod pow_checked;
 pub mod pow_wrapped;
 pub mod rem_checked;
 pub mod rem_wrapped;
+pub mod rotate_left;
+pub mod rotate_right;
 pub mod shl_checked;
 pub mod shl_wrapped;
 pub mod shr_checked;
@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateLeft<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `self` rotated left by `rhs` bits, where `rhs` is reduced modulo `I::BITS`.
+    #[inline]
+    fn rotate_left(&self, rhs: &Integer<E, M>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && rhs.is_constant() {
+            // Note: Casting `rhs` to `u32` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+            let value = self.eject_value().rotate_left(rhs.eject_value().to_u32().unwrap());
+            Integer::new(Mode::Constant, console::Integer::new(value))
+        } else {
+            // Determine the number of lower bits of `rhs` that encode `rhs` modulo `I::BITS`.
+            // Note: This is exact, as I::BITS = 8, 16, 32, 64, or 128.
+            let num_amount_bits = I::BITS.trailing_zeros() as usize;
+
+            let mut bits_le = self.bits_le.clone();
+            for (i, bit) in rhs.bits_le[..num_amount_bits].iter().enumerate() {
+                // Rotate the bits left by 2^i, which moves the bit at index `j` to index `j + 2^i`.
+                let mut rotated_bits_le = bits_le.clone();
+                rotated_bits_le.rotate_right(1 << i);
+                // If the bit is set, select the rotated bits.
+                // Note: If the bit is constant, this is a permutation of wires, and uses 0 constraints.
+                bits_le = rotated_bits_le
+                    .iter()
+                    .zip_eq(&bits_le)
+                    .map(|(rotated, unrotated)| Boolean::ternary(bit, rotated, unrotated))
+                    .collect();
+            }
+            Self { bits_le, phantom: Default::default() }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn RotateLeft<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // The number of ternary selections in the barrel rotation.
+        let num_selections = I::BITS.trailing_zeros() as u64 * I::BITS;
+
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (_, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // Note: Selecting between constant bits is free, so the count depends on the bits of `self`.
+            (Mode::Constant, _) => Count::less_than(0, 0, num_selections, num_selections),
+            (_, _) => Count::is(0, 0, num_selections, num_selections),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn RotateLeft<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (mode_a, Mode::Constant) => mode_a,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rotate_left<I: IntegerType, M: Magnitude>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let expected = first.rotate_left(second.to_u32().unwrap());
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        Circuit::scope(name, || {
+            let candidate = a.rotate_left(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(RotateLeft(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType, M: Magnitude + TryFrom<u64>>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("RotateLeft: {mode_a} <<> {mode_b} {i}");
+            check_rotate_left::<I, M>(&name, first, second, mode_a, mode_b);
+
+            // Check that rotating left by zero is the identity.
+            let name = format!("Identity: {mode_a} <<> {mode_b} {i}");
+            check_rotate_left::<I, M>(&name, first, console::Integer::zero(), mode_a, mode_b);
+
+            // Check that rotating left by one is computed correctly.
+            let name = format!("One: {mode_a} <<> {mode_b} {i}");
+            check_rotate_left::<I, M>(&name, first, console::Integer::one(), mode_a, mode_b);
+
+            // Check that rotating left by `I::BITS - 1` and `I::BITS` wraps around.
+            for amount in [I::BITS - 1, I::BITS] {
+                if let Ok(amount) = M::try_from(amount) {
+                    let name = format!("Wrap {amount}: {mode_a} <<> {mode_b} {i}");
+                    check_rotate_left::<I, M>(&name, first, console::Integer::new(amount), mode_a, mode_b);
+                }
+            }
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType, M: Magnitude>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+        RangeInclusive<M>: Iterator<Item = M>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in M::MIN..=M::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, M>::new(second);
+
+                let name = format!("RotateLeft: ({first} <<> {second})");
+                check_rotate_left::<I, M>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, u8, rotate_left);
+    test_integer_binary!(run_test, i8, u16, rotate_left);
+    test_integer_binary!(run_test, i8, u32, rotate_left);
+
+    test_integer_binary!(run_test, i16, u8, rotate_left);
+    test_integer_binary!(run_test, i16, u16, rotate_left);
+    test_integer_binary!(run_test, i16, u32, rotate_left);
+
+    test_integer_binary!(run_test, i32, u8, rotate_left);
+    test_integer_binary!(run_test, i32, u16, rotate_left);
+    test_integer_binary!(run_test, i32, u32, rotate_left);
+
+    test_integer_binary!(run_test, i64, u8, rotate_left);
+    test_integer_binary!(run_test, i64, u16, rotate_left);
+    test_integer_binary!(run_test, i64, u32, rotate_left);
+
+    test_integer_binary!(run_test, i128, u8, rotate_left);
+    test_integer_binary!(run_test, i128, u16, rotate_left);
+    test_integer_binary!(run_test, i128, u32, rotate_left);
+
+    test_integer_binary!(run_test, u8, u8, rotate_left);
+    test_integer_binary!(run_test, u8, u16, rotate_left);
+    test_integer_binary!(run_test, u8, u32, rotate_left);
+
+    test_integer_binary!(run_test, u16, u8, rotate_left);
+    test_integer_binary!(run_test, u16, u16, rotate_left);
+    test_integer_binary!(run_test, u16, u32, rotate_left);
+
+    test_integer_binary!(run_test, u32, u8, rotate_left);
+    test_integer_binary!(run_test, u32, u16, rotate_left);
+    test_integer_binary!(run_test, u32, u32, rotate_left);
+
+    test_integer_binary!(run_test, u64, u8, rotate_left);
+    test_integer_binary!(run_test, u64, u16, rotate_left);
+    test_integer_binary!(run_test, u64, u32, rotate_left);
+
+    test_integer_binary!(run_test, u128, u8, rotate_left);
+    test_integer_binary!(run_test, u128, u16, rotate_left);
+    test_integer_binary!(run_test, u128, u32, rotate_left);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, rotate_left, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, rotate_left, exhaustive);
+}
@@ -64,6 +64,10 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlAssign<&Integer<E, M>> for
 impl<E: Environment, I: IntegerType, M: Magnitude> ShlChecked<Integer<E, M>> for Integer<E, I> {
     type Output = Self;
 
+    /// Returns `self << rhs`, matching the native `checked_shl` semantics.
+    ///
+    /// This method halts (for constant operands), or is unsatisfiable, if `rhs >= I::BITS`,
+    /// or if a set bit is shifted past the most significant bit (i.e. the sign bit, for signed integers).
     #[inline]
     fn shl_checked(&self, rhs: &Integer<E, M>) -> Self::Output {
         // Retrieve the index for the first upper bit from the RHS that we mask.
@@ -77,8 +81,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlChecked<Integer<E, M>> for
                     Boolean::assert_bits_are_zero(&rhs.bits_le[first_upper_bit_index..]);
 
                     // Sign-extend `self` to 2 * I::BITS.
-                    let mut bits_le = self.to_bits_le();
-                    bits_le.resize(2 * I::BITS as usize, self.msb().clone());
+                    let bits_le = self.to_bits_le_sign_extended(2 * I::BITS as usize);
 
                     // Calculate the result directly in the field.
                     // Since 2^{rhs} < Integer::MAX and 3 * I::BITS is less than E::BaseField::size in data bits,
@@ -295,6 +298,25 @@ mod tests {
         }
     }
 
+    fn run_overflow_boundary_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe + TryFrom<u64>>(
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        for shift in 0..=I::BITS as u32 {
+            let second = console::Integer::<_, M>::new(M::try_from(shift as u64).unwrap_or_default());
+
+            // Check the largest and smallest values that can be shifted without overflow, and their neighbors,
+            // which shift a set bit (or, for negative values, a clear bit) past the most significant bit.
+            let max = I::MAX.wrapping_shr(shift);
+            let min = I::MIN.wrapping_shr(shift);
+            let values = [max, max.wrapping_add(&I::one()), min, min.wrapping_sub(&I::one())];
+            for (i, first) in values.into_iter().enumerate() {
+                let name = format!("Shl: {mode_a} << {mode_b} overflow boundary {shift} {i}");
+                check_shl::<I, M>(&name, console::Integer::new(first), second, mode_a, mode_b);
+            }
+        }
+    }
+
     fn run_exhaustive_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe + TryFrom<u64>>(
         mode_a: Mode,
         mode_b: Mode,
@@ -353,6 +375,9 @@ mod tests {
     test_integer_binary!(run_test, u128, u16, shl);
     test_integer_binary!(run_test, u128, u32, shl);
 
+    test_integer_binary!(run_overflow_boundary_test, i8, u8, shl_overflow_boundary);
+    test_integer_binary!(run_overflow_boundary_test, i128, u8, shl_overflow_boundary);
+
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, shl, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, shl, exhaustive);
 }
@@ -15,7 +15,7 @@
 #[macro_use]
 extern crate criterion;
 
-use snarkvm_console_algorithms::{BHP1024, BHP256, BHP512, BHP768};
+use snarkvm_console_algorithms::{BHP, BHP1024, BHP256, BHP512, BHP768};
 use snarkvm_console_types::prelude::*;
 use snarkvm_utilities::{TestRng, Uniform};
 
@@ -65,10 +65,36 @@ fn bhp1024(c: &mut Criterion) {
     c.bench_function(&format!("BHP1024 Hash - input size {}", input.len()), |b| b.iter(|| hash.hash(&input)));
 }
 
+fn bhp_hash_two(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+    let hash = BHP512::<Console>::setup("BHP512").unwrap();
+
+    // Sample two child nodes, as in the hash of a Merkle tree node.
+    let left = Field::<Console>::rand(rng).to_bits_le();
+    let right = Field::<Console>::rand(rng).to_bits_le();
+    c.bench_function(&format!("BHP512 Hash - concatenated input size {}", left.len() + right.len()), |b| {
+        b.iter(|| hash.hash(&[left.as_slice(), &right].concat()))
+    });
+    c.bench_function(&format!("BHP512 Hash Two - input size {}", left.len() + right.len()), |b| {
+        b.iter(|| hash.hash_two(&left, &right))
+    });
+}
+
+fn bhp_setup(c: &mut Criterion) {
+    c.bench_function("BHP Setup - 8 windows", |b| b.iter(|| BHP1024::<Console>::setup("BHP1024").unwrap()));
+    c.bench_function("BHP Setup - 64 windows", |b| b.iter(|| BHP::<Console, 64, 54>::setup("BHPSetup").unwrap()));
+}
+
 criterion_group! {
     name = bhp;
     config = Criterion::default().sample_size(1000);
-    targets = bhp256, bhp512, bhp768, bhp1024
+    targets = bhp256, bhp512, bhp768, bhp1024, bhp_hash_two
+}
+
+criterion_group! {
+    name = bhp_setup_group;
+    config = Criterion::default().sample_size(10);
+    targets = bhp_setup
 }
 
-criterion_main!(bhp);
+criterion_main!(bhp, bhp_setup_group);
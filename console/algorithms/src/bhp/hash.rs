@@ -19,7 +19,86 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Hash for BHP<
     type Output = Field<E>;
 
     /// Returns the BHP hash of the given input as a field element.
+    ///
+    /// Inputs of any length are supported, as inputs exceeding the capacity of one iteration
+    /// are chained through the hasher, block by block, after a length prefix (see `BHP`).
+    ///
+    /// As the preimage is already constructed as: [ DOMAIN || LENGTH(INPUT) || INPUT ],
+    /// inputs that differ only by trailing zero bits do not collide, and need no further prefix.
     fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
         Ok(self.hash_uncompressed(input)?.to_x_coordinate())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    /// Returns the BHP hash of the given input, by chaining each block of the input through the hasher by hand.
+    fn manual_hash<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>,
+        input: &[bool],
+    ) -> Result<Field<CurrentEnvironment>> {
+        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let num_data_bits = Field::<CurrentEnvironment>::size_in_data_bits();
+
+        let mut digest: Option<Group<CurrentEnvironment>> = None;
+        for block in input.chunks(num_hasher_bits - num_data_bits) {
+            // Prefix the block with the domain and length of the input, or with the previous digest.
+            let mut preimage = match digest {
+                None => [bhp.domain(), &(input.len() as u64).to_bits_le()[..]].concat(),
+                Some(digest) => digest.to_x_coordinate().to_bits_le()[..num_data_bits].to_vec(),
+            };
+            preimage.extend(block);
+            digest = Some(bhp.hasher.hash_uncompressed(&preimage)?);
+        }
+        Ok(digest.unwrap_or_else(Group::zero).to_x_coordinate())
+    }
+
+    fn check_hash_exceeding_capacity<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let block_size = num_hasher_bits - Field::<CurrentEnvironment>::size_in_data_bits();
+
+        // Check inputs within one block, at and around the block boundaries, and far exceeding the hasher capacity.
+        for num_bits in [1, block_size, block_size + 1, 3 * block_size - 1, 3 * block_size, 10 * num_hasher_bits + 7] {
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            assert_eq!(manual_hash(bhp, &input)?, bhp.hash(&input)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_exceeding_capacity() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash_exceeding_capacity(&BHP256::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_exceeding_capacity(&BHP512::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_exceeding_capacity(&BHP768::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_exceeding_capacity(&BHP1024::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)
+    }
+
+    #[test]
+    fn test_hash_trailing_zeros() -> Result<()> {
+        let mut rng = TestRng::default();
+        let bhp = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+
+        // Ensure the empty input and a single zero bit hash differently.
+        assert_ne!(bhp.hash(&[])?, bhp.hash(&[false])?);
+
+        // Ensure appending trailing zeros changes the hash, as the input is length-prefixed.
+        let input = (0..bhp.window_size() as usize).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        let expected = bhp.hash(&input)?;
+        for num_zeros in [1, BHP_CHUNK_SIZE, 64] {
+            let mut padded = input.clone();
+            padded.resize(input.len() + num_zeros, false);
+            assert_ne!(expected, bhp.hash(&padded)?);
+        }
+        Ok(())
+    }
+}
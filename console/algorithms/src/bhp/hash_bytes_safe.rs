@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given bytes as a field element.
+    ///
+    /// Each byte is expanded into its bits in little-endian order, in the order of the bytes, and hashed with `hash`.
+    /// As `hash` already prefixes the preimage with the length of the input in bits,
+    /// inputs that differ only by trailing zero bytes do not collide.
+    pub fn hash_bytes_safe(&self, input: &[u8]) -> Result<Field<E>> {
+        self.hash(&input.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_hash_bytes_safe<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        for i in 0..ITERATIONS {
+            let input = (0..i).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+
+            // Ensure the hash matches hashing the bits of the bytes directly.
+            let bits = input.iter().flat_map(|byte| (0..8).map(move |j| (byte >> j) & 1 == 1)).collect::<Vec<_>>();
+            let expected = bhp.hash(&bits)?;
+            assert_eq!(expected, bhp.hash_bytes_safe(&input)?);
+
+            // Ensure appending trailing zero bytes changes the hash.
+            for num_zeros in [1, 2, 8] {
+                let mut padded = input.clone();
+                padded.resize(input.len() + num_zeros, 0u8);
+                assert_ne!(expected, bhp.hash_bytes_safe(&padded)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_bytes_safe() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash_bytes_safe(&BHP256::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_bytes_safe(&BHP512::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_bytes_safe(&BHP768::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_bytes_safe(&BHP1024::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)
+    }
+
+    #[test]
+    fn test_hash_bytes_safe_empty() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+
+        // Ensure the empty input and a single zero byte hash differently.
+        assert_ne!(bhp.hash_bytes_safe(&[])?, bhp.hash_bytes_safe(&[0u8])?);
+        Ok(())
+    }
+}
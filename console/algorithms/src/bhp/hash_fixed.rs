@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the maximum number of input bits that are hashed in a single iteration.
+    pub fn fixed_input_size_in_bits(&self) -> usize {
+        NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE - Field::<E>::size_in_data_bits()
+    }
+
+    /// Returns the BHP hash of the given input, padded with `pad_bit` to the fixed input size, as a field element.
+    ///
+    /// As every preimage has the same length, the hash is always computed in exactly one iteration.
+    /// Note that an input ending in `pad_bit` is indistinguishable from the same input without it,
+    /// so callers should choose `pad_bit` (or encode the length) accordingly.
+    ///
+    /// This method returns an error if the input exceeds `fixed_input_size_in_bits`.
+    pub fn hash_fixed(&self, input: &[bool], pad_bit: bool) -> Result<Field<E>> {
+        // Ensure the input does not exceed the fixed input size.
+        let num_fixed_bits = self.fixed_input_size_in_bits();
+        ensure!(
+            input.len() <= num_fixed_bits,
+            "Inputs to the fixed-length BHP hash must be at most {num_fixed_bits} bits, found {} bits",
+            input.len()
+        );
+        // Pad the input to the fixed input size.
+        let mut preimage = Vec::with_capacity(num_fixed_bits);
+        preimage.extend_from_slice(input);
+        preimage.resize(num_fixed_bits, pad_bit);
+        // Hash the preimage.
+        self.hash(&preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_hash_fixed<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>,
+        expected_num_fixed_bits: usize,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let num_fixed_bits = bhp.fixed_input_size_in_bits();
+        assert_eq!(expected_num_fixed_bits, num_fixed_bits);
+
+        for i in 0..ITERATIONS {
+            let num_bits = (i * 7) % num_fixed_bits;
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+
+            // Ensure the hash matches hashing the padded preimage directly.
+            for pad_bit in [false, true] {
+                let mut preimage = input.clone();
+                preimage.resize(num_fixed_bits, pad_bit);
+                assert_eq!(bhp.hash(&preimage)?, bhp.hash_fixed(&input, pad_bit)?);
+            }
+
+            // Ensure inputs with the same prefix, but of different lengths and pad bits, hash differently.
+            let mut longer = input.clone();
+            longer.push(false);
+            assert_ne!(bhp.hash_fixed(&input, true)?, bhp.hash_fixed(&longer, false)?);
+            assert_ne!(bhp.hash_fixed(&input, false)?, bhp.hash_fixed(&input, true)?);
+        }
+
+        // Ensure an input of exactly the fixed input size is hashed without padding.
+        let input = (0..num_fixed_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        assert_eq!(bhp.hash(&input)?, bhp.hash_fixed(&input, false)?);
+        assert_eq!(bhp.hash(&input)?, bhp.hash_fixed(&input, true)?);
+
+        // Ensure an input exceeding the fixed input size fails.
+        let input = (0..num_fixed_bits + 1).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        assert!(bhp.hash_fixed(&input, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_fixed() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash_fixed(&BHP256::<CurrentEnvironment>::setup("BHPTest")?, 261, &mut rng)?;
+        check_hash_fixed(&BHP512::<CurrentEnvironment>::setup("BHPTest")?, 522, &mut rng)?;
+        check_hash_fixed(&BHP768::<CurrentEnvironment>::setup("BHPTest")?, 783, &mut rng)?;
+        check_hash_fixed(&BHP1024::<CurrentEnvironment>::setup("BHPTest")?, 1044, &mut rng)
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as the `(x, y)` affine coordinates of the group element.
+    ///
+    /// The x-coordinate is equal to `hash` on the same input, which returns only the x-coordinate.
+    pub fn hash_to_affine(&self, input: &[bool]) -> Result<(Field<E>, Field<E>)> {
+        Ok(self.hash_uncompressed(input)?.to_xy_coordinates())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_hash_to_affine<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let num_fixed_bits = bhp.fixed_input_size_in_bits();
+
+        // Check the empty input, a single iteration, and inputs spanning multiple iterations.
+        let mut lengths = vec![0, 1, num_fixed_bits, num_fixed_bits + 1, 3 * num_fixed_bits + 7];
+        lengths.extend((0..ITERATIONS).map(|_| rng.gen_range(0..2 * num_fixed_bits)));
+
+        for num_bits in lengths {
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let (x, y) = bhp.hash_to_affine(&input)?;
+            // Ensure the x-coordinate matches the hash.
+            assert_eq!(bhp.hash(&input)?, x);
+            // Ensure the affine point lies on the curve, and matches the uncompressed hash.
+            // Note: `Group::from_xy_coordinates` halts if the point is not on the curve.
+            assert_eq!(bhp.hash_uncompressed(&input)?, Group::from_xy_coordinates(x, y));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_affine() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash_to_affine(&BHP256::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_affine(&BHP512::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_affine(&BHP768::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_affine(&BHP1024::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)
+    }
+}
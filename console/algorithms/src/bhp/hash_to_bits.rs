@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the lower `num_bits` bits of the BHP hash of the given input.
+    ///
+    /// The hash is the x-coordinate of the output group element, which is decomposed into bits
+    /// in little-endian order, so the first bit of the output is the least significant bit.
+    /// As such, the output is always a prefix of `self.hash(input)?.to_bits_le()`.
+    ///
+    /// This method returns an error if `num_bits` exceeds the number of bits in the field.
+    pub fn hash_to_bits(&self, input: &[bool], num_bits: usize) -> Result<Vec<bool>> {
+        // Ensure the number of bits does not exceed the field size.
+        let max_bits = Field::<E>::size_in_bits();
+        ensure!(num_bits <= max_bits, "Cannot output more than {max_bits} bits from BHP, found {num_bits} bits");
+        // Hash the input, and decompose the x-coordinate into little-endian bits.
+        let mut bits = self.hash(input)?.to_bits_le();
+        // Truncate to the lower `num_bits` bits.
+        bits.truncate(num_bits);
+        Ok(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_hash_to_bits<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let max_bits = Field::<CurrentEnvironment>::size_in_bits();
+
+        for i in 0..ITERATIONS {
+            let input = (0..bhp.window_size() as u64 + i).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let expected = bhp.hash(&input)?.to_bits_le();
+
+            // Ensure the output is the little-endian prefix of the hash.
+            for num_bits in [0, 1, 64, 128, max_bits] {
+                assert_eq!(expected[..num_bits], bhp.hash_to_bits(&input, num_bits)?);
+            }
+
+            // Ensure requesting more bits than the field size fails.
+            assert!(bhp.hash_to_bits(&input, max_bits + 1).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash_to_bits(&BHP256::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_bits(&BHP512::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_bits(&BHP768::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_bits(&BHP1024::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)
+    }
+
+    #[test]
+    fn test_hash_to_bits_low_64_bits() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup("AleoBHP256")?;
+
+        // Hash the little-endian bits of the bytes of "snarkVM".
+        let bits = bhp.hash_to_bits(&b"snarkVM".to_bits_le(), 64)?;
+        assert_eq!(bits.len(), 64);
+
+        // Ensure the lower 64 bits of the x-coordinate match the expected value.
+        let candidate = bits.iter().rev().fold(0u64, |acc, bit| (acc << 1) | *bit as u64);
+        assert_eq!(candidate, 10861492630266652430);
+        Ok(())
+    }
+}
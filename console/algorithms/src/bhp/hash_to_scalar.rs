@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashToScalar for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    type Input = bool;
+    type Output = Scalar<E>;
+
+    /// Returns the BHP hash of the given input as a scalar.
+    ///
+    /// The input is hashed to the x-coordinate in the base field, and then projected onto the scalar field
+    /// by taking its lowest `Scalar::size_in_data_bits()` bits (1 bit less than the scalar MODULUS).
+    /// As these bits always encode a value less than the scalar MODULUS, the projection never requires
+    /// a modular reduction, and so it introduces no bias from wrapping around the scalar MODULUS.
+    /// This is the same projection as in `Poseidon::hash_to_scalar`, and it is fixed for compatibility.
+    fn hash_to_scalar(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Hash the input to the base field.
+        let output = self.hash(input)?;
+        // Convert the output to the scalar field,
+        // truncating to the size in data bits (1 bit less than the MODULUS) of the scalar.
+        Ok(Scalar::from_field_lossy(&output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_hash_to_scalar<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let num_data_bits = Scalar::<CurrentEnvironment>::size_in_data_bits();
+
+        for i in 0..ITERATIONS {
+            let input = (0..i * 11).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+
+            // Ensure the scalar is the lowest data bits of the field hash.
+            let candidate = bhp.hash_to_scalar(&input)?;
+            let expected = bhp.hash(&input)?.to_bits_le()[..num_data_bits].to_vec();
+            assert_eq!(expected, candidate.to_bits_le()[..num_data_bits]);
+            // Ensure the remaining bits of the scalar are zero.
+            assert!(candidate.to_bits_le()[num_data_bits..].iter().all(|bit| !bit));
+            // Ensure the scalar is deterministic.
+            assert_eq!(candidate, bhp.hash_to_scalar(&input)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_scalar() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash_to_scalar(&BHP256::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_scalar(&BHP512::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_scalar(&BHP768::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_to_scalar(&BHP1024::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)
+    }
+
+    #[test]
+    fn test_hash_to_scalar_projection_is_stable() -> Result<()> {
+        // Ensure the projection from the base field onto the scalar field is pinned, for known field elements.
+        let vectors = [
+            ("0field", "0scalar"),
+            ("12345field", "12345scalar"),
+            ("1809251394333065553493296640760748560207343510400633813116524750123642662969field", "12345scalar"),
+            (
+                "4222230874714185212124412469390773265687949667577031913967616727958704619520field",
+                "603728086048054105137819187869276145273262646775764287734567227711419318272scalar",
+            ),
+            (
+                "8444461749428370424248824938781546531375899335154063827935233455917409239040field",
+                "1207456172096108210275638375738552290546525293551528575469134455422838636544scalar",
+            ),
+        ];
+        for (field, expected) in vectors {
+            let field = Field::<CurrentEnvironment>::from_str(field)?;
+            assert_eq!(Scalar::<CurrentEnvironment>::from_str(expected)?, Scalar::from_field_lossy(&field));
+        }
+
+        // Ensure the BHP hash to scalar is pinned, for a known input.
+        let bhp = BHP256::<CurrentEnvironment>::setup("AleoBHP256")?;
+        let input = [true, false, true, false, true, false, true, false];
+        let expected = Scalar::<CurrentEnvironment>::from_str(
+            "1063767211932690162263415054573522447645990756946415343203622884881287531069scalar",
+        )?;
+        assert_eq!(expected, bhp.hash_to_scalar(&input)?);
+        Ok(())
+    }
+}
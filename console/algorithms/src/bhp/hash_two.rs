@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the concatenation of `left` and `right`, as a field element.
+    ///
+    /// This method is equivalent to `hash` on `left || right`, and is intended as a compression function
+    /// for two child nodes. When the combined input fits in a single iteration (see `fixed_input_size_in_bits`),
+    /// the preimage is assembled directly from both operands, skipping the concatenation and the chunking of `hash`.
+    pub fn hash_two(&self, left: &[bool], right: &[bool]) -> Result<Field<E>> {
+        // Compute the combined number of input bits.
+        let num_input_bits = left.len() + right.len();
+
+        match num_input_bits > 0 && num_input_bits <= self.fixed_input_size_in_bits() {
+            // Construct the preimage as: [ 0...0 || DOMAIN || LENGTH(INPUT) || LEFT || RIGHT ].
+            true => {
+                let mut preimage = Vec::with_capacity(self.domain.len() + 64 + num_input_bits);
+                preimage.extend(&self.domain);
+                (num_input_bits as u64).write_bits_le(&mut preimage);
+                preimage.extend_from_slice(left);
+                preimage.extend_from_slice(right);
+                // Hash the preimage in a single iteration.
+                Ok(self.hasher.hash_uncompressed(&preimage)?.to_x_coordinate())
+            }
+            // Otherwise, hash the concatenation of the operands over multiple iterations.
+            false => self.hash(&[left, right].concat()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_hash_two<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let num_fixed_bits = bhp.fixed_input_size_in_bits();
+
+        // Check the boundaries of a single iteration, and inputs spanning multiple iterations.
+        let mut lengths = vec![(0, 0), (0, 1), (1, 0), (0, num_fixed_bits), (num_fixed_bits, 0)];
+        lengths.extend([(num_fixed_bits / 2, num_fixed_bits - num_fixed_bits / 2), (num_fixed_bits, 1)]);
+        lengths.extend([(num_fixed_bits, num_fixed_bits), (3 * num_fixed_bits, num_fixed_bits + 7)]);
+        // Check random lengths, spanning up to two iterations.
+        lengths.extend((0..ITERATIONS).map(|_| (rng.gen_range(0..num_fixed_bits), rng.gen_range(0..num_fixed_bits))));
+
+        for (num_left_bits, num_right_bits) in lengths {
+            let left = (0..num_left_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let right = (0..num_right_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            // Ensure the hash matches hashing the concatenation of the operands.
+            assert_eq!(bhp.hash(&[left.as_slice(), &right].concat())?, bhp.hash_two(&left, &right)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_two() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash_two(&BHP256::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_two(&BHP512::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_two(&BHP768::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)?;
+        check_hash_two(&BHP1024::<CurrentEnvironment>::setup("BHPTest")?, &mut rng)
+    }
+}
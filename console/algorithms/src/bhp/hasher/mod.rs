@@ -18,7 +18,9 @@ use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
 use snarkvm_utilities::BigInteger;
 
-use std::sync::Arc;
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+use std::sync::{Arc, OnceLock};
 
 /// The BHP chunk size (this implementation is for a 3-bit BHP).
 pub(super) const BHP_CHUNK_SIZE: usize = 3;
@@ -26,10 +28,12 @@ pub(super) const BHP_LOOKUP_SIZE: usize = 1 << BHP_CHUNK_SIZE;
 
 /// BHP is a collision-resistant hash function that takes a variable-length input.
 /// The BHP hasher is used to process one internal iteration of the BHP hash function.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
     /// The bases for the BHP hash.
     bases: Arc<Vec<Vec<Group<E>>>>,
+    /// The bases for the BHP hash, in affine form, which are computed on first use.
+    bases_affine: OnceLock<Arc<Vec<Vec<E::Affine>>>>,
     /// The bases lookup table for the BHP hash.
     bases_lookup: Arc<Vec<Vec<[Group<E>; BHP_LOOKUP_SIZE]>>>,
     /// The random base for the BHP commitment.
@@ -52,26 +56,15 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
             range.muln(4); // range * 2^4
             maximum_window_size += 1;
         }
-        ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
+        ensure!(
+            WINDOW_SIZE <= maximum_window_size,
+            "The BHP window size ({WINDOW_SIZE}) exceeds the maximum BHP window size ({maximum_window_size})"
+        );
 
         // Compute the bases.
-        let bases = (0..NUM_WINDOWS)
-            .map(|index| {
-                // Construct an indexed message to attempt to sample a base.
-                let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!(
-                    "Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.{index}"
-                ));
-                let mut base = Group::<E>::new(generator);
-                // Compute the generators for the sampled base.
-                let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
-                for _ in 0..WINDOW_SIZE {
-                    powers.push(base);
-                    for _ in 0..4 {
-                        base = base.double();
-                    }
-                }
-                powers
-            })
+        // Note: The bases are collected in window order, so the result is independent of thread scheduling.
+        let bases = cfg_into_iter!(0..NUM_WINDOWS)
+            .map(|index| Self::window_bases(domain, index))
             .collect::<Vec<Vec<Group<E>>>>();
         ensure!(bases.len() == NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
         for window in &bases {
@@ -122,7 +115,53 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
             random_base.len()
         );
 
-        Ok(Self { bases: Arc::new(bases), bases_lookup: Arc::new(bases_lookup), random_base: Arc::new(random_base) })
+        Ok(Self {
+            bases: Arc::new(bases),
+            bases_affine: OnceLock::new(),
+            bases_lookup: Arc::new(bases_lookup),
+            random_base: Arc::new(random_base),
+        })
+    }
+
+    /// Ensures the base for the window at the given index, derived from the given domain, is the expected base.
+    ///
+    /// The base is recomputed from `hash_to_curve`, so that a change in the derivation of the bases
+    /// (i.e. from a dependency update) can be detected by pinning the expected base in a test.
+    pub fn assert_canonical_base(domain: &str, window: u8, expected: E::Affine) -> Result<()> {
+        // Ensure the window index is within the number of windows.
+        ensure!(window < NUM_WINDOWS, "The BHP window index {window} exceeds the {NUM_WINDOWS} windows");
+        // Recompute the base for the window.
+        let base = Self::window_base(domain, window);
+        ensure!(
+            base == expected,
+            "Found an unexpected BHP base for window {window} of '{domain}' ({}, expected {})",
+            Group::<E>::new(base),
+            Group::<E>::new(expected)
+        );
+        Ok(())
+    }
+
+    /// Returns the base sampled for the window at the given index.
+    fn window_base(domain: &str, index: u8) -> E::Affine {
+        // Construct an indexed message to attempt to sample a base.
+        let (generator, _, _) =
+            Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.{index}"));
+        generator
+    }
+
+    /// Returns the bases for the window at the given index.
+    fn window_bases(domain: &str, index: u8) -> Vec<Group<E>> {
+        // Sample the base for the window.
+        let mut base = Group::<E>::new(Self::window_base(domain, index));
+        // Compute the generators for the sampled base.
+        let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
+        for _ in 0..WINDOW_SIZE {
+            powers.push(base);
+            for _ in 0..4 {
+                base = base.double();
+            }
+        }
+        powers
     }
 
     /// Returns the bases.
@@ -130,8 +169,157 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
         &self.bases
     }
 
+    /// Returns the bases, in affine form.
+    ///
+    /// The affine bases are computed on the first call, using one batch normalization per window, and cached.
+    pub fn bases_affine(&self) -> &Arc<Vec<Vec<E::Affine>>> {
+        self.bases_affine.get_or_init(|| {
+            Arc::new(
+                self.bases
+                    .iter()
+                    .map(|window| {
+                        E::Projective::batch_normalization_into_affine(window.iter().map(|base| **base).collect())
+                    })
+                    .collect(),
+            )
+        })
+    }
+
     /// Returns the random base window.
     pub fn random_base(&self) -> &Arc<Vec<Group<E>>> {
         &self.random_base
     }
+
+    /// Returns the number of bases, which is `NUM_WINDOWS * WINDOW_SIZE`.
+    pub fn base_count(&self) -> usize {
+        self.bases.iter().map(|window| window.len()).sum()
+    }
+
+    /// Returns the size in bytes of the bases lookup table, which holds `BHP_LOOKUP_SIZE` group elements per base,
+    /// for a total of `NUM_WINDOWS * WINDOW_SIZE * BHP_LOOKUP_SIZE` group elements.
+    ///
+    /// Note: This excludes the allocation overhead of the vectors, and the bases in projective and affine form.
+    pub fn lookup_memory_bytes(&self) -> usize {
+        let num_lookups = self.bases_lookup.iter().map(|window| window.len()).sum::<usize>();
+        num_lookups * std::mem::size_of::<[Group<E>; BHP_LOOKUP_SIZE]>()
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PartialEq
+    for BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    /// Returns `true` if the hashers have the same bases, ignoring whether the affine bases have been computed.
+    fn eq(&self, other: &Self) -> bool {
+        self.bases == other.bases && self.bases_lookup == other.bases_lookup && self.random_base == other.random_base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    fn check_setup_is_deterministic<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(domain: &str) -> Result<()> {
+        // Compute the bases serially.
+        let expected = (0..NUM_WINDOWS)
+            .map(|index| BHPHasher::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::window_bases(domain, index))
+            .collect::<Vec<_>>();
+
+        // Ensure the bases from the (possibly parallel) setup match the serial bases.
+        let hasher = BHPHasher::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
+        assert_eq!(expected, **hasher.bases());
+        for (expected, candidate) in expected.iter().flatten().zip_eq(hasher.bases().iter().flatten()) {
+            assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+        }
+
+        // Ensure repeated setups produce identical parameters.
+        assert_eq!(hasher, BHPHasher::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?);
+        Ok(())
+    }
+
+    fn check_bases_affine<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(domain: &str) -> Result<()> {
+        let hasher = BHPHasher::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
+        assert_eq!(hasher.bases().len(), hasher.bases_affine().len());
+
+        // Ensure each affine base is the affine form of the corresponding projective base.
+        for (window, window_affine) in hasher.bases().iter().zip_eq(hasher.bases_affine().iter()) {
+            for (base, base_affine) in window.iter().zip_eq(window_affine) {
+                assert_eq!((**base).to_affine(), *base_affine);
+            }
+        }
+
+        // Ensure the affine bases are cached, and do not affect equality with a hasher that has not computed them.
+        assert!(Arc::ptr_eq(hasher.bases_affine(), hasher.bases_affine()));
+        assert_eq!(hasher, BHPHasher::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_canonical_base() -> Result<()> {
+        type Hasher = BHPHasher<CurrentEnvironment, 3, 57>;
+
+        // Pin the base for the first window of BHP256, with the domain "AleoBHP256".
+        let expected = (*Group::<CurrentEnvironment>::from_str(
+            "8082350955820896452846401243379720989747348587860352246988289609686025280006group",
+        )?)
+        .to_affine();
+        Hasher::assert_canonical_base("AleoBHP256", 0, expected)?;
+
+        // Ensure the pinned base is the first base from the setup.
+        let hasher = Hasher::setup("AleoBHP256")?;
+        assert_eq!(expected, hasher.bases_affine()[0][0]);
+        // Ensure the base for each window matches the setup.
+        for (window, bases) in (0u8..).zip(hasher.bases_affine().iter()) {
+            Hasher::assert_canonical_base("AleoBHP256", window, bases[0])?;
+        }
+
+        // Ensure a different domain, window, or base is rejected.
+        assert!(Hasher::assert_canonical_base("AleoBHP512", 0, expected).is_err());
+        assert!(Hasher::assert_canonical_base("AleoBHP256", 1, expected).is_err());
+        assert!(Hasher::assert_canonical_base("AleoBHP256", 0, hasher.bases_affine()[0][1]).is_err());
+        // Ensure an out-of-range window is rejected.
+        assert!(Hasher::assert_canonical_base("AleoBHP256", 3, expected).is_err());
+        Ok(())
+    }
+
+    fn check_lookup_memory_bytes<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(domain: &str) -> Result<()> {
+        let hasher = BHPHasher::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
+
+        // Ensure the number of bases matches the configuration.
+        let expected_base_count = NUM_WINDOWS as usize * WINDOW_SIZE as usize;
+        assert_eq!(expected_base_count, hasher.base_count());
+        assert_eq!(expected_base_count, hasher.bases_lookup.iter().flatten().count());
+
+        // Ensure the size of the lookup table matches the formula.
+        let expected = expected_base_count * BHP_LOOKUP_SIZE * std::mem::size_of::<Group<CurrentEnvironment>>();
+        assert_eq!(expected, hasher.lookup_memory_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bases_affine() -> Result<()> {
+        check_bases_affine::<3, 57>("BHPTest")?;
+        check_bases_affine::<6, 43>("BHPTest")?;
+        check_bases_affine::<15, 23>("BHPTest")?;
+        check_bases_affine::<8, 54>("BHPTest")
+    }
+
+    #[test]
+    fn test_lookup_memory_bytes() -> Result<()> {
+        check_lookup_memory_bytes::<3, 57>("BHPTest")?;
+        check_lookup_memory_bytes::<6, 43>("BHPTest")?;
+        check_lookup_memory_bytes::<15, 23>("BHPTest")?;
+        check_lookup_memory_bytes::<8, 54>("BHPTest")
+    }
+
+    #[test]
+    fn test_setup_is_deterministic() -> Result<()> {
+        check_setup_is_deterministic::<3, 57>("BHPTest")?;
+        check_setup_is_deterministic::<6, 43>("BHPTest")?;
+        check_setup_is_deterministic::<15, 23>("BHPTest")?;
+        check_setup_is_deterministic::<8, 54>("BHPTest")?;
+        check_setup_is_deterministic::<64, 16>("BHPTest")
+    }
 }
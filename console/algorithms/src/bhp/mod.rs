@@ -18,10 +18,21 @@ use hasher::BHPHasher;
 mod commit;
 mod commit_uncompressed;
 mod hash;
+mod hash_bytes_safe;
+mod hash_fixed;
+mod hash_to_affine;
+mod hash_to_bits;
+mod hash_to_scalar;
+mod hash_two;
 mod hash_uncompressed;
 
+#[cfg(test)]
+mod vectors;
+
 use snarkvm_console_types::prelude::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 use std::sync::Arc;
 
 const BHP_CHUNK_SIZE: usize = 3;
@@ -79,6 +90,14 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WI
         Ok(Self { domain, hasher })
     }
 
+    /// Initializes a new instance of BHP for each of the given domains.
+    ///
+    /// The instances are set up concurrently (unless the `serial` feature is enabled), and each instance
+    /// is identical to the one from `setup` on its domain, as the bases are derived solely from the domain.
+    pub fn batch_setup(domains: &[&str]) -> Result<Vec<Self>> {
+        cfg_iter!(domains).map(|domain| Self::setup(domain)).collect()
+    }
+
     /// Returns the domain separator for the BHP hash function.
     pub fn domain(&self) -> &[bool] {
         &self.domain
@@ -89,11 +108,26 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WI
         self.hasher.bases()
     }
 
+    /// Returns the bases, in affine form.
+    pub fn bases_affine(&self) -> &Arc<Vec<Vec<E::Affine>>> {
+        self.hasher.bases_affine()
+    }
+
     /// Returns the random base window.
     pub fn random_base(&self) -> &Arc<Vec<Group<E>>> {
         self.hasher.random_base()
     }
 
+    /// Returns the number of bases.
+    pub fn base_count(&self) -> usize {
+        self.hasher.base_count()
+    }
+
+    /// Returns the size in bytes of the bases lookup table.
+    pub fn lookup_memory_bytes(&self) -> usize {
+        self.hasher.lookup_memory_bytes()
+    }
+
     /// Returns the number of windows.
     pub fn num_windows(&self) -> u8 {
         NUM_WINDOWS
@@ -104,3 +138,46 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WI
         WINDOW_SIZE
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const DOMAINS: &[&str] = &["AleoBHP256", "AleoBHP512", "AleoBHP768", "AleoBHP1024", "BHPTest"];
+
+    fn check_batch_setup<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>() -> Result<()> {
+        // Ensure each instance matches the instance from setting up its domain individually.
+        let candidates = BHP::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::batch_setup(DOMAINS)?;
+        assert_eq!(DOMAINS.len(), candidates.len());
+        for (domain, candidate) in DOMAINS.iter().zip_eq(&candidates) {
+            assert_eq!(&BHP::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?, candidate);
+        }
+
+        // Ensure no domains yield no instances.
+        assert!(BHP::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::batch_setup(&[])?.is_empty());
+        // Ensure an oversized domain fails.
+        let domain = "a".repeat(Field::<CurrentEnvironment>::size_in_data_bits());
+        assert!(BHP::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::batch_setup(&["BHPTest", &domain]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_setup() -> Result<()> {
+        check_batch_setup::<3, 57>()?;
+        check_batch_setup::<6, 43>()?;
+        check_batch_setup::<15, 23>()?;
+        check_batch_setup::<8, 54>()
+    }
+
+    #[test]
+    fn test_setup_window_size_too_large() {
+        // Ensure an over-large window size returns an error, rather than panicking.
+        let error = BHP::<CurrentEnvironment, 1, 255>::setup("BHPTest").unwrap_err().to_string();
+        assert!(error.contains("window size (255) exceeds the maximum"), "Unexpected error: {error}");
+        let error = BHP::<CurrentEnvironment, 8, 128>::setup("BHPTest").unwrap_err().to_string();
+        assert!(error.contains("window size (128) exceeds the maximum"), "Unexpected error: {error}");
+    }
+}
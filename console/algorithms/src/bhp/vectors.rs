@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_types::environment::Console;
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+type CurrentEnvironment = Console;
+
+/// A BHP test vector, for cross-implementation conformance.
+#[derive(Serialize, Deserialize)]
+struct Vector {
+    /// The name of the BHP hasher (i.e. "AleoBHP256").
+    hasher: String,
+    /// The input bits, as a string of little-endian `0` and `1` characters.
+    input: String,
+    /// The output field element, in its canonical string representation.
+    output: String,
+}
+
+/// Returns the path to the BHP test vector file.
+fn vectors_path() -> PathBuf {
+    // Construct the path for the test vector file.
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("src");
+    path.push("bhp");
+    path.push("resources");
+    path.push("bhp_vectors.json");
+    path
+}
+
+/// The standard BHP hashers.
+struct Hashers {
+    bhp256: BHP256<CurrentEnvironment>,
+    bhp512: BHP512<CurrentEnvironment>,
+    bhp768: BHP768<CurrentEnvironment>,
+    bhp1024: BHP1024<CurrentEnvironment>,
+}
+
+impl Hashers {
+    /// Initializes the standard BHP hashers.
+    fn setup() -> Result<Self> {
+        Ok(Self {
+            bhp256: BHP256::setup("AleoBHP256")?,
+            bhp512: BHP512::setup("AleoBHP512")?,
+            bhp768: BHP768::setup("AleoBHP768")?,
+            bhp1024: BHP1024::setup("AleoBHP1024")?,
+        })
+    }
+
+    /// Returns the BHP hash of the given input, for the given hasher name.
+    fn hash(&self, hasher: &str, input: &[bool]) -> Result<Field<CurrentEnvironment>> {
+        match hasher {
+            "AleoBHP256" => self.bhp256.hash(input),
+            "AleoBHP512" => self.bhp512.hash(input),
+            "AleoBHP768" => self.bhp768.hash(input),
+            "AleoBHP1024" => self.bhp1024.hash(input),
+            _ => bail!("Unknown BHP hasher '{hasher}'"),
+        }
+    }
+}
+
+/// Returns the maximum number of input bits processed in the first iteration, for the given hasher.
+fn max_input_bits_per_iteration<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>() -> usize {
+    NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE - Field::<CurrentEnvironment>::size_in_data_bits()
+}
+
+/// Generates the BHP test vectors for the standard hashers.
+///
+/// The inputs include the empty input, short inputs, and inputs at (and past) the length of one iteration.
+fn generate_vectors(hashers: &Hashers) -> Result<Vec<Vector>> {
+    let configs = [
+        ("AleoBHP256", max_input_bits_per_iteration::<3, 57>()),
+        ("AleoBHP512", max_input_bits_per_iteration::<6, 43>()),
+        ("AleoBHP768", max_input_bits_per_iteration::<15, 23>()),
+        ("AleoBHP1024", max_input_bits_per_iteration::<8, 54>()),
+    ];
+
+    let mut vectors = Vec::new();
+    for (hasher, max_bits) in configs {
+        // Construct the inputs.
+        let inputs = [
+            vec![],
+            vec![false],
+            vec![true],
+            (0..8).map(|i| i % 2 == 0).collect::<Vec<_>>(),
+            vec![true; max_bits],
+            (0..max_bits + 1).map(|i| i % 3 == 0).collect::<Vec<_>>(),
+        ];
+        // Compute the vectors.
+        for input in inputs {
+            let output = hashers.hash(hasher, &input)?.to_string();
+            let input = input.iter().map(|bit| if *bit { '1' } else { '0' }).collect();
+            vectors.push(Vector { hasher: hasher.to_string(), input, output });
+        }
+    }
+    Ok(vectors)
+}
+
+/// Writes the BHP test vectors to `src/bhp/resources/bhp_vectors.json`.
+///
+/// To regenerate the test vectors, run `cargo test generate_bhp_vectors -- --ignored`, and commit the file.
+#[test]
+#[ignore]
+fn generate_bhp_vectors() -> Result<()> {
+    let vectors = generate_vectors(&Hashers::setup()?)?;
+    std::fs::write(vectors_path(), serde_json::to_string_pretty(&vectors)? + "\n")?;
+    Ok(())
+}
+
+/// Ensures the library matches the committed BHP test vectors.
+#[test]
+fn test_bhp_vectors() -> Result<()> {
+    let hashers = Hashers::setup()?;
+
+    // Load the committed test vectors.
+    let vectors: Vec<Vector> = serde_json::from_str(include_str!("./resources/bhp_vectors.json"))?;
+    ensure!(!vectors.is_empty(), "The BHP test vectors are empty");
+
+    // Check each test vector.
+    for vector in vectors {
+        let input = vector
+            .input
+            .chars()
+            .map(|c| match c {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => bail!("Invalid bit '{c}' in the BHP test vector input"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let candidate = hashers.hash(&vector.hasher, &input)?.to_string();
+        assert_eq!(vector.output, candidate, "{} on '{}'", vector.hasher, vector.input);
+    }
+    Ok(())
+}
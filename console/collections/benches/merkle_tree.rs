@@ -15,6 +15,8 @@
 #[macro_use]
 extern crate criterion;
 
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_collections::merkle_tree::MerkleTree;
 use snarkvm_console_network::{
     prelude::{TestRng, ToBits, Uniform},
     MainnetV0,
@@ -31,6 +33,7 @@ const MAX_INSTANTIATED_DEPTH: u8 = 16;
 const NUM_LEAVES: &[usize] = &[1, 10, 100, 1_000, 10_000, 100_000];
 const APPEND_SIZES: &[usize] = &[1, 10, 100, 1_000, 10_000, 100_000];
 const UPDATE_SIZES: &[usize] = &[1, 10, 100, 1_000, 10_000];
+const NUM_SPARSE_SET_LEAVES: usize = 10;
 
 /// Generates the specified number of random Merkle tree leaves.
 macro_rules! generate_leaves {
@@ -51,6 +54,38 @@ fn new(c: &mut Criterion) {
     }
 }
 
+fn rebuild(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+    // Initialize the hashers.
+    let leaf_hasher = BHP1024::<MainnetV0>::setup("AleoBHP1024").unwrap();
+    let path_hasher = BHP512::<MainnetV0>::setup("AleoBHP512").unwrap();
+    // Accumulate leaves in a vector to avoid recomputing across iterations.
+    let leaves = generate_leaves!(*NUM_LEAVES.last().unwrap(), &mut rng);
+    // Precompute the leaf hashes, to avoid recomputing across iterations.
+    let leaf_hashes = MainnetV0::merkle_tree_bhp::<DEPTH>(&leaves).unwrap().leaf_hashes().unwrap().to_vec();
+    for num_leaves in NUM_LEAVES {
+        // Benchmark rebuilding a Merkle tree from the leaves.
+        c.bench_function(&format!("MerkleTree/rebuild/leaves/{num_leaves}"), |b| {
+            b.iter(|| {
+                let _tree =
+                    MerkleTree::<MainnetV0, _, _, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves[..*num_leaves])
+                        .unwrap();
+            })
+        });
+        // Benchmark rebuilding a Merkle tree from the precomputed leaf hashes.
+        c.bench_function(&format!("MerkleTree/rebuild/leaf_hashes/{num_leaves}"), |b| {
+            b.iter(|| {
+                let _tree = MerkleTree::<MainnetV0, _, _, DEPTH>::new_from_leaf_hashes(
+                    &leaf_hasher,
+                    &path_hasher,
+                    &leaf_hashes[..*num_leaves],
+                )
+                .unwrap();
+            })
+        });
+    }
+}
+
 fn append(c: &mut Criterion) {
     let mut rng = TestRng::default();
     // Accumulate all leaves in a vector to avoid recomputing across iterations.
@@ -175,9 +210,52 @@ fn update_vs_update_many(c: &mut Criterion) {
     }
 }
 
+fn new_vs_new_sparse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("NewVSNewSparse");
+    let mut rng = TestRng::default();
+    // Initialize the hashers.
+    let leaf_hasher = BHP1024::<MainnetV0>::setup("AleoBHP1024").unwrap();
+    let path_hasher = BHP512::<MainnetV0>::setup("AleoBHP512").unwrap();
+    // Generate the default leaf, and the set leaves.
+    let default_leaf = generate_leaves!(1, &mut rng).pop().unwrap();
+    let set_leaves = generate_leaves!(NUM_SPARSE_SET_LEAVES, &mut rng);
+    for depth in [8, 12, MAX_INSTANTIATED_DEPTH] {
+        // Compute the number of leaves at this depth.
+        let num_leaves = 2usize.saturating_pow(depth as u32);
+        // Select a random index for each set leaf.
+        let set_leaves = set_leaves
+            .iter()
+            .map(|leaf| {
+                let index: usize = Uniform::rand(&mut rng);
+                (index % num_leaves, leaf.clone())
+            })
+            .collect::<BTreeMap<_, _>>();
+        // Construct the full list of leaves.
+        let leaves =
+            (0..num_leaves).map(|index| set_leaves.get(&index).unwrap_or(&default_leaf).clone()).collect::<Vec<_>>();
+        // Benchmark the construction of the Merkle tree from the full list of leaves.
+        group.bench_with_input(BenchmarkId::new("Dense", &format!("{depth}")), &leaves, |b, leaves| {
+            b.iter(|| MerkleTree::<MainnetV0, _, _, DEPTH>::new(&leaf_hasher, &path_hasher, leaves).unwrap())
+        });
+        // Benchmark the construction of the Merkle tree from the default leaf and the set leaves.
+        group.bench_with_input(BenchmarkId::new("Sparse", &format!("{depth}")), &set_leaves, |b, set_leaves| {
+            b.iter(|| {
+                MerkleTree::<MainnetV0, _, _, DEPTH>::new_sparse(
+                    &leaf_hasher,
+                    &path_hasher,
+                    num_leaves,
+                    &default_leaf,
+                    set_leaves,
+                )
+                .unwrap()
+            })
+        });
+    }
+}
+
 criterion_group! {
     name = merkle_tree;
     config = Criterion::default().sample_size(10);
-    targets = new, append, update, update_many, update_vs_update_many
+    targets = new, rebuild, append, update, update_many, update_vs_update_many, new_vs_new_sparse
 }
 criterion_main!(merkle_tree);
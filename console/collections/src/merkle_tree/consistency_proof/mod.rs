@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle proof that the first `old_size` leaves of a Merkle tree are unchanged in a larger Merkle tree,
+/// in the spirit of RFC 6962 consistency proofs.
+///
+/// The `frontier` contains the roots of the perfect subtrees that partition the first `old_size` leaves,
+/// from the smallest (rightmost) subtree to the largest (leftmost) subtree. From the level of the smallest
+/// subtree to the root, the boundary node is either a right child, whose left sibling is the next subtree
+/// in the `frontier`, or a left child, whose right sibling in the new Merkle tree is the next hash in `siblings`.
+/// The old Merkle root is recomputed from the `frontier` alone, as its right siblings are all empty.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConsistencyProof<E: Environment, const DEPTH: u8> {
+    /// The `frontier` contains the subtree roots of the old leaves, from the smallest subtree to the largest.
+    frontier: Vec<Field<E>>,
+    /// The `siblings` contains the right sibling hashes in the new Merkle tree, from the leaf level to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(Vec<Field<E>>, Vec<Field<E>>)> for ConsistencyProof<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle consistency proof.
+    fn try_from((frontier, siblings): (Vec<Field<E>>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the frontier is non-empty, and contains at most one subtree per level.
+        ensure!(!frontier.is_empty(), "Found an empty Merkle consistency proof frontier");
+        ensure!(frontier.len() <= DEPTH as usize + 1, "Found an incorrect Merkle consistency proof frontier length");
+        // Ensure the number of siblings is within the bound of one per level.
+        ensure!(siblings.len() <= DEPTH as usize, "Found an incorrect Merkle consistency proof length");
+        // Return the Merkle consistency proof.
+        Ok(Self { frontier, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ConsistencyProof<E, DEPTH> {
+    /// Returns the subtree roots of the old leaves, from the smallest subtree to the largest.
+    pub fn frontier(&self) -> &[Field<E>] {
+        &self.frontier
+    }
+
+    /// Returns the right sibling hashes in the new Merkle tree, from the leaf level to the root.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle consistency proof shows that the first `old_size` leaves of the Merkle tree
+    /// with root `new_root` and `new_size` leaves are the leaves of the Merkle tree with root `old_root`.
+    pub fn verify<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        old_root: &PH::Hash,
+        old_size: usize,
+        new_root: &PH::Hash,
+        new_size: usize,
+    ) -> bool {
+        // Ensure the old size is non-zero, and does not exceed the new size.
+        if old_size == 0 || old_size > new_size {
+            eprintln!("Found an invalid pair of Merkle tree sizes ({old_size}, {new_size})");
+            return false;
+        }
+        // Ensure the new size is within the tree depth.
+        else if new_size as u128 > (1u128 << DEPTH) {
+            eprintln!("Found an out of bounds Merkle tree size");
+            return false;
+        }
+
+        // Compute the level of the smallest subtree, which is the lowest set bit of the old size.
+        let start_level = old_size.trailing_zeros();
+        // Ensure the proof has the expected number of subtrees and siblings.
+        let num_subtrees = old_size.count_ones() as usize;
+        if self.frontier.len() != num_subtrees
+            || self.siblings.len() != (DEPTH as usize - start_level as usize) - (num_subtrees - 1)
+        {
+            eprintln!("Found an incorrect Merkle consistency proof length");
+            return false;
+        }
+
+        // Compute the number of levels in the old and new Merkle trees (excluding the padded levels).
+        let (old_levels, new_levels) = (num_levels(old_size), num_levels(new_size));
+
+        // Compute the empty hash, and the root of an empty subtree on each level.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize);
+        match path_hasher.hash_empty() {
+            Ok(empty_hash) => empty_hashes.push(empty_hash),
+            Err(error) => {
+                eprintln!("Failed to hash the empty hash during verification: {error}");
+                return false;
+            }
+        }
+        for level in 1..DEPTH as usize {
+            let previous = empty_hashes[level - 1];
+            match path_hasher.hash_children(&previous, &previous) {
+                Ok(hash) => empty_hashes.push(hash),
+                Err(error) => {
+                    eprintln!("Failed to hash an empty subtree during verification: {error}");
+                    return false;
+                }
+            }
+        }
+        let empty_hash = empty_hashes[0];
+
+        // Initialize the old and new hashes with the smallest subtree.
+        let (mut old_hash, mut new_hash) = (self.frontier[0], self.frontier[0]);
+        // Initialize iterators over the remaining subtrees and the siblings.
+        let mut frontier = self.frontier.iter().skip(1);
+        let mut siblings = self.siblings.iter();
+
+        // Check levels between the smallest subtree and the root.
+        for level in start_level..DEPTH as u32 {
+            // Determine if the boundary node is a right child on this level.
+            let is_right_child = level > start_level && (old_size >> level) & 1 == 1;
+            let ((old_left, old_right), (new_left, new_right)) = match is_right_child {
+                // If the boundary node is a right child, its left sibling is the next subtree.
+                true => match frontier.next() {
+                    Some(subtree) => ((*subtree, old_hash), (*subtree, new_hash)),
+                    None => {
+                        eprintln!("Found an incorrect Merkle consistency proof frontier length");
+                        return false;
+                    }
+                },
+                // If the boundary node is a left child, its right sibling is empty in the old Merkle tree,
+                // and is the next sibling in the new Merkle tree.
+                false => {
+                    let old_sibling = match level < old_levels {
+                        true => empty_hashes[level as usize],
+                        false => empty_hash,
+                    };
+                    let new_sibling = match siblings.next() {
+                        Some(sibling) => *sibling,
+                        None => {
+                            eprintln!("Found an incorrect Merkle consistency proof length");
+                            return false;
+                        }
+                    };
+                    // Ensure the right sibling in the new Merkle tree is empty, if it is beyond the new leaves.
+                    let sibling_start = ((((old_size - 1) >> level) as u128) + 1) << level;
+                    let expected_empty = match level < new_levels {
+                        true => (sibling_start >= new_size as u128).then_some(empty_hashes[level as usize]),
+                        false => Some(empty_hash),
+                    };
+                    if matches!(expected_empty, Some(expected) if expected != new_sibling) {
+                        eprintln!("Found a non-empty sibling beyond the new Merkle leaves");
+                        return false;
+                    }
+                    ((old_hash, old_sibling), (new_hash, new_sibling))
+                }
+            };
+            // Update the old and new hashes for the next level.
+            match (path_hasher.hash_children(&old_left, &old_right), path_hasher.hash_children(&new_left, &new_right)) {
+                (Ok(old), Ok(new)) => (old_hash, new_hash) = (old, new),
+                (Err(error), _) | (_, Err(error)) => {
+                    eprintln!("Failed to hash the Merkle consistency proof during verification: {error}");
+                    return false;
+                }
+            }
+        }
+
+        // Ensure the final hashes match the given roots.
+        old_hash == *old_root && new_hash == *new_root
+    }
+}
+
+/// Returns the number of levels in a Merkle tree with the given number of leaves, excluding the padded levels.
+fn num_levels(num_leaves: usize) -> u32 {
+    match num_leaves.checked_next_power_of_two() {
+        Some(num_leaves) => num_leaves.trailing_zeros(),
+        None => usize::BITS,
+    }
+}
@@ -12,12 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod consistency_proof;
+pub use consistency_proof::*;
+
 mod helpers;
 pub use helpers::*;
 
 mod path;
 pub use path::*;
 
+mod path_verifier;
+pub use path_verifier::*;
+
+mod range_proof;
+pub use range_proof::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -49,18 +58,61 @@ pub struct MerkleTree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHas
 impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
     MerkleTree<E, LH, PH, DEPTH>
 {
+    /// Validates the depth of the Merkle tree and the given path hasher, so that malformed parameters
+    /// fail early with a clear message, rather than while hashing the leaves.
+    ///
+    /// This method returns an error if the depth is not between 1 and 64, if the path hasher fails
+    /// to compute the empty hash, or if the path hasher does not depend on the order of the child nodes.
+    pub fn validate_parameters(path_hasher: &PH) -> Result<()> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Ensure the path hasher computes the empty hash.
+        let empty_hash = match path_hasher.hash_empty() {
+            Ok(empty_hash) => empty_hash,
+            Err(error) => bail!("Failed to compute the empty hash for the Merkle tree: {error}"),
+        };
+        // Ensure the path hasher depends on the order of the child nodes, as the siblings in a path are ordered.
+        let one = PH::Hash::one();
+        let left = path_hasher.hash_children(&one, &empty_hash)?;
+        let right = path_hasher.hash_children(&empty_hash, &one)?;
+        ensure!(left != right, "The Merkle tree path hasher must depend on the order of the child nodes");
+        Ok(())
+    }
+
     #[inline]
     /// Initializes a new Merkle tree with the given leaves.
     pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::new");
 
+        // Compute the leaf hashes.
+        let leaf_hashes = leaf_hasher.hash_leaves(leaves)?;
+        lap!(timer, "Hashed {} leaves", leaves.len());
+
+        // Initialize the Merkle tree from the leaf hashes.
+        let merkle_tree = Self::new_from_leaf_hashes(leaf_hasher, path_hasher, &leaf_hashes)?;
+
+        finish!(timer);
+        Ok(merkle_tree)
+    }
+
+    #[inline]
+    /// Initializes a new Merkle tree with the given (precomputed) leaf hashes.
+    ///
+    /// The resulting tree is identical to the one produced by `MerkleTree::new` on the original leaves,
+    /// and allows callers that already hold the leaf hashes to skip rehashing the leaves.
+    pub fn new_from_leaf_hashes(leaf_hasher: &LH, path_hasher: &PH, leaf_hashes: &[LH::Hash]) -> Result<Self> {
+        let timer = timer!("MerkleTree::new_from_leaf_hashes");
+
         // Ensure the Merkle tree depth is greater than 0.
         ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
         // Ensure the Merkle tree depth is less than or equal to 64.
         ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
 
         // Compute the maximum number of leaves.
-        let max_leaves = match leaves.len().checked_next_power_of_two() {
+        let max_leaves = match leaf_hashes.len().checked_next_power_of_two() {
             Some(num_leaves) => num_leaves,
             None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
         };
@@ -80,9 +132,9 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Initialize the Merkle tree.
         let mut tree = vec![empty_hash; tree_size];
 
-        // Compute and store each leaf hash.
-        tree[num_nodes..num_nodes + leaves.len()].copy_from_slice(&leaf_hasher.hash_leaves(leaves)?);
-        lap!(timer, "Hashed {} leaves", leaves.len());
+        // Store each leaf hash.
+        tree[num_nodes..num_nodes + leaf_hashes.len()].copy_from_slice(leaf_hashes);
+        lap!(timer, "Stored {} leaf hashes", leaf_hashes.len());
 
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
         let mut start_index = num_nodes;
@@ -115,7 +167,139 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             root: root_hash,
             tree,
             empty_hash,
-            number_of_leaves: leaves.len(),
+            number_of_leaves: leaf_hashes.len(),
+        })
+    }
+
+    #[inline]
+    /// Initializes a new Merkle tree with `num_leaves` leaves, where each leaf is the given default leaf,
+    /// except for the given set leaves, which are keyed by their leaf index.
+    ///
+    /// The resulting tree is identical to the one produced by `MerkleTree::new` on the full list of leaves.
+    /// However, as every subtree of default leaves (or of empty leaves) at a given level has the same hash,
+    /// this method hashes the default leaf once, and then each level once, and only hashes the nodes
+    /// on the paths from the set leaves (and from the last default leaf) to the root.
+    pub fn new_sparse(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        num_leaves: usize,
+        default_leaf: &LH::Leaf,
+        set_leaves: &BTreeMap<usize, LH::Leaf>,
+    ) -> Result<Self> {
+        let timer = timer!("MerkleTree::new_sparse");
+
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the latest set leaf index is less than the number of leaves.
+        if let Some((leaf_index, _)) = set_leaves.last_key_value() {
+            ensure!(*leaf_index < num_leaves, "Leaf index must be less than the number of leaves in the Merkle tree");
+        }
+
+        // Compute the maximum number of leaves.
+        let max_leaves = match num_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves,
+            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
+        };
+
+        // Compute the number of nodes.
+        let num_nodes = max_leaves - 1;
+        // Compute the tree size as the maximum number of leaves plus the number of nodes.
+        let tree_size = max_leaves + num_nodes;
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(tree_size)?;
+        // Compute the number of padded levels.
+        let padding_depth = DEPTH - tree_depth;
+
+        // Compute the empty hash.
+        let empty_hash = path_hasher.hash_empty()?;
+
+        // Initialize the Merkle tree.
+        let mut tree = vec![empty_hash; tree_size];
+
+        // Compute the default leaf hash, and store it for each leaf.
+        let mut default_hash = leaf_hasher.hash_leaf(default_leaf)?;
+        tree[num_nodes..num_nodes + num_leaves].fill(default_hash);
+
+        // A helper to compute the leaf hash.
+        let hash_leaf = |(leaf_index, leaf): (&usize, &LH::Leaf)| {
+            leaf_hasher.hash_leaf(leaf).map(|hash| (num_nodes + *leaf_index, hash))
+        };
+
+        // Hash the set leaves.
+        let leaf_hashes: Vec<(usize, LH::Hash)> = match set_leaves.len() {
+            0..=100 => set_leaves.iter().map(hash_leaf).collect::<Result<Vec<_>>>()?,
+            _ => cfg_iter!(set_leaves).map(hash_leaf).collect::<Result<Vec<_>>>()?,
+        };
+        // Store each set leaf hash.
+        for (index, hash) in &leaf_hashes {
+            tree[*index] = *hash;
+        }
+        lap!(timer, "Hashed {} set leaves", leaf_hashes.len());
+
+        // Initialize the indices of the nodes in the current level that differ from the default and empty hashes.
+        let mut updated_indices = leaf_hashes.into_iter().map(|(index, _)| index).collect::<Vec<_>>();
+        // Initialize the hash of an empty subtree in the current level.
+        let mut empty_subtree_hash = empty_hash;
+        // Initialize the number of nodes in the current level that are only above default leaves,
+        // and the number of nodes in the current level that are above at least one default leaf.
+        let (mut num_default_nodes, mut num_nonempty_nodes) = (num_leaves, num_leaves);
+
+        // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
+        let mut start_index = num_nodes;
+        // Compute the start index of the current level.
+        while let Some(start) = parent(start_index) {
+            // Compute the end index of the current level.
+            let end = left_child(start);
+
+            // Compute the hashes of the default subtree and of the empty subtree in the current level.
+            default_hash = path_hasher.hash_children(&default_hash, &default_hash)?;
+            empty_subtree_hash = path_hasher.hash_children(&empty_subtree_hash, &empty_subtree_hash)?;
+            num_default_nodes /= 2;
+            num_nonempty_nodes = (num_nonempty_nodes + 1) / 2;
+            // Store the default hash and the empty subtree hash for their respective nodes in the current level.
+            tree[start..start + num_default_nodes].fill(default_hash);
+            tree[start + num_nonempty_nodes..end].fill(empty_subtree_hash);
+
+            // Determine the nodes in the current level to hash, which are the parents of the updated nodes,
+            // along with the node above both default and empty leaves, if one exists.
+            let mut indices = updated_indices.iter().filter_map(|index| parent(*index)).collect::<Vec<_>>();
+            if num_default_nodes < num_nonempty_nodes {
+                indices.push(start + num_default_nodes);
+            }
+            indices.sort_unstable();
+            indices.dedup();
+
+            // Construct the children for each node to hash in the current level.
+            let tuples = indices.iter().map(|i| (tree[left_child(*i)], tree[right_child(*i)])).collect::<Vec<_>>();
+            // Compute and store the hashes for each node to hash in the current level.
+            for (index, hash) in indices.iter().zip_eq(path_hasher.hash_all_children(&tuples)?) {
+                tree[*index] = hash;
+            }
+            // Update the updated indices and the start index for the next level.
+            updated_indices = indices;
+            start_index = start;
+        }
+        lap!(timer, "Hashed {} levels", tree_depth);
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = tree[0];
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = path_hasher.hash_children(&root_hash, &empty_hash)?;
+        }
+        lap!(timer, "Hashed {} padding levels", padding_depth);
+
+        finish!(timer);
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: root_hash,
+            tree,
+            empty_hash,
+            number_of_leaves: num_leaves,
         })
     }
 
@@ -124,8 +308,24 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_append");
 
+        // Compute the new leaf hashes.
+        let new_leaf_hashes = self.leaf_hasher.hash_leaves(new_leaves)?;
+        lap!(timer, "Hashed {} new leaves", new_leaves.len());
+
+        // Compute the updated Merkle tree with the new leaf hashes.
+        let updated_tree = self.prepare_append_leaf_hashes(&new_leaf_hashes)?;
+
+        finish!(timer);
+        Ok(updated_tree)
+    }
+
+    #[inline]
+    /// Returns a new Merkle tree with the given (precomputed) new leaf hashes appended to it.
+    pub fn prepare_append_leaf_hashes(&self, new_leaf_hashes: &[LH::Hash]) -> Result<Self> {
+        let timer = timer!("MerkleTree::prepare_append_leaf_hashes");
+
         // Compute the maximum number of leaves.
-        let max_leaves = match (self.number_of_leaves + new_leaves.len()).checked_next_power_of_two() {
+        let max_leaves = match (self.number_of_leaves + new_leaf_hashes.len()).checked_next_power_of_two() {
             Some(num_leaves) => num_leaves,
             None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
         };
@@ -143,10 +343,10 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Extend the new Merkle tree with the existing leaf hashes.
         tree.extend(self.leaf_hashes()?);
         // Extend the new Merkle tree with the new leaf hashes.
-        tree.extend(&self.leaf_hasher.hash_leaves(new_leaves)?);
+        tree.extend(new_leaf_hashes);
         // Resize the new Merkle tree with empty hashes to pad up to `tree_size`.
         tree.resize(tree_size, self.empty_hash);
-        lap!(timer, "Hashed {} new leaves", new_leaves.len());
+        lap!(timer, "Stored {} new leaf hashes", new_leaf_hashes.len());
 
         // Initialize a start index to track the starting index of the current level.
         let start_index = num_nodes;
@@ -160,7 +360,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Initialize a precompute index to track the middle index of each precomputed level.
         let middle_precompute_index = match num_nodes == start_precompute_index {
             // If the old tree and new tree are of the same size, then we can copy over the right half of the old tree.
-            true => Some(start_precompute_index + self.number_of_leaves + new_leaves.len() + 1),
+            true => Some(start_precompute_index + self.number_of_leaves + new_leaf_hashes.len() + 1),
             // Otherwise, we need to compute the right half of the new tree.
             false => None,
         };
@@ -190,7 +390,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             root: root_hash,
             tree,
             empty_hash: self.empty_hash,
-            number_of_leaves: self.number_of_leaves + new_leaves.len(),
+            number_of_leaves: self.number_of_leaves + new_leaf_hashes.len(),
         })
     }
 
@@ -208,6 +408,57 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         Ok(())
     }
 
+    #[inline]
+    /// Updates the Merkle tree with the given (precomputed) new leaf hashes appended to it.
+    pub fn append_leaf_hashes(&mut self, new_leaf_hashes: &[LH::Hash]) -> Result<()> {
+        let timer = timer!("MerkleTree::append_leaf_hashes");
+
+        // Compute the updated Merkle tree with the new leaf hashes.
+        let updated_tree = self.prepare_append_leaf_hashes(new_leaf_hashes)?;
+        // Update the tree at the very end, so the original tree is not altered in case of failure.
+        *self = updated_tree;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    #[inline]
+    /// Returns a new Merkle tree with the leaves of `other` appended after the leaves of `self`.
+    ///
+    /// The leaf hashes of `other` are reused, and the internal nodes of `self` are reused where
+    /// the boundary allows, so the resulting root matches `new` on the concatenated leaves.
+    pub fn concat(&self, other: &Self) -> Result<Self> {
+        let timer = timer!("MerkleTree::concat");
+
+        // Ensure both Merkle trees use the same path hasher.
+        ensure!(self.empty_hash == other.empty_hash, "Cannot concatenate Merkle trees with different hashers");
+
+        // Compute the concatenated Merkle tree, by appending the leaf hashes of `other`.
+        let concatenated_tree = self.prepare_append_leaf_hashes(other.leaf_hashes()?)?;
+
+        finish!(timer);
+        Ok(concatenated_tree)
+    }
+
+    #[inline]
+    /// Returns a new Merkle tree with the existing leaves rebound to the given path hasher.
+    ///
+    /// As the Merkle tree stores the leaf hashes rather than the leaves, the leaf hasher is retained,
+    /// and the existing leaf hashes are rehashed under the new path hasher, which is validated first.
+    /// The resulting tree matches `new` with the new path hasher on the original leaves.
+    pub fn rebuild_with_parameters(&self, path_hasher: &PH) -> Result<Self> {
+        let timer = timer!("MerkleTree::rebuild_with_parameters");
+
+        // Ensure the new parameters are well-formed.
+        Self::validate_parameters(path_hasher)?;
+
+        // Compute the rebuilt Merkle tree from the existing leaf hashes.
+        let rebuilt_tree = Self::new_from_leaf_hashes(&self.leaf_hasher, path_hasher, self.leaf_hashes()?)?;
+
+        finish!(timer);
+        Ok(rebuilt_tree)
+    }
+
     #[inline]
     /// Updates the Merkle tree at the location of the given leaf index with the new leaf.
     pub fn update(&mut self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<()> {
@@ -222,11 +473,41 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         Ok(())
     }
 
+    #[inline]
+    /// Updates the Merkle tree at the location of the given leaf index with the (precomputed) new leaf hash.
+    pub fn update_leaf_hash(&mut self, leaf_index: usize, new_leaf_hash: &LH::Hash) -> Result<()> {
+        let timer = timer!("MerkleTree::update_leaf_hash");
+
+        // Compute the updated Merkle tree with the new leaf hash.
+        let updated_tree = self.prepare_update_leaf_hash(leaf_index, new_leaf_hash)?;
+        // Update the tree at the very end, so the original tree is not altered in case of failure.
+        *self = updated_tree;
+
+        finish!(timer);
+        Ok(())
+    }
+
     #[inline]
     /// Returns a new Merkle tree with updates at the location of the given leaf index with the new leaf.
     pub fn prepare_update(&self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_update");
 
+        // Compute the new leaf hash.
+        let new_leaf_hash = self.leaf_hasher.hash_leaf(new_leaf)?;
+        lap!(timer, "Hashed 1 new leaf");
+
+        // Compute the updated Merkle tree with the new leaf hash.
+        let updated_tree = self.prepare_update_leaf_hash(leaf_index, &new_leaf_hash)?;
+
+        finish!(timer);
+        Ok(updated_tree)
+    }
+
+    #[inline]
+    /// Returns a new Merkle tree with updates at the location of the given leaf index with the new leaf hash.
+    pub fn prepare_update_leaf_hash(&self, leaf_index: usize, new_leaf_hash: &LH::Hash) -> Result<Self> {
+        let timer = timer!("MerkleTree::prepare_update_leaf_hash");
+
         // Check that the leaf index is within the bounds of the Merkle tree.
         ensure!(
             leaf_index < self.number_of_leaves,
@@ -237,9 +518,8 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Allocate a vector to store the path hashes.
         let mut path_hashes = Vec::with_capacity(DEPTH as usize);
 
-        // Compute and add the new leaf hash to the path hashes.
-        path_hashes.push(self.leaf_hasher.hash_leaf(new_leaf)?);
-        lap!(timer, "Hashed 1 new leaf");
+        // Add the new leaf hash to the path hashes.
+        path_hashes.push(*new_leaf_hash);
 
         // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
         let start = match self.number_of_leaves.checked_next_power_of_two() {
@@ -538,12 +818,38 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         };
 
         // Compute the absolute index of the leaf in the Merkle tree.
-        let mut index = start + leaf_index;
+        let index = start + leaf_index;
         // Ensure the leaf index is valid.
         ensure!(index < self.tree.len(), "The given Merkle leaf index is out of bounds");
         // Ensure the leaf hash matches the one in the tree.
         ensure!(self.tree[index] == leaf_hash, "The given Merkle leaf does not match the one in the Merkle tree");
 
+        // Return the Merkle path.
+        self.prove_index(leaf_index, index)
+    }
+
+    #[inline]
+    /// Returns the index and Merkle path of the first leaf whose leaf hash satisfies the given predicate,
+    /// scanning the leaves in index order, or `None` if no leaf hash satisfies the predicate.
+    ///
+    /// Note: The Merkle tree only stores the leaf hashes, so the predicate is evaluated on the leaf hashes.
+    pub fn find(&self, predicate: impl Fn(&LH::Hash) -> bool) -> Result<Option<(usize, MerklePath<E, DEPTH>)>> {
+        // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
+        let start = match self.number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves - 1,
+            None => bail!("Integer overflow when computing the Merkle tree start index"),
+        };
+
+        // Find the first leaf hash that satisfies the predicate.
+        match self.leaf_hashes()?.iter().position(predicate) {
+            // Return the leaf index and its Merkle path.
+            Some(leaf_index) => Ok(Some((leaf_index, self.prove_index(leaf_index, start + leaf_index)?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the Merkle path for the given leaf index, located at the given absolute index in the Merkle tree.
+    fn prove_index(&self, leaf_index: usize, mut index: usize) -> Result<MerklePath<E, DEPTH>> {
         // Initialize a vector for the Merkle path.
         let mut path = Vec::with_capacity(DEPTH as usize);
 
@@ -575,11 +881,148 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
     }
 
+    #[inline]
+    /// Returns the range Merkle proof for the contiguous leaves in `[start, end)`.
+    pub fn generate_range_proof(&self, start: usize, end: usize) -> Result<RangeMerkleProof<E, DEPTH>> {
+        // Ensure the range is valid.
+        ensure!(start < end, "The given Merkle leaf range must be non-empty");
+        ensure!(end <= self.number_of_leaves, "The given Merkle leaf range is out of bounds");
+
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(self.tree.len())?;
+
+        // Initialize a vector for the boundary siblings.
+        let mut siblings = Vec::with_capacity(2 * DEPTH as usize);
+
+        // Initialize the indices of the first and last nodes on the current level.
+        let (mut first, mut last) = (start, end - 1);
+        // Iterate from the leaf level to the root level, storing the boundary siblings of each level.
+        for level in 0..DEPTH {
+            match level < tree_depth {
+                true => {
+                    // Compute the start index (on the left) of the current level in the Merkle tree.
+                    let level_start = (1usize << (tree_depth - level)) - 1;
+                    // If the first node is a right child, append its left sibling.
+                    if first % 2 == 1 {
+                        siblings.push(self.tree[level_start + first - 1]);
+                    }
+                    // If the last node is a left child, append its right sibling.
+                    if last % 2 == 0 {
+                        siblings.push(self.tree[level_start + last + 1]);
+                    }
+                }
+                // If the level is above the root of the Merkle tree, the sibling is the empty hash.
+                false => siblings.push(self.empty_hash),
+            }
+            // Update the indices for the next level.
+            first /= 2;
+            last /= 2;
+        }
+
+        // Return the range Merkle proof.
+        RangeMerkleProof::try_from((U64::new(start as u64), siblings))
+    }
+
+    /// Returns `true` if the given range Merkle proof is valid for the given root and the contiguous leaves at `start`.
+    pub fn verify_range_proof(
+        &self,
+        root: &PH::Hash,
+        start: usize,
+        leaves: &[LH::Leaf],
+        proof: &RangeMerkleProof<E, DEPTH>,
+    ) -> bool {
+        // Ensure the proof starts at the given leaf index.
+        if *proof.start() != start as u64 {
+            eprintln!("Found a mismatching range Merkle proof start index");
+            return false;
+        }
+        proof.verify(&self.leaf_hasher, &self.path_hasher, root, leaves)
+    }
+
+    #[inline]
+    /// Returns the Merkle consistency proof that the first `old_size` leaves are unchanged in this Merkle tree.
+    pub fn generate_consistency_proof(&self, old_size: usize) -> Result<ConsistencyProof<E, DEPTH>> {
+        // Ensure the old size is valid.
+        ensure!(old_size > 0, "The old Merkle tree size must be non-zero");
+        ensure!(old_size <= self.number_of_leaves, "The old Merkle tree size exceeds the number of leaves");
+
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(self.tree.len())?;
+        // Compute the level of the smallest subtree, which is the lowest set bit of the old size.
+        let start_level = u8::try_from(old_size.trailing_zeros())?;
+
+        // Initialize vectors for the frontier and the siblings.
+        let mut frontier = Vec::with_capacity(old_size.count_ones() as usize);
+        let mut siblings = Vec::with_capacity(DEPTH as usize);
+
+        // Iterate from the level of the smallest subtree to the root level.
+        for level in start_level..DEPTH {
+            // Compute the start index (on the left) of the current level in the Merkle tree.
+            let level_start = (1usize << tree_depth.saturating_sub(level)) - 1;
+            // Determine if the boundary node is a right child on this level.
+            let is_right_child = level > start_level && (old_size >> level) & 1 == 1;
+            // If this is the smallest subtree, or the boundary node is a right child, store the subtree on the left.
+            if level == start_level || is_right_child {
+                frontier.push(self.tree[level_start + (old_size >> level) - 1]);
+            }
+            // If the boundary node is a left child, store its right sibling.
+            if !is_right_child {
+                match level < tree_depth {
+                    true => siblings.push(self.tree[level_start + ((old_size - 1) >> level) + 1]),
+                    // If the level is above the root of the Merkle tree, the sibling is the empty hash.
+                    false => siblings.push(self.empty_hash),
+                }
+            }
+        }
+        // If the old leaves fill the entire depth, the frontier is the root.
+        if frontier.is_empty() {
+            frontier.push(self.tree[0]);
+        }
+
+        // Return the Merkle consistency proof.
+        ConsistencyProof::try_from((frontier, siblings))
+    }
+
+    /// Returns `true` if the given Merkle consistency proof shows that the first `old_size` leaves of
+    /// the Merkle tree with root `new_root` and `new_size` leaves are the leaves of the Merkle tree with root `old_root`.
+    pub fn verify_consistency_proof(
+        &self,
+        old_root: &PH::Hash,
+        old_size: usize,
+        new_root: &PH::Hash,
+        new_size: usize,
+        proof: &ConsistencyProof<E, DEPTH>,
+    ) -> bool {
+        proof.verify(&self.path_hasher, old_root, old_size, new_root, new_size)
+    }
+
     /// Returns the Merkle root of the tree.
     pub const fn root(&self) -> &PH::Hash {
         &self.root
     }
 
+    /// Returns the Merkle root of the tree, by value.
+    ///
+    /// This is an alias for `root`, as every constructor computes the root, including for zero leaves,
+    /// in which case the root is the empty-tree root (see `empty_root`).
+    pub const fn root_or_default(&self) -> PH::Hash {
+        self.root
+    }
+
+    /// Returns the Merkle root of a tree with no leaves, under the path hasher of this tree.
+    ///
+    /// A tree with no leaves stores only the empty hash `E`, so every level up to `DEPTH` is a padding level,
+    /// where the root is hashed with `E` (i.e. `R_0 = E` and `R_{i+1} = H(R_i, E)`), as in `new`.
+    /// Note that within a tree, an empty subtree is instead hashed as `E_{l+1} = H(E_l, E_l)` from `E_0 = E`.
+    pub fn empty_root(&self) -> Result<PH::Hash> {
+        // Compute the root hash, by hashing the root hash with the empty hash at each padding level up to `DEPTH`.
+        let mut root_hash = self.empty_hash;
+        for _ in 0..DEPTH {
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hash)?;
+        }
+        Ok(root_hash)
+    }
+
     /// Returns the Merkle tree (excluding the hashes of the leaves).
     pub fn tree(&self) -> &[PH::Hash] {
         &self.tree
@@ -603,11 +1046,147 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         Ok(&self.tree[start..end])
     }
 
+    /// Returns an iterator over the leaf hashes of the Merkle tree, in index order, excluding the padding.
+    ///
+    /// Note that the Merkle tree does not store its leaves, so an audit may rehash a candidate leaf sequence,
+    /// and compare the result against this iterator.
+    pub fn iter_leaf_hashes(&self) -> Result<impl Iterator<Item = &LH::Hash> + '_> {
+        Ok(self.leaf_hashes()?.iter())
+    }
+
+    /// Returns the hashes of the nodes at the given level of the Merkle tree, from left to right.
+    ///
+    /// Level 0 holds the leaf hashes, including the empty hashes that pad the leaves to a power of two,
+    /// and each level above holds half as many nodes as the level below, down to a single node.
+    /// The padded levels above the stored tree each hold a single node, so level `DEPTH` holds only the root.
+    pub fn level_hashes(&self, level: usize) -> Result<Vec<PH::Hash>> {
+        // Ensure the level is within the Merkle tree.
+        ensure!(level <= DEPTH as usize, "Merkle tree level {level} exceeds the depth {DEPTH}");
+
+        // Compute the number of stored levels in the Merkle tree.
+        let tree_depth = tree_depth::<DEPTH>(self.tree.len())? as usize;
+        match level <= tree_depth {
+            // Return the stored nodes at the level, which start at index `2^(tree_depth - level) - 1`.
+            true => {
+                let start = (1usize << (tree_depth - level)) - 1;
+                Ok(self.tree[start..left_child(start)].to_vec())
+            }
+            // Compute the node at the padded level, by hashing the stored root with the empty hash.
+            false => {
+                let mut hash = self.tree[0];
+                for _ in tree_depth..level {
+                    hash = self.path_hasher.hash_children(&hash, &self.empty_hash)?;
+                }
+                Ok(vec![hash])
+            }
+        }
+    }
+
+    /// Returns the frontier of the Merkle tree, from the largest (leftmost) subtree to the smallest (rightmost).
+    ///
+    /// The frontier contains the roots of the perfect subtrees that partition the leaves,
+    /// with one subtree for each set bit in the number of leaves, and is empty for an empty Merkle tree.
+    pub fn frontier(&self) -> Vec<PH::Hash> {
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = self.tree.len().ilog2();
+
+        // Initialize a vector for the frontier, and the number of leaves to the left of the current subtree.
+        let mut frontier = Vec::with_capacity(self.number_of_leaves.count_ones() as usize);
+        let mut offset = 0usize;
+
+        // Iterate from the level of the largest subtree down to the leaf level.
+        for level in (0..=tree_depth).rev() {
+            if (self.number_of_leaves >> level) & 1 == 1 {
+                // Compute the start index (on the left) of the current level in the Merkle tree.
+                let level_start = (1usize << (tree_depth - level)) - 1;
+                // Store the root of the subtree, and advance past its leaves.
+                frontier.push(self.tree[level_start + (offset >> level)]);
+                offset += 1usize << level;
+            }
+        }
+        frontier
+    }
+
     /// Returns the number of leaves in the Merkle tree.
     pub const fn number_of_leaves(&self) -> usize {
         self.number_of_leaves
     }
 
+    /// Returns the depth of the Merkle tree.
+    pub const fn depth(&self) -> u8 {
+        DEPTH
+    }
+
+    /// Returns the number of sibling hashes in each Merkle path, which is the depth of the Merkle tree.
+    pub const fn path_length(&self) -> usize {
+        DEPTH as usize
+    }
+
+    /// Returns the number of bytes in a serialized Merkle path for the Merkle tree.
+    ///
+    /// A Merkle path consists of the leaf index followed by `path_length` sibling hashes,
+    /// so its size is independent of the leaf, and may be used to size buffers ahead of time.
+    pub fn estimated_proof_bytes(&self) -> usize {
+        U64::<E>::size_in_bytes() + self.path_length() * Field::<E>::size_in_bytes()
+    }
+
+    /// Returns a digest of the path parameters of the Merkle tree, which are the depth and the path hasher,
+    /// so that drift in these parameters between deployments may be detected by comparing a single hash.
+    ///
+    /// The digest hashes the depth with the empty hash and the hash of an ordered pair of child nodes,
+    /// which are independent of the leaves, so trees with the same depth and path hasher share a digest.
+    /// Note that the digest does not cover the leaf hasher, as leaves are generic and may not be sampled here,
+    /// so trees that differ only in their leaf hasher share a digest.
+    pub fn path_parameters_digest(&self) -> Result<PH::Hash> {
+        // Hash an ordered pair of child nodes, to commit to the path hasher beyond the empty hash.
+        let children_hash = self.path_hasher.hash_children(&PH::Hash::one(), &self.empty_hash)?;
+        let hasher_digest = self.path_hasher.hash_children(&self.empty_hash, &children_hash)?;
+        // Hash the depth with the path hasher digest.
+        self.path_hasher.hash_children(&Field::from_u8(DEPTH), &hasher_digest)
+    }
+
+    /// Returns a Graphviz DOT representation of the Merkle tree, for debugging.
+    ///
+    /// Each node is labeled with a prefix of its hash, and each leaf is drawn as a box,
+    /// where the empty leaves (padding the tree to a power of two) are dashed.
+    /// The root of the full Merkle tree, including the padded levels up to `DEPTH`, is the graph label.
+    pub fn to_dot(&self) -> String {
+        // A helper to truncate a hash into a node label.
+        let label = |hash: &PH::Hash| {
+            let hash = hash.to_string();
+            match hash.len() > DOT_LABEL_LENGTH {
+                true => format!("{}...", &hash[..DOT_LABEL_LENGTH]),
+                false => hash,
+            }
+        };
+
+        // Compute the number of internal nodes, as the tree stores `2 * max_leaves - 1` hashes.
+        let num_nodes = self.tree.len() / 2;
+
+        let mut dot = String::from("digraph MerkleTree {\n");
+        dot.push_str(&format!("    label=\"root: {}\";\n", label(&self.root)));
+        for (index, hash) in self.tree.iter().enumerate() {
+            match index < num_nodes {
+                // Write the internal node, and the edges to its children.
+                true => {
+                    dot.push_str(&format!("    n{index} [label=\"{}\"];\n", label(hash)));
+                    dot.push_str(&format!("    n{index} -> n{};\n", left_child(index)));
+                    dot.push_str(&format!("    n{index} -> n{};\n", right_child(index)));
+                }
+                // Write the leaf, dashing the empty leaves.
+                false => {
+                    let style = match index - num_nodes < self.number_of_leaves {
+                        true => "",
+                        false => ", style=dashed",
+                    };
+                    dot.push_str(&format!("    n{index} [label=\"{}\", shape=box{style}];\n", label(hash)));
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
     /// Compute and store the hashes for each level, iterating from the penultimate level to the root level.
     ///
     /// ```ignore
@@ -720,6 +1299,36 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     }
 }
 
+/// Returns `true` if the frontiers are consistent with the Merkle tree of `shorter_size` leaves being a prefix
+/// of the Merkle tree of `longer_size` leaves, where each frontier is ordered as in `MerkleTree::frontier`.
+///
+/// Two append-only Merkle trees share the subtrees for the set bits above the highest bit where their sizes differ,
+/// so only these subtrees are compared. The remaining subtrees of the shorter frontier are contained in a single
+/// subtree of the longer frontier, and a divergence within them is only detected by a Merkle consistency proof.
+pub fn frontier_is_prefix<H: PartialEq>(shorter: &[H], longer: &[H], shorter_size: usize, longer_size: usize) -> bool {
+    // Ensure the shorter size does not exceed the longer size.
+    if shorter_size > longer_size {
+        return false;
+    }
+    // Ensure each frontier has one subtree for each set bit of its size.
+    if shorter.len() != shorter_size.count_ones() as usize || longer.len() != longer_size.count_ones() as usize {
+        return false;
+    }
+    // If the sizes are equal, the frontiers must be identical.
+    if shorter_size == longer_size {
+        return shorter == longer;
+    }
+    // Compute the highest bit where the sizes differ, which is set in the longer size.
+    let highest_bit = (shorter_size ^ longer_size).ilog2();
+    // Compute the number of subtrees above the highest differing bit, which both frontiers share.
+    let num_shared = shorter_size.checked_shr(highest_bit + 1).unwrap_or(0).count_ones() as usize;
+    // Check that the shared subtrees are identical.
+    shorter[..num_shared] == longer[..num_shared]
+}
+
+/// The number of characters of each hash to display in a node label of the DOT representation.
+const DOT_LABEL_LENGTH: usize = 8;
+
 /// Returns the depth of the tree, given the size of the tree.
 #[inline]
 fn tree_depth<const DEPTH: u8>(tree_size: usize) -> Result<u8> {
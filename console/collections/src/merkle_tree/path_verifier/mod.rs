@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A verifier for a Merkle path that is streamed one level at a time, from the leaf to the root.
+///
+/// The verifier only holds the hash of the current level, so that a Merkle path can be verified
+/// as its sibling hashes are received, without holding the entire Merkle path in memory.
+#[derive(Clone, Debug)]
+pub struct PathVerifier<'a, PH: PathHash, const DEPTH: u8> {
+    /// The path hasher for the Merkle tree.
+    path_hasher: &'a PH,
+    /// The hash of the current level, which is `None` if a sibling could not be hashed.
+    current_hash: Option<PH::Hash>,
+    /// The number of sibling hashes that have been pushed.
+    num_siblings: usize,
+}
+
+impl<'a, PH: PathHash, const DEPTH: u8> PathVerifier<'a, PH, DEPTH> {
+    /// Initializes a new Merkle path verifier, seeded with the hash of the given leaf.
+    pub fn new<LH: LeafHash<Hash = PH::Hash>>(leaf_hasher: &LH, path_hasher: &'a PH, leaf: &LH::Leaf) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Compute the leaf hash to start.
+        let leaf_hash = leaf_hasher.hash_leaf(leaf)?;
+        // Return the Merkle path verifier.
+        Ok(Self { path_hasher, current_hash: Some(leaf_hash), num_siblings: 0 })
+    }
+
+    /// Returns the number of sibling hashes that have been pushed.
+    pub const fn num_siblings(&self) -> usize {
+        self.num_siblings
+    }
+
+    /// Hashes the current hash with the given sibling hash, to compute the hash of the next level.
+    /// If `is_left` is `true`, then the sibling is the left child, otherwise the sibling is the right child.
+    ///
+    /// If more than `DEPTH` siblings are pushed, or a sibling fails to hash, the Merkle path is rejected on `finalize`.
+    pub fn push_sibling(&mut self, sibling: PH::Hash, is_left: bool) {
+        self.num_siblings = self.num_siblings.saturating_add(1);
+        if let Some(current_hash) = self.current_hash {
+            // Construct the ordering of the left & right child hash for this level.
+            let (left, right) = match is_left {
+                true => (sibling, current_hash),
+                false => (current_hash, sibling),
+            };
+            // Update the current hash for the next level.
+            self.current_hash = match self.path_hasher.hash_children(&left, &right) {
+                Ok(hash) => Some(hash),
+                Err(error) => {
+                    eprintln!("Failed to hash the Merkle path during verification: {error}");
+                    None
+                }
+            };
+        }
+    }
+
+    /// Returns `true` if exactly `DEPTH` siblings were pushed, and the final hash matches the given root.
+    pub fn finalize(self, expected_root: &PH::Hash) -> bool {
+        // Ensure the path length matches the expected depth.
+        if self.num_siblings != DEPTH as usize {
+            eprintln!("Found an incorrect Merkle path length");
+            return false;
+        }
+        // Ensure the final hash matches the given root.
+        self.current_hash == Some(*expected_root)
+    }
+}
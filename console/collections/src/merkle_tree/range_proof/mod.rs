@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle proof that a contiguous range of leaves are in the Merkle tree, starting at a given leaf index.
+///
+/// On each level, from the leaf level to the root, the range of nodes requires at most
+/// two boundary siblings: the left sibling of the first node, if it is a right child,
+/// and the right sibling of the last node, if it is a left child.
+/// The `siblings` are stored in this order, level by level.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RangeMerkleProof<E: Environment, const DEPTH: u8> {
+    /// The leaf index of the first leaf in the range.
+    start: U64<E>,
+    /// The `siblings` contains the boundary sibling hashes, from the leaf level to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(U64<E>, Vec<Field<E>>)> for RangeMerkleProof<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a range Merkle proof.
+    fn try_from((start, siblings): (U64<E>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the start index is within the tree depth.
+        ensure!((*start as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
+        // Ensure the number of siblings is within the bound of two per level.
+        ensure!(siblings.len() <= 2 * DEPTH as usize, "Found an incorrect range Merkle proof length");
+        // Return the range Merkle proof.
+        Ok(Self { start, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> RangeMerkleProof<E, DEPTH> {
+    /// Returns the leaf index of the first leaf in the range.
+    pub fn start(&self) -> U64<E> {
+        self.start
+    }
+
+    /// Returns the boundary siblings for the proof.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the range Merkle proof is valid for the given root and contiguous leaves.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> bool {
+        // Ensure the range is non-empty.
+        if leaves.is_empty() {
+            eprintln!("Found an empty range of Merkle leaves");
+            return false;
+        }
+        // Ensure the range is within the tree depth.
+        else if (*self.start as u128).saturating_add(leaves.len() as u128) > (1u128 << DEPTH) {
+            eprintln!("Found an out of bounds range of Merkle leaves");
+            return false;
+        }
+
+        // Initialize a tracker for the current hashes, by computing the leaf hashes to start.
+        let mut current_hashes = match leaf_hasher.hash_leaves(leaves) {
+            Ok(leaf_hashes) => leaf_hashes,
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaves during verification: {error}");
+                return false;
+            }
+        };
+        // Initialize a tracker for the index of the first node on the current level.
+        let mut first = *self.start;
+        // Initialize an iterator over the boundary siblings.
+        let mut siblings = self.siblings.iter();
+
+        // Check levels between leaf level and root.
+        for _ in 0..DEPTH {
+            // Compute the index of the last node on the current level.
+            let last = first + current_hashes.len() as u64 - 1;
+            // If the first node is a right child, prepend its left sibling.
+            if first % 2 == 1 {
+                match siblings.next() {
+                    Some(sibling) => current_hashes.insert(0, *sibling),
+                    None => {
+                        eprintln!("Found an incorrect range Merkle proof length");
+                        return false;
+                    }
+                }
+            }
+            // If the last node is a left child, append its right sibling.
+            if last % 2 == 0 {
+                match siblings.next() {
+                    Some(sibling) => current_hashes.push(*sibling),
+                    None => {
+                        eprintln!("Found an incorrect range Merkle proof length");
+                        return false;
+                    }
+                }
+            }
+            // Construct the children for each parent on the next level.
+            let tuples = current_hashes.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect::<Vec<_>>();
+            // Update the current hashes for the next level.
+            match path_hasher.hash_all_children(&tuples) {
+                Ok(hashes) => current_hashes = hashes,
+                Err(error) => {
+                    eprintln!("Failed to hash the range Merkle proof during verification: {error}");
+                    return false;
+                }
+            }
+            // Update the index of the first node for the next level.
+            first /= 2;
+        }
+
+        // Ensure all of the siblings were used.
+        if siblings.next().is_some() {
+            eprintln!("Found an incorrect range Merkle proof length");
+            return false;
+        }
+        // Ensure the final hash matches the given root.
+        current_hashes.len() == 1 && current_hashes[0] == *root
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Runs the following test:
+/// 1. Construct a Merkle tree from the left leaves, and from the right leaves.
+/// 2. Concatenate the Merkle trees.
+/// 3. Check that the concatenated Merkle tree is identical to the Merkle tree of the concatenated leaves.
+fn check_merkle_tree_concat<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    left_leaves: &[LH::Leaf],
+    right_leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle trees for the left and right leaves.
+    let left = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, left_leaves)?;
+    let right = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, right_leaves)?;
+
+    // Construct the Merkle tree for the concatenated leaves.
+    let leaves = left_leaves.iter().chain(right_leaves).cloned().collect::<Vec<_>>();
+    let expected = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves)?;
+
+    // Concatenate the Merkle trees.
+    let candidate = left.concat(&right)?;
+
+    // Check that the Merkle trees are identical.
+    assert_eq!(expected.root(), candidate.root());
+    assert_eq!(expected.tree(), candidate.tree());
+    assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+
+    // Check that the original Merkle trees are unchanged.
+    assert_eq!(left_leaves.len(), left.number_of_leaves());
+    assert_eq!(right_leaves.len(), right.number_of_leaves());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_concat() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the left and right leaves.
+            let num_left_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            let num_right_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_left_leaves, ITERATIONS - i);
+
+            // Check the Merkle tree.
+            check_merkle_tree_concat::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_left_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+                &(0..num_right_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_concat() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the left and right leaves.
+            let num_left_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            let num_right_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_left_leaves, ITERATIONS - i);
+
+            // Check the Merkle tree.
+            check_merkle_tree_concat::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_left_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+                &(0..num_right_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_concat_exceeds_capacity() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct two Merkle trees of depth 2, with a combined number of leaves exceeding the capacity of 4.
+    let left_leaves = (0..3).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let right_leaves = (0..2).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let left = MerkleTree::<CurrentEnvironment, LH, PH, 2>::new(&leaf_hasher, &path_hasher, &left_leaves)?;
+    let right = MerkleTree::<CurrentEnvironment, LH, PH, 2>::new(&leaf_hasher, &path_hasher, &right_leaves)?;
+
+    // Ensure the concatenation fails.
+    assert!(left.concat(&right).is_err());
+    // Ensure the concatenation succeeds once the combined number of leaves fits.
+    let right = MerkleTree::<CurrentEnvironment, LH, PH, 2>::new(&leaf_hasher, &path_hasher, &right_leaves[..1])?;
+    assert_eq!(4, left.concat(&right)?.number_of_leaves());
+    Ok(())
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Runs the following test:
+/// 1. Construct the old Merkle tree, and the new Merkle tree with the additional leaves appended.
+/// 2. Check that the consistency proof verifies for the old and new roots.
+/// 3. Check that the consistency proof fails for mismatching roots and sizes.
+/// 4. Check that the consistency proof fails if an old leaf was altered.
+fn check_merkle_tree_consistency_proof<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    additional_leaves: &[LH::Leaf],
+) -> Result<()> {
+    let (old_size, new_size) = (leaves.len(), leaves.len() + additional_leaves.len());
+
+    // Construct the old and new Merkle trees.
+    let old_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    let new_tree = old_tree.prepare_append(additional_leaves)?;
+    let (old_root, new_root) = (old_tree.root(), new_tree.root());
+
+    // Ensure an empty old Merkle tree fails.
+    if old_size == 0 {
+        assert!(new_tree.generate_consistency_proof(0).is_err());
+        return Ok(());
+    }
+
+    // Check that the consistency proof verifies.
+    let proof = new_tree.generate_consistency_proof(old_size)?;
+    assert!(new_tree.verify_consistency_proof(old_root, old_size, new_root, new_size, &proof));
+    // Check that a consistency proof with the same size verifies.
+    let proof_to_self = new_tree.generate_consistency_proof(new_size)?;
+    assert!(new_tree.verify_consistency_proof(new_root, new_size, new_root, new_size, &proof_to_self));
+    // Ensure the consistency proof cannot exceed the number of leaves.
+    assert!(new_tree.generate_consistency_proof(new_size + 1).is_err());
+
+    // Check that the consistency proof fails for mismatching roots.
+    assert!(!new_tree.verify_consistency_proof(&Field::zero(), old_size, new_root, new_size, &proof));
+    assert!(!new_tree.verify_consistency_proof(old_root, old_size, &Field::zero(), new_size, &proof));
+    if !additional_leaves.is_empty() {
+        assert!(!new_tree.verify_consistency_proof(new_root, old_size, old_root, new_size, &proof));
+    }
+    // Check that the consistency proof fails for mismatching sizes.
+    assert!(!new_tree.verify_consistency_proof(old_root, old_size + 1, new_root, new_size, &proof));
+    assert!(!new_tree.verify_consistency_proof(old_root, old_size - 1, new_root, new_size, &proof));
+    assert!(!new_tree.verify_consistency_proof(old_root, old_size, new_root, old_size - 1, &proof));
+
+    // Alter the first old leaf, by replacing it with the last old leaf.
+    if old_size > 1 {
+        let mut altered_leaves = leaves.to_vec();
+        altered_leaves[0] = leaves[old_size - 1].clone();
+        let altered_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &altered_leaves)?;
+        // Ensure the consistency proof fails for the altered old Merkle tree.
+        assert!(!new_tree.verify_consistency_proof(altered_tree.root(), old_size, new_root, new_size, &proof));
+
+        // Ensure a consistency proof from the altered new Merkle tree fails for the original old Merkle tree.
+        let altered_tree = altered_tree.prepare_append(additional_leaves)?;
+        let altered_root = altered_tree.root();
+        let altered_proof = altered_tree.generate_consistency_proof(old_size)?;
+        assert!(!altered_tree.verify_consistency_proof(old_root, old_size, altered_root, new_size, &altered_proof));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_consistency_proof() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the leaves and additional leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            let num_additional_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_leaves, ITERATIONS - i);
+
+            // Check the Merkle tree.
+            check_merkle_tree_consistency_proof::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+                &(0..num_additional_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_consistency_proof() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the leaves and additional leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            let num_additional_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_leaves, ITERATIONS - i);
+
+            // Check the Merkle tree.
+            check_merkle_tree_consistency_proof::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+                &(0..num_additional_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
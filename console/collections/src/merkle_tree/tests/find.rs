@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const DEPTH: u8 = 8;
+
+#[test]
+fn test_merkle_tree_find() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct the Merkle tree for random leaves.
+    let leaves = (0..10).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Find each leaf by its (unique) leaf hash.
+    for (expected_index, leaf) in leaves.iter().enumerate() {
+        let leaf_hash = leaf_hasher.hash_leaf(leaf)?;
+        let (leaf_index, path) = merkle_tree.find(|candidate| *candidate == leaf_hash)?.unwrap();
+        assert_eq!(expected_index, leaf_index);
+        // Ensure the Merkle path is valid, and matches the one from `prove`.
+        assert!(merkle_tree.verify(&path, merkle_tree.root(), leaf));
+        assert_eq!(merkle_tree.prove(leaf_index, leaf)?, path);
+    }
+
+    // Ensure the first matching leaf is returned.
+    let (leaf_index, _) = merkle_tree.find(|_| true)?.unwrap();
+    assert_eq!(0, leaf_index);
+
+    // Ensure no leaf is found if no leaf hash satisfies the predicate.
+    assert!(merkle_tree.find(|_| false)?.is_none());
+    assert!(merkle_tree.find(|candidate| *candidate == Field::zero())?.is_none());
+
+    // Ensure no leaf is found in an empty Merkle tree.
+    let empty = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &[])?;
+    assert!(empty.find(|_| true)?.is_none());
+    Ok(())
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const MAX_LEAVES: u128 = 16;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for each prefix of the leaves, as in an append-only sequence.
+/// 2. Check that each frontier holds the roots of the perfect subtrees that partition the leaves.
+/// 3. Check that each frontier is a prefix of the frontiers of the longer Merkle trees, and not the reverse.
+/// 4. Replace one leaf, and check that the prefix relationship fails when the divergent leaf is in a shared subtree.
+fn check_frontier<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    divergent_leaf: &LH::Leaf,
+) -> Result<()> {
+    // Compute the frontier of the Merkle tree for each prefix of the given leaves.
+    let mut frontiers = Vec::with_capacity(leaves.len() + 1);
+    for size in 0..=leaves.len() {
+        let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..size])?;
+        let frontier = merkle_tree.frontier();
+
+        // Compute the expected frontier, with one subtree root for each set bit of the size.
+        let mut expected = Vec::new();
+        let mut offset = 0;
+        for level in (0..usize::BITS as usize).rev() {
+            if (size >> level) & 1 == 1 {
+                expected.push(merkle_tree.level_hashes(level)?[offset >> level]);
+                offset += 1 << level;
+            }
+        }
+        assert_eq!(expected, frontier);
+        frontiers.push(frontier);
+    }
+
+    // Check that each frontier is a prefix of the frontiers of equal or longer Merkle trees.
+    for (shorter_size, shorter) in frontiers.iter().enumerate() {
+        for (longer_size, longer) in frontiers.iter().enumerate() {
+            let expected = shorter_size <= longer_size;
+            assert_eq!(expected, frontier_is_prefix(shorter, longer, shorter_size, longer_size));
+        }
+    }
+
+    for index in 0..leaves.len() {
+        // Construct the leaves with a divergent leaf at the given index.
+        let mut divergent_leaves = leaves.to_vec();
+        divergent_leaves[index] = divergent_leaf.clone();
+
+        for longer_size in index + 1..=leaves.len() {
+            let merkle_tree =
+                MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &divergent_leaves[..longer_size])?;
+            let longer = merkle_tree.frontier();
+
+            for (shorter_size, shorter) in frontiers.iter().enumerate().take(longer_size + 1) {
+                let is_prefix = frontier_is_prefix(shorter, &longer, shorter_size, longer_size);
+                // Compute the number of leaves in the shared subtrees.
+                let num_shared = match shorter_size == longer_size {
+                    true => shorter_size,
+                    false => {
+                        let highest_bit = (shorter_size ^ longer_size).ilog2();
+                        shorter_size.checked_shr(highest_bit + 1).unwrap_or(0) << (highest_bit + 1)
+                    }
+                };
+                // If the divergent leaf is past the shorter Merkle tree, the prefix relationship holds.
+                if index >= shorter_size {
+                    assert!(is_prefix);
+                }
+                // If the divergent leaf is in a shared subtree, the prefix relationship fails.
+                else if index < num_shared {
+                    assert!(!is_prefix);
+                }
+            }
+        }
+    }
+
+    // Check that the frontiers with an incorrect number of subtrees are rejected.
+    let frontier = frontiers.last().unwrap();
+    if !frontier.is_empty() {
+        assert!(!frontier_is_prefix(&frontier[1..], frontier, leaves.len(), leaves.len()));
+        assert!(!frontier_is_prefix(frontier, &frontier[1..], leaves.len(), leaves.len()));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_frontier() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), MAX_LEAVES);
+        // Check the Merkle tree.
+        check_frontier::<CurrentEnvironment, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &(0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>(),
+            &Field::<CurrentEnvironment>::rand(rng).to_bits_le(),
+        )
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_frontier() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), MAX_LEAVES);
+        // Check the Merkle tree.
+        check_frontier::<CurrentEnvironment, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+            &vec![Uniform::rand(rng)],
+        )
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
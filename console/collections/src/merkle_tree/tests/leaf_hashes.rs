@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree from the leaves, and from the leaf hashes.
+/// 2. Check that both Merkle trees are identical.
+/// 3. Append leaves (and leaf hashes) to both Merkle trees, and check that they remain identical.
+/// 4. Update a leaf (and leaf hash) in both Merkle trees, and check that they remain identical.
+fn check_merkle_tree_from_leaf_hashes<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    additional_leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let mut merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    // Construct the Merkle tree for the given leaf hashes.
+    let leaf_hashes = leaf_hasher.hash_leaves(leaves)?;
+    let mut candidate = MerkleTree::<E, LH, PH, DEPTH>::new_from_leaf_hashes(leaf_hasher, path_hasher, &leaf_hashes)?;
+
+    // Check that the Merkle trees are identical.
+    assert_eq!(merkle_tree.root(), candidate.root());
+    assert_eq!(merkle_tree.tree(), candidate.tree());
+    assert_eq!(merkle_tree.number_of_leaves(), candidate.number_of_leaves());
+    assert_eq!(leaf_hashes, candidate.leaf_hashes()?);
+    // Check that iterating over the leaf hashes reproduces the hashes of the given leaves, in order.
+    assert_eq!(leaf_hashes, merkle_tree.iter_leaf_hashes()?.copied().collect::<Vec<_>>());
+
+    // Append the additional leaves to both Merkle trees.
+    merkle_tree.append(additional_leaves)?;
+    candidate.append_leaf_hashes(&leaf_hasher.hash_leaves(additional_leaves)?)?;
+
+    // Check that the Merkle trees are identical.
+    assert_eq!(merkle_tree.root(), candidate.root());
+    assert_eq!(merkle_tree.tree(), candidate.tree());
+    assert_eq!(merkle_tree.number_of_leaves(), candidate.number_of_leaves());
+    // Check that iterating over the leaf hashes reproduces the hashes of all of the leaves, in order.
+    let all_leaf_hashes = leaf_hasher.hash_leaves(&[leaves, additional_leaves].concat())?;
+    assert_eq!(all_leaf_hashes, merkle_tree.iter_leaf_hashes()?.copied().collect::<Vec<_>>());
+
+    // Update the first leaf of both Merkle trees with the last additional leaf.
+    if let Some(new_leaf) = additional_leaves.last() {
+        merkle_tree.update(0, new_leaf)?;
+        candidate.update_leaf_hash(0, &leaf_hasher.hash_leaf(new_leaf)?)?;
+
+        // Check that the Merkle trees are identical.
+        assert_eq!(merkle_tree.root(), candidate.root());
+        assert_eq!(merkle_tree.tree(), candidate.tree());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_from_leaf_hashes() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the leaves and additional leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            let num_additional_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_leaves, i);
+
+            // Check the Merkle tree.
+            check_merkle_tree_from_leaf_hashes::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+                &(0..num_additional_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_from_leaf_hashes() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the leaves and additional leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            let num_additional_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_leaves, i);
+
+            // Check the Merkle tree.
+            check_merkle_tree_from_leaf_hashes::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+                &(0..num_additional_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree from the leaves.
+/// 2. Check that level 0 begins with the leaf hashes, and that the level sizes halve upward.
+/// 3. Check that each node is the hash of its children in the level below.
+/// 4. Check that level `DEPTH` holds only the root, and that the levels above it are rejected.
+fn check_level_hashes<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Check that level 0 holds the leaf hashes, padded with the empty hash to a power of two.
+    let mut level = merkle_tree.level_hashes(0)?;
+    assert_eq!(leaves.len().checked_next_power_of_two().unwrap(), level.len());
+    assert_eq!(merkle_tree.leaf_hashes()?, &level[..leaves.len()]);
+    assert!(level[leaves.len()..].iter().all(|hash| hash == merkle_tree.empty_hash()));
+
+    for index in 1..=DEPTH as usize {
+        let parents = merkle_tree.level_hashes(index)?;
+        // Compute the expected parents, where a single node is hashed with the empty hash.
+        let expected = match level.len() {
+            1 => vec![path_hasher.hash_children(&level[0], merkle_tree.empty_hash())?],
+            _ => {
+                level.chunks(2).map(|pair| path_hasher.hash_children(&pair[0], &pair[1])).collect::<Result<Vec<_>>>()?
+            }
+        };
+        // Check that the level size halves, down to a single node.
+        assert_eq!(core::cmp::max(1, level.len() / 2), parents.len());
+        assert_eq!(expected, parents);
+        level = parents;
+    }
+
+    // Check that the top level holds only the root.
+    assert_eq!(vec![*merkle_tree.root()], merkle_tree.level_hashes(DEPTH as usize)?);
+    // Check that the levels above the top level are rejected.
+    assert!(merkle_tree.level_hashes(DEPTH as usize + 1).is_err());
+    assert!(merkle_tree.level_hashes(usize::MAX).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_level_hashes() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            // Check the Merkle tree.
+            check_level_hashes::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_level_hashes() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            // Check the Merkle tree.
+            check_level_hashes::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
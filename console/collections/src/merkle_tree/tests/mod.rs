@@ -13,11 +13,27 @@
 // limitations under the License.
 
 use super::*;
+use snarkvm_console_types::prelude::Console;
 
 mod append;
+mod concat;
+mod consistency_proof;
+mod find;
+mod frontier;
+mod leaf_hashes;
+mod level_hashes;
+mod new_sparse;
+mod path_length;
+mod path_parameters_digest;
+mod path_verifier;
+mod range_proof;
+mod rebuild_with_parameters;
 mod remove;
+mod root_or_default;
+mod to_dot;
 mod update;
 mod update_many;
+mod validate_parameters;
 
 macro_rules! run_tests {
     ($rng:expr, [$($i:expr),*]) => {
@@ -25,3 +41,15 @@ macro_rules! run_tests {
     };
 }
 use run_tests;
+
+/// A malformed path hasher, which adds the child nodes, and is therefore independent of their order.
+#[derive(Clone)]
+struct SymmetricHash;
+
+impl PathHash for SymmetricHash {
+    type Hash = Field<Console>;
+
+    fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash> {
+        Ok(*left + *right)
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Runs the following test:
+/// 1. Construct a sparse Merkle tree from the default leaf and the set leaves.
+/// 2. Construct a dense Merkle tree from the full list of leaves.
+/// 3. Check that the Merkle trees are identical, and that the set leaves can be proven.
+fn check_merkle_tree_new_sparse<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    num_leaves: usize,
+    default_leaf: &LH::Leaf,
+    set_leaves: &BTreeMap<usize, LH::Leaf>,
+) -> Result<()> {
+    // Construct the sparse Merkle tree.
+    let candidate =
+        MerkleTree::<E, LH, PH, DEPTH>::new_sparse(leaf_hasher, path_hasher, num_leaves, default_leaf, set_leaves)?;
+
+    // Construct the dense Merkle tree on the full list of leaves.
+    let leaves =
+        (0..num_leaves).map(|index| set_leaves.get(&index).unwrap_or(default_leaf).clone()).collect::<Vec<_>>();
+    let expected = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves)?;
+
+    // Check that the Merkle trees are identical.
+    assert_eq!(expected.root(), candidate.root());
+    assert_eq!(expected.tree(), candidate.tree());
+    assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+
+    // Check that each set leaf can be proven.
+    for (leaf_index, leaf) in set_leaves {
+        let proof = candidate.prove(*leaf_index, leaf)?;
+        assert!(candidate.verify(&proof, candidate.root(), leaf));
+    }
+    Ok(())
+}
+
+/// Samples the set leaves for the given number of leaves, where roughly one in four leaves is set.
+fn sample_set_leaves<L>(
+    num_leaves: usize,
+    rng: &mut TestRng,
+    sample_leaf: impl Fn(&mut TestRng) -> L,
+) -> BTreeMap<usize, L> {
+    let mut set_leaves = BTreeMap::new();
+    for index in 0..num_leaves {
+        if rng.gen_range(0..4) == 0 {
+            set_leaves.insert(index, sample_leaf(rng));
+        }
+    }
+    set_leaves
+}
+
+#[test]
+fn test_merkle_tree_bhp_new_sparse() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i * 3) as usize;
+            // Sample the default leaf and the set leaves.
+            let default_leaf = Field::<CurrentEnvironment>::rand(rng).to_bits_le();
+            let set_leaves =
+                sample_set_leaves(num_leaves, rng, |rng| Field::<CurrentEnvironment>::rand(rng).to_bits_le());
+
+            // Check the Merkle tree.
+            check_merkle_tree_new_sparse::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                num_leaves,
+                &default_leaf,
+                &set_leaves,
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_new_sparse() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i * 3) as usize;
+            // Sample the default leaf and the set leaves.
+            let default_leaf = vec![Uniform::rand(rng)];
+            let set_leaves = sample_set_leaves(num_leaves, rng, |rng| vec![Uniform::rand(rng)]);
+
+            // Check the Merkle tree.
+            check_merkle_tree_new_sparse::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                num_leaves,
+                &default_leaf,
+                &set_leaves,
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_new_sparse_edge_cases() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+    type Tree = MerkleTree<CurrentEnvironment, LH, PH, 10>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let default_leaf = vec![Uniform::rand(&mut rng)];
+
+    // Check a tree with only default leaves, and a tree where every leaf is set.
+    for num_leaves in [0, 1, 2, 5, 8, 100] {
+        check_merkle_tree_new_sparse::<CurrentEnvironment, LH, PH, 10>(
+            &leaf_hasher,
+            &path_hasher,
+            num_leaves,
+            &default_leaf,
+            &BTreeMap::new(),
+        )?;
+        let set_leaves = (0..num_leaves).map(|index| (index, vec![Uniform::rand(&mut rng)])).collect();
+        check_merkle_tree_new_sparse::<CurrentEnvironment, LH, PH, 10>(
+            &leaf_hasher,
+            &path_hasher,
+            num_leaves,
+            &default_leaf,
+            &set_leaves,
+        )?;
+    }
+
+    // Ensure a set leaf index beyond the number of leaves fails.
+    let set_leaves = BTreeMap::from([(5, vec![Uniform::rand(&mut rng)])]);
+    assert!(Tree::new_sparse(&leaf_hasher, &path_hasher, 5, &default_leaf, &set_leaves).is_err());
+    assert!(Tree::new_sparse(&leaf_hasher, &path_hasher, 6, &default_leaf, &set_leaves).is_ok());
+
+    // Ensure a number of leaves beyond the capacity of the tree fails.
+    assert!(Tree::new_sparse(&leaf_hasher, &path_hasher, 1025, &default_leaf, &BTreeMap::new()).is_err());
+    Ok(())
+}
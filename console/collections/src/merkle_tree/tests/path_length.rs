@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const MAX_LEAVES: u128 = 16;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree, and check that the path length is the depth.
+/// 2. Check that each Merkle path has `path_length` siblings.
+/// 3. Check that each serialized Merkle path has `estimated_proof_bytes` bytes.
+fn check_path_length<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    assert_eq!(DEPTH, merkle_tree.depth());
+    assert_eq!(merkle_tree.depth() as usize, merkle_tree.path_length());
+
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        // Ensure the Merkle path has the expected number of siblings, and size in bytes.
+        let proof = merkle_tree.prove(leaf_index, leaf)?;
+        assert_eq!(merkle_tree.path_length(), proof.siblings().len());
+        assert_eq!(merkle_tree.estimated_proof_bytes(), proof.to_bytes_le()?.len());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_path_length() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), MAX_LEAVES);
+        // Check the Merkle tree.
+        check_path_length::<CurrentEnvironment, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &(0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>(),
+        )
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_path_length() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), MAX_LEAVES);
+        // Check the Merkle tree.
+        check_path_length::<CurrentEnvironment, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+        )
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type BHPTree<const DEPTH: u8> =
+    MerkleTree<CurrentEnvironment, BHP1024<CurrentEnvironment>, BHP512<CurrentEnvironment>, DEPTH>;
+type PoseidonTree<const DEPTH: u8> =
+    MerkleTree<CurrentEnvironment, Poseidon<CurrentEnvironment, 4>, Poseidon<CurrentEnvironment, 2>, DEPTH>;
+
+/// Returns a BHP Merkle tree with the given path hasher domain, and a random number of random leaves.
+fn sample_bhp_tree<const DEPTH: u8>(domain: &str, rng: &mut TestRng) -> Result<BHPTree<DEPTH>> {
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup(domain)?;
+    let num_leaves = rng.gen_range(0..16);
+    let leaves = (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+    BHPTree::<DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)
+}
+
+/// Returns a Poseidon Merkle tree with the given path hasher domain, and a random number of random leaves.
+fn sample_poseidon_tree<const DEPTH: u8>(domain: &str, rng: &mut TestRng) -> Result<PoseidonTree<DEPTH>> {
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup(domain)?;
+    let num_leaves = rng.gen_range(0..16);
+    let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    PoseidonTree::<DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)
+}
+
+#[test]
+fn test_merkle_tree_path_parameters_digest() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Ensure trees with the same parameters share a digest, regardless of their leaves.
+    let digest = sample_bhp_tree::<16>("AleoMerkleTreeTest1", &mut rng)?.path_parameters_digest()?;
+    for _ in 0..8 {
+        assert_eq!(digest, sample_bhp_tree::<16>("AleoMerkleTreeTest1", &mut rng)?.path_parameters_digest()?);
+    }
+    let poseidon_digest = sample_poseidon_tree::<16>("AleoMerkleTreeTest1", &mut rng)?.path_parameters_digest()?;
+    for _ in 0..8 {
+        assert_eq!(
+            poseidon_digest,
+            sample_poseidon_tree::<16>("AleoMerkleTreeTest1", &mut rng)?.path_parameters_digest()?
+        );
+    }
+
+    // Ensure trees that differ only in their leaf hasher share a digest, as it is not covered.
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest2")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+    assert_eq!(digest, BHPTree::<16>::new(&leaf_hasher, &path_hasher, &[])?.path_parameters_digest()?);
+
+    // Ensure trees with a different depth, path hasher domain, or path hasher have a different digest.
+    let digests = [
+        digest,
+        poseidon_digest,
+        sample_bhp_tree::<15>("AleoMerkleTreeTest1", &mut rng)?.path_parameters_digest()?,
+        sample_bhp_tree::<17>("AleoMerkleTreeTest1", &mut rng)?.path_parameters_digest()?,
+        sample_bhp_tree::<16>("AleoMerkleTreeTest2", &mut rng)?.path_parameters_digest()?,
+        sample_poseidon_tree::<32>("AleoMerkleTreeTest1", &mut rng)?.path_parameters_digest()?,
+        sample_poseidon_tree::<16>("AleoMerkleTreeTest2", &mut rng)?.path_parameters_digest()?,
+    ];
+    for (i, first) in digests.iter().enumerate() {
+        for second in digests.iter().skip(i + 1) {
+            assert_ne!(first, second);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Streams the given Merkle path through a path verifier, returning the result for the given root.
+fn stream_path<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaf_index: u64,
+    siblings: &[Field<E>],
+    root: &PH::Hash,
+    leaf: &LH::Leaf,
+) -> Result<bool> {
+    let mut verifier = PathVerifier::<PH, DEPTH>::new(leaf_hasher, path_hasher, leaf)?;
+    for (level, sibling) in siblings.iter().enumerate() {
+        // The sibling is the left child if the leaf index is odd on this level.
+        verifier.push_sibling(*sibling, leaf_index.checked_shr(u32::try_from(level)?).unwrap_or(0) & 1 == 1);
+        assert_eq!(level + 1, verifier.num_siblings());
+    }
+    Ok(verifier.finalize(root))
+}
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Check that streaming the Merkle path for each leaf through a path verifier succeeds, as with `verify`.
+/// 3. Check that the path verifier fails for an invalid root, leaf, sibling, or number of siblings.
+fn check_path_verifier<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    let root = merkle_tree.root();
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        // Compute the Merkle path for the leaf.
+        let path = merkle_tree.prove(index, leaf)?;
+        let (leaf_index, siblings) = (*path.leaf_index(), path.siblings());
+        let stream = |siblings: &[Field<E>], root: &PH::Hash, leaf: &LH::Leaf| {
+            stream_path::<E, LH, PH, DEPTH>(leaf_hasher, path_hasher, leaf_index, siblings, root, leaf)
+        };
+
+        // Ensure streaming the Merkle path matches the batch verification.
+        assert!(merkle_tree.verify(&path, root, leaf));
+        assert!(stream(siblings, root, leaf)?);
+
+        // Ensure streaming the Merkle path **fails** on an invalid root, as with the batch verification.
+        for invalid_root in [PH::Hash::zero(), PH::Hash::one()] {
+            assert!(!merkle_tree.verify(&path, &invalid_root, leaf));
+            assert!(!stream(siblings, &invalid_root, leaf)?);
+        }
+        // Ensure streaming the Merkle path **fails** on a different leaf, as with the batch verification.
+        let other_leaf = &leaves[(index + 1) % leaves.len()];
+        assert_eq!(merkle_tree.verify(&path, root, other_leaf), stream(siblings, root, other_leaf)?);
+        // Ensure streaming the Merkle path **fails** on an invalid sibling.
+        for level in 0..siblings.len() {
+            let mut invalid_siblings = siblings.to_vec();
+            invalid_siblings[level] += Field::one();
+            assert!(!stream(&invalid_siblings, root, leaf)?);
+        }
+        // Ensure streaming the Merkle path **fails** on too few or too many siblings.
+        assert!(!stream(&siblings[..siblings.len() - 1], root, leaf)?);
+        assert!(!stream(&[siblings, &[Field::zero()][..]].concat(), root, leaf)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_path_verifier() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 1..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+
+            // Check the path verifier.
+            check_path_verifier::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_path_verifier() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 1..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+
+            // Check the path verifier.
+            check_path_verifier::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
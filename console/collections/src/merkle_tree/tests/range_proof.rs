@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Check that the range Merkle proof for every contiguous range of leaves is valid.
+/// 3. Check that the range Merkle proof fails for an invalid root, start index, or leaves.
+fn check_merkle_tree_range_proof<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    let root = merkle_tree.root();
+
+    // Ensure an empty or out of bounds range fails.
+    assert!(merkle_tree.generate_range_proof(0, 0).is_err());
+    assert!(merkle_tree.generate_range_proof(0, leaves.len() + 1).is_err());
+
+    // Check each contiguous range of leaves in the Merkle tree.
+    for start in 0..leaves.len() {
+        for end in (start + 1)..=leaves.len() {
+            let range = &leaves[start..end];
+            // Compute a range Merkle proof for the leaves.
+            let proof = merkle_tree.generate_range_proof(start, end)?;
+            assert!(proof.siblings().len() <= 2 * DEPTH as usize);
+            // Verify the range Merkle proof succeeds.
+            assert!(merkle_tree.verify_range_proof(root, start, range, &proof));
+            assert!(proof.verify(leaf_hasher, path_hasher, root, range));
+            // Verify the range Merkle proof **fails** on an invalid root.
+            assert!(!merkle_tree.verify_range_proof(&PH::Hash::zero(), start, range, &proof));
+            assert!(!merkle_tree.verify_range_proof(&PH::Hash::one(), start, range, &proof));
+            // Verify the range Merkle proof **fails** on an invalid start index.
+            assert!(!merkle_tree.verify_range_proof(root, start + 1, range, &proof));
+            // Verify the range Merkle proof **fails** on a different range of leaves.
+            assert!(!merkle_tree.verify_range_proof(root, start, &range[1..], &proof));
+            if end < leaves.len() {
+                assert!(!merkle_tree.verify_range_proof(root, start, &leaves[start..end + 1], &proof));
+            }
+
+            // Ensure a single-leaf range Merkle proof is consistent with the Merkle path for the leaf.
+            if range.len() == 1 {
+                let path = merkle_tree.prove(start, &range[0])?;
+                assert!(path.verify(leaf_hasher, path_hasher, root, &range[0]));
+                assert_eq!(path.siblings(), proof.siblings());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_range_proof() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 1..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+
+            // Check the range Merkle proofs.
+            check_merkle_tree_range_proof::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_range_proof() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 1..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+
+            // Check the range Merkle proofs.
+            check_merkle_tree_range_proof::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 15, 16, 31, 32, 64]);
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Checks that rebuilding the Merkle tree matches `new` with the new path hasher, and changes the root.
+fn check_rebuild_with_parameters<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<CurrentEnvironment>>>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    new_path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 32>::new(leaf_hasher, path_hasher, leaves)?;
+    let rebuilt_tree = merkle_tree.rebuild_with_parameters(new_path_hasher)?;
+
+    // Ensure the rebuilt tree matches the tree constructed from scratch under the new path hasher.
+    let expected = MerkleTree::<CurrentEnvironment, LH, PH, 32>::new(leaf_hasher, new_path_hasher, leaves)?;
+    assert_eq!(expected.root(), rebuilt_tree.root());
+    assert_eq!(expected.tree(), rebuilt_tree.tree());
+    assert_eq!(expected.number_of_leaves(), rebuilt_tree.number_of_leaves());
+    assert_eq!(merkle_tree.leaf_hashes()?, rebuilt_tree.leaf_hashes()?);
+
+    // Ensure the root changes under the new path hasher.
+    assert_ne!(merkle_tree.root(), rebuilt_tree.root());
+
+    // Ensure the rebuilt tree proves the original leaves.
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        let proof = rebuilt_tree.prove(leaf_index, leaf)?;
+        assert!(rebuilt_tree.verify(&proof, rebuilt_tree.root(), leaf));
+        assert!(!merkle_tree.verify(&proof, merkle_tree.root(), leaf));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_rebuild_with_parameters_bhp() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+    let new_path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest2")?;
+
+    for _ in 0..ITERATIONS {
+        let num_leaves = rng.gen_range(0..16);
+        let leaves =
+            (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+        check_rebuild_with_parameters(&leaf_hasher, &path_hasher, &new_path_hasher, &leaves)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_rebuild_with_parameters_poseidon() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup("AleoMerkleTreeTest1")?;
+    let new_path_hasher = Poseidon::<CurrentEnvironment, 2>::setup("AleoMerkleTreeTest2")?;
+
+    for _ in 0..ITERATIONS {
+        let num_leaves = rng.gen_range(0..16);
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        check_rebuild_with_parameters(&leaf_hasher, &path_hasher, &new_path_hasher, &leaves)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_rebuild_with_parameters_malformed_hasher() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup("AleoMerkleTreeTest0")?;
+    let leaves = (0..4).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, _, _, 32>::new(&leaf_hasher, &SymmetricHash, &leaves)?;
+
+    // Ensure rebuilding with a malformed path hasher fails validation.
+    let error = match merkle_tree.rebuild_with_parameters(&SymmetricHash) {
+        Ok(_) => bail!("Rebuilding with a malformed path hasher should fail"),
+        Err(error) => error.to_string(),
+    };
+    assert!(error.contains("order of the child nodes"), "Unexpected error: {error}");
+    Ok(())
+}
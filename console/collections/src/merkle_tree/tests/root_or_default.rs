@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Checks that `root_or_default` is the empty-tree root for no leaves, and the root otherwise.
+fn check_root_or_default<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<CurrentEnvironment>>>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Ensure a fresh tree has the empty-tree root.
+    let empty_tree = MerkleTree::<CurrentEnvironment, LH, PH, 32>::new(leaf_hasher, path_hasher, &[])?;
+    let empty_root = empty_tree.empty_root()?;
+    assert_eq!(empty_root, empty_tree.root_or_default());
+    assert_eq!(empty_tree.root(), &empty_tree.root_or_default());
+
+    // Ensure a populated tree has its root, which differs from the empty-tree root.
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 32>::new(leaf_hasher, path_hasher, leaves)?;
+    assert_eq!(merkle_tree.root(), &merkle_tree.root_or_default());
+    assert_ne!(empty_root, merkle_tree.root_or_default());
+    assert_eq!(empty_root, merkle_tree.empty_root()?);
+
+    // Ensure removing every leaf restores the empty-tree root.
+    let removed_tree = merkle_tree.prepare_remove_last_n(leaves.len())?;
+    assert_eq!(empty_root, removed_tree.root_or_default());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_root_or_default_bhp() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    for _ in 0..ITERATIONS {
+        let num_leaves = rng.gen_range(1..16);
+        let leaves =
+            (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+        check_root_or_default(&leaf_hasher, &path_hasher, &leaves)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_root_or_default_poseidon() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup("AleoMerkleTreeTest1")?;
+
+    for _ in 0..ITERATIONS {
+        let num_leaves = rng.gen_range(1..16);
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        check_root_or_default(&leaf_hasher, &path_hasher, &leaves)?;
+    }
+    Ok(())
+}
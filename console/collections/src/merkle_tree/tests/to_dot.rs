@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::Poseidon;
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type LH = Poseidon<CurrentEnvironment, 4>;
+type PH = Poseidon<CurrentEnvironment, 2>;
+
+/// Returns the number of lines in the DOT representation that contain the given pattern.
+fn count_lines(dot: &str, pattern: &str) -> usize {
+    dot.lines().filter(|line| line.contains(pattern)).count()
+}
+
+#[test]
+fn test_merkle_tree_to_dot() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a Merkle tree with 5 leaves, padded to 8 leaves with 7 internal nodes.
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let dot = merkle_tree.to_dot();
+
+    // Ensure the DOT representation is a single graph.
+    assert!(dot.starts_with("digraph MerkleTree {\n"));
+    assert!(dot.ends_with("}\n"));
+
+    // Ensure there is a node for each hash, of which 8 are leaves, and 3 of those are empty.
+    assert_eq!(15, count_lines(&dot, "[label="));
+    assert_eq!(8, count_lines(&dot, "shape=box"));
+    assert_eq!(3, count_lines(&dot, "style=dashed"));
+    // Ensure each internal node has an edge to each of its children.
+    assert_eq!(14, count_lines(&dot, " -> "));
+    assert_eq!(2, count_lines(&dot, "n0 -> "));
+
+    // Ensure the nodes are labeled with a prefix of their hash, and the graph with a prefix of the root.
+    for (index, hash) in merkle_tree.tree().iter().enumerate() {
+        let hash = hash.to_string();
+        assert!(dot.contains(&format!("n{index} [label=\"{}...\"", &hash[..8])));
+    }
+    assert!(dot.contains(&format!("label=\"root: {}...\";", &merkle_tree.root().to_string()[..8])));
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_to_dot_single_node() -> Result<()> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    // Ensure an empty Merkle tree has a single empty leaf, and no edges.
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &[])?;
+    let dot = merkle_tree.to_dot();
+    assert_eq!(1, count_lines(&dot, "shape=box, style=dashed"));
+    assert_eq!(0, count_lines(&dot, " -> "));
+
+    // Ensure a Merkle tree with a single leaf has a single (non-empty) leaf, and no edges.
+    let merkle_tree =
+        MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &[vec![Field::one()]])?;
+    let dot = merkle_tree.to_dot();
+    assert_eq!(1, count_lines(&dot, "shape=box"));
+    assert_eq!(0, count_lines(&dot, "style=dashed"));
+    assert_eq!(0, count_lines(&dot, " -> "));
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+/// A malformed path hasher, which fails to hash.
+#[derive(Clone)]
+struct FailingHash;
+
+impl PathHash for FailingHash {
+    type Hash = Field<CurrentEnvironment>;
+
+    fn hash_children(&self, _left: &Self::Hash, _right: &Self::Hash) -> Result<Self::Hash> {
+        bail!("The path hasher is unavailable")
+    }
+}
+
+/// Returns the error message from validating the parameters with the given path hasher.
+fn validation_error<PH: PathHash<Hash = Field<CurrentEnvironment>>, const DEPTH: u8>(path_hasher: &PH) -> String {
+    MerkleTree::<CurrentEnvironment, Poseidon<CurrentEnvironment, 4>, PH, DEPTH>::validate_parameters(path_hasher)
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn test_merkle_tree_validate_parameters() -> Result<()> {
+    type BHPTree<const DEPTH: u8> =
+        MerkleTree<CurrentEnvironment, BHP1024<CurrentEnvironment>, BHP512<CurrentEnvironment>, DEPTH>;
+    type PoseidonTree<const DEPTH: u8> =
+        MerkleTree<CurrentEnvironment, Poseidon<CurrentEnvironment, 4>, Poseidon<CurrentEnvironment, 2>, DEPTH>;
+
+    let bhp = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+    let poseidon = Poseidon::<CurrentEnvironment, 2>::setup("AleoMerkleTreeTest1")?;
+
+    // Ensure well-formed parameters pass.
+    BHPTree::<1>::validate_parameters(&bhp)?;
+    BHPTree::<32>::validate_parameters(&bhp)?;
+    BHPTree::<64>::validate_parameters(&bhp)?;
+    PoseidonTree::<1>::validate_parameters(&poseidon)?;
+    PoseidonTree::<32>::validate_parameters(&poseidon)?;
+    PoseidonTree::<64>::validate_parameters(&poseidon)?;
+
+    // Ensure an out of bounds depth fails.
+    let error = BHPTree::<0>::validate_parameters(&bhp).unwrap_err().to_string();
+    assert!(error.contains("greater than 0"), "Unexpected error: {error}");
+    let error = PoseidonTree::<65>::validate_parameters(&poseidon).unwrap_err().to_string();
+    assert!(error.contains("less than or equal to 64"), "Unexpected error: {error}");
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_validate_parameters_malformed_hasher() {
+    // Ensure a path hasher that ignores the order of the child nodes fails.
+    let error = validation_error::<_, 32>(&SymmetricHash);
+    assert!(error.contains("order of the child nodes"), "Unexpected error: {error}");
+
+    // Ensure a path hasher that fails to hash fails.
+    let error = validation_error::<_, 32>(&FailingHash);
+    assert!(error.contains("empty hash") && error.contains("unavailable"), "Unexpected error: {error}");
+}
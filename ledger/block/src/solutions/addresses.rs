@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::types::Address;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the `(prover address, target)` pair of each prover solution, in storage order,
+    /// along with the number of prover solutions that were skipped.
+    ///
+    /// The prover address is stored in each prover solution, so a prover solution is only skipped
+    /// if its target cannot be computed. If there are no solutions, this method returns `(vec![], 0)`.
+    #[allow(clippy::type_complexity)]
+    pub fn to_address_target_pairs(&self) -> Result<(Vec<(Address<N>, u64)>, usize)> {
+        match &self.solutions {
+            Some(solutions) => {
+                Ok(collect_address_target_pairs(solutions.values().map(|s| (s.address(), s.to_target()))))
+            }
+            None => Ok((vec![], 0)),
+        }
+    }
+}
+
+/// Returns the `(prover address, target)` pairs with a computed target, and the number of pairs without one.
+fn collect_address_target_pairs<N: Network>(
+    entries: impl Iterator<Item = (Address<N>, Result<u64>)>,
+) -> (Vec<(Address<N>, u64)>, usize) {
+    let mut num_skipped = 0;
+    let pairs = entries
+        .filter_map(|(address, target)| match target {
+            Ok(target) => Some((address, target)),
+            Err(_) => {
+                num_skipped += 1;
+                None
+            }
+        })
+        .collect();
+    (pairs, num_skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_to_address_target_pairs() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+
+            // Ensure every prover solution is recovered.
+            let (pairs, num_skipped) = solutions.to_address_target_pairs()?;
+            assert_eq!(0, num_skipped);
+            assert_eq!(solutions.len(), pairs.len());
+            for ((address, target), solution) in pairs.iter().zip_eq(solutions.as_ref().unwrap().values()) {
+                assert_eq!(solution.address(), *address);
+                assert_eq!(solution.to_target()?, *target);
+            }
+        }
+
+        // Ensure empty solutions return no pairs, and none skipped.
+        let (pairs, num_skipped) = Solutions::<CurrentNetwork>::from(None).to_address_target_pairs()?;
+        assert!(pairs.is_empty());
+        assert_eq!(0, num_skipped);
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_address_target_pairs() {
+        let rng = &mut TestRng::default();
+
+        // Sample prover addresses.
+        let addresses = (0..6)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        // Construct entries, where every third target is unrecoverable.
+        let entries = addresses.iter().enumerate().map(|(index, address)| match index % 3 {
+            2 => (*address, Err(anyhow!("Failed to compute the target"))),
+            _ => (*address, Ok(index as u64)),
+        });
+
+        // Ensure the unrecoverable entries are skipped, and the others are preserved in order.
+        let (pairs, num_skipped) = collect_address_target_pairs(entries);
+        assert_eq!(2, num_skipped);
+        assert_eq!(vec![(addresses[0], 0), (addresses[1], 1), (addresses[3], 3), (addresses[4], 4)], pairs);
+
+        // Ensure no entries return no pairs, and none skipped.
+        let (pairs, num_skipped) = collect_address_target_pairs::<CurrentNetwork>(std::iter::empty());
+        assert!(pairs.is_empty());
+        assert_eq!(0, num_skipped);
+    }
+}
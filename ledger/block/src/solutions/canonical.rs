@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns `true` if the prover solutions are stored in strictly ascending order of puzzle commitment,
+    /// as produced by `validate_and_canonicalize`. The empty solutions are always canonical.
+    pub fn is_canonical(&self) -> bool {
+        match &self.solutions {
+            Some(solutions) => {
+                // Retrieve the bytes of each puzzle commitment, in storage order.
+                let commitments = match solutions.keys().map(|c| c.to_bytes_le()).collect::<Result<Vec<_>>>() {
+                    Ok(commitments) => commitments,
+                    Err(_) => return false,
+                };
+                // Ensure the puzzle commitments are in strictly ascending order.
+                is_strictly_ascending(&commitments)
+            }
+            None => true,
+        }
+    }
+}
+
+/// Returns `true` if the given byte strings are in strictly ascending order.
+fn is_strictly_ascending(commitments: &[Vec<u8>]) -> bool {
+    commitments.windows(2).all(|pair| pair[0] < pair[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_is_canonical() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions, and canonicalize them.
+            let mut solutions = sample_solutions(rng);
+            solutions.validate_and_canonicalize()?;
+            assert!(solutions.is_canonical());
+
+            // Reverse the order of the prover solutions.
+            let mut prover_solutions = solutions.to_sorted_solutions()?;
+            prover_solutions.reverse();
+            let shuffled = Solutions::from_prover_solutions(prover_solutions)?;
+            // Ensure the shuffled solutions are only canonical if there is a single prover solution.
+            assert_eq!(solutions.len() == 1, shuffled.is_canonical());
+        }
+
+        // Ensure empty solutions are canonical.
+        assert!(Solutions::<console::network::MainnetV0>::from(None).is_canonical());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_strictly_ascending() {
+        assert!(is_strictly_ascending(&[]));
+        assert!(is_strictly_ascending(&[vec![1]]));
+        assert!(is_strictly_ascending(&[vec![1], vec![1, 0], vec![2]]));
+        // Ensure out-of-order and duplicate entries are not ascending.
+        assert!(!is_strictly_ascending(&[vec![2], vec![1]]));
+        assert!(!is_strictly_ascending(&[vec![1], vec![1]]));
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns a digest of the solutions, which is independent of the order of the prover solutions.
+    ///
+    /// The preimage is the number of prover solutions, followed by the bytes of each prover solution,
+    /// sorted by the byte representation of their puzzle commitment, and is hashed with BHP1024.
+    /// The empty solutions have a fixed digest, which is the hash of a zero count.
+    pub fn canonical_digest(&self) -> Result<Field<N>> {
+        // Retrieve the prover solutions, in canonical order.
+        let solutions = self.to_sorted_solutions()?;
+
+        let mut preimage = Vec::new();
+        // Write the number of prover solutions.
+        u16::try_from(solutions.len())?.write_le(&mut preimage)?;
+        // Write the prover solutions.
+        for solution in &solutions {
+            solution.write_le(&mut preimage)?;
+        }
+        // Hash the preimage.
+        N::hash_bhp1024(&preimage.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+    use ledger_coinbase::{PartialSolution, PuzzleProof};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_canonical_digest() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let digest = solutions.canonical_digest()?;
+
+            // Ensure the digest is independent of the order of the prover solutions.
+            let mut prover_solutions = solutions.to_sorted_solutions()?;
+            let sorted = Solutions::from_prover_solutions(prover_solutions.clone())?;
+            prover_solutions.reverse();
+            let reversed = Solutions::from_prover_solutions(prover_solutions.clone())?;
+            assert_eq!(digest, sorted.canonical_digest()?);
+            assert_eq!(digest, reversed.canonical_digest()?);
+
+            // Ensure changing the proof of a prover solution changes the digest.
+            let original = prover_solutions[0];
+            let partial_solution = PartialSolution::new(original.address(), original.nonce(), original.commitment());
+            let proof = PuzzleProof::<CurrentNetwork> { w: rng.gen(), random_v: None };
+            prover_solutions[0] = ProverSolution::new(partial_solution, proof);
+            let changed = Solutions::from_prover_solutions(prover_solutions)?;
+            assert_ne!(digest, changed.canonical_digest()?);
+
+            // Ensure adding a prover solution changes the digest.
+            let extended = solutions.merge(&sample_solutions(rng))?;
+            assert_ne!(digest, extended.canonical_digest()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_digest_empty() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure the empty solutions have a fixed digest.
+        let empty = Solutions::<CurrentNetwork>::from(None);
+        let digest = empty.canonical_digest()?;
+        assert_eq!(digest, Solutions::<CurrentNetwork>::from(None).canonical_digest()?);
+        assert_eq!(digest, CurrentNetwork::hash_bhp1024(&0u16.to_bytes_le()?.to_bits_le())?);
+
+        // Ensure the digest of non-empty solutions differs.
+        assert_ne!(digest, sample_solutions(rng).canonical_digest()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns an iterator over the puzzle commitments in chunks of `size`, sorted by their byte representation.
+    ///
+    /// Each chunk has `size` puzzle commitments, except for the last chunk, which may be shorter.
+    /// As the order is independent of the order in which the prover solutions are stored,
+    /// the `i`-th chunk may be served as the `i`-th page of the puzzle commitments.
+    /// If there are no solutions, the iterator is empty.
+    ///
+    /// This method returns an error if `size` is zero.
+    pub fn chunks(&self, size: usize) -> Result<impl Iterator<Item = Vec<&PuzzleCommitment<N>>> + '_> {
+        // Ensure the chunk size is greater than zero.
+        ensure!(size > 0, "The chunk size must be greater than zero");
+
+        // Pair each puzzle commitment with its bytes.
+        let mut commitments = self
+            .solution_ids()
+            .map(|commitment| Ok((commitment.to_bytes_le()?, commitment)))
+            .collect::<Result<Vec<_>>>()?;
+        // Sort the puzzle commitments by their bytes.
+        commitments.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        let commitments = commitments.into_iter().map(|(_, commitment)| commitment).collect::<Vec<_>>();
+
+        // Return the chunks of the sorted puzzle commitments.
+        Ok(commitments.chunks(size).map(|chunk| chunk.to_vec()).collect::<Vec<_>>().into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_chunks() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let num_solutions = solutions.len();
+            // Compute the expected order of the puzzle commitments.
+            let expected = solutions.to_sorted_solutions()?.iter().map(|s| s.commitment()).collect::<Vec<_>>();
+
+            for size in 1..=num_solutions + 1 {
+                let chunks = solutions.chunks(size)?.collect::<Vec<_>>();
+                // Ensure the number of chunks is correct.
+                assert_eq!((num_solutions + size - 1) / size, chunks.len());
+                // Ensure every chunk, except for the last, has `size` puzzle commitments.
+                for (index, chunk) in chunks.iter().enumerate() {
+                    match index + 1 == chunks.len() {
+                        true => assert_eq!(num_solutions - index * size, chunk.len()),
+                        false => assert_eq!(size, chunk.len()),
+                    }
+                    assert!(!chunk.is_empty() && chunk.len() <= size);
+                }
+                // Ensure concatenating the chunks reproduces the sorted puzzle commitments.
+                let candidate = chunks.into_iter().flatten().copied().collect::<Vec<_>>();
+                assert_eq!(expected, candidate);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunks_empty() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure a chunk size of zero fails.
+        assert!(sample_solutions(rng).chunks(0).is_err());
+        let empty = Solutions::<console::network::MainnetV0>::from(None);
+        assert!(empty.chunks(0).is_err());
+
+        // Ensure empty solutions have no chunks.
+        assert_eq!(0, empty.chunks(1)?.count());
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the coefficient of variation of the targets of the prover solutions.
+    ///
+    /// The coefficient of variation is the population standard deviation of the targets divided by their mean,
+    /// i.e. `sqrt(sum_i (x_i - mean)^2 / n) / mean`. It is `0.0` when every prover solution has the same target,
+    /// and at most `sqrt(n - 1)`, when a single prover solution has a non-zero target.
+    /// If there are fewer than two solutions, this method returns `0.0`.
+    /// Otherwise, if every target is zero, the mean is zero and this method returns an error.
+    ///
+    /// Note: This method is intended for analytics, and uses floating-point arithmetic.
+    pub fn target_cv(&self) -> Result<f64> {
+        match &self.solutions {
+            Some(solutions) => {
+                // Compute the target of each prover solution.
+                let targets = solutions.values().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+                // Compute the coefficient of variation of the targets.
+                coefficient_of_variation(&targets)
+            }
+            None => Ok(0.0),
+        }
+    }
+}
+
+/// Returns the coefficient of variation of the given targets.
+fn coefficient_of_variation(targets: &[u64]) -> Result<f64> {
+    // If there are fewer than two targets, there is no variation.
+    if targets.len() < 2 {
+        return Ok(0.0);
+    }
+    // Compute the combined target.
+    let combined_target = targets.iter().try_fold(0u128, |combined, target| {
+        combined.checked_add(*target as u128).ok_or_else(|| anyhow!("Combined target overflowed"))
+    })?;
+    // Ensure the combined target is non-zero, as the mean is the divisor.
+    ensure!(combined_target > 0, "Cannot compute the coefficient of variation of targets with a mean of zero");
+
+    // Compute the mean of the targets.
+    let n = targets.len() as f64;
+    let mean = combined_target as f64 / n;
+    // Compute the population variance of the targets.
+    let variance = targets.iter().map(|target| (*target as f64 - mean).powi(2)).sum::<f64>() / n;
+    // Return the population standard deviation divided by the mean.
+    Ok(variance.sqrt() / mean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    /// The tolerance for floating-point comparisons.
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_coefficient_of_variation() -> Result<()> {
+        // Check the empty and single-target cases.
+        assert_eq!(0.0, coefficient_of_variation(&[])?);
+        assert_eq!(0.0, coefficient_of_variation(&[0])?);
+        assert_eq!(0.0, coefficient_of_variation(&[42])?);
+
+        // Check that a perfectly equal distribution has a coefficient of variation of zero.
+        for n in [2, 4, 10, 100] {
+            assert!(coefficient_of_variation(&vec![1_000; n])?.abs() < EPSILON);
+        }
+        assert!(coefficient_of_variation(&[u64::MAX; 3])?.abs() < EPSILON);
+
+        // Check that a maximally concentrated distribution has a coefficient of variation of `sqrt(n - 1)`.
+        for n in [2usize, 4, 10, 100, 1000] {
+            let mut targets = vec![0; n];
+            targets[n / 2] = 1_000_000;
+            let expected = ((n - 1) as f64).sqrt();
+            assert!((coefficient_of_variation(&targets)? - expected).abs() < EPSILON);
+        }
+
+        // Check a known distribution, with a mean of 5 and a population standard deviation of 2.
+        assert!((coefficient_of_variation(&[2, 4, 4, 4, 5, 5, 7, 9])? - 0.4).abs() < EPSILON);
+        // Check that scaling the targets does not change the coefficient of variation.
+        assert!((coefficient_of_variation(&[200, 400, 400, 400, 500, 500, 700, 900])? - 0.4).abs() < EPSILON);
+
+        // Check that a mean of zero fails.
+        assert!(coefficient_of_variation(&[0, 0]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_target_cv() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+
+            // Ensure the coefficient of variation matches the standard deviation over the mean, and is within bounds.
+            let targets = solutions.sorted_targets()?;
+            let candidate = solutions.target_cv()?;
+            assert!(candidate >= 0.0);
+            assert!(candidate <= ((solutions.len() - 1) as f64).sqrt() + EPSILON);
+            if targets.len() > 1 {
+                let n = targets.len() as f64;
+                let mean = targets.iter().map(|target| *target as f64).sum::<f64>() / n;
+                let variance = targets.iter().map(|target| (*target as f64).powi(2)).sum::<f64>() / n - mean * mean;
+                assert!((variance.max(0.0).sqrt() / mean - candidate).abs() < 1e-6);
+            } else {
+                assert_eq!(0.0, candidate);
+            }
+        }
+
+        // Ensure empty solutions have a coefficient of variation of zero.
+        assert_eq!(0.0, Solutions::<console::network::MainnetV0>::from(None).target_cv()?);
+        Ok(())
+    }
+}
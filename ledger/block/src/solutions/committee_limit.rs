@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the maximum number of solutions for the given committee.
+    ///
+    /// Each member proposes at most one batch per round, of at most `MAX_TRANSMISSIONS_PER_BATCH` transmissions,
+    /// so a committee proposes at most `MAX_TRANSMISSIONS_PER_BATCH * num_members` solutions in a round.
+    /// This bound is capped at `N::MAX_SOLUTIONS`, and is below it for committees with few members.
+    pub fn max_solutions_for_committee(committee: &Committee<N>) -> usize {
+        let maximum = BatchHeader::<N>::MAX_TRANSMISSIONS_PER_BATCH * committee.num_members();
+        maximum.min(N::MAX_SOLUTIONS)
+    }
+
+    /// Ensures the number of solutions does not exceed the maximum for the given committee
+    /// (see `max_solutions_for_committee`), which catches over-large sets for smaller committees.
+    pub fn validate_for_committee(&self, committee: &Committee<N>) -> Result<()> {
+        let maximum = Self::max_solutions_for_committee(committee);
+        ensure!(
+            self.len() <= maximum,
+            "The solutions exceed the limit for a committee of {} members ({} > {maximum})",
+            committee.num_members(),
+            self.len()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::{serialize::tests::sample_solutions, validate::tests::sample_prover_solutions};
+    use ledger_committee::test_helpers::sample_committee_for_round_and_size;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_validate_for_committee() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a small committee, and a committee of the maximum size.
+        let small = sample_committee_for_round_and_size(1, 4, rng);
+        let large = sample_committee_for_round_and_size(1, Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE, rng);
+
+        // Ensure the limit scales with the members of the small committee, and is capped for the large committee.
+        let small_limit = Solutions::max_solutions_for_committee(&small);
+        let large_limit = Solutions::max_solutions_for_committee(&large);
+        assert_eq!(4 * BatchHeader::<CurrentNetwork>::MAX_TRANSMISSIONS_PER_BATCH, small_limit);
+        assert_eq!(CurrentNetwork::MAX_SOLUTIONS, large_limit);
+        assert!(small_limit < large_limit);
+
+        // Ensure the solutions at the limit of the small committee are valid for both committees.
+        let solutions = Solutions::from_prover_solutions(sample_prover_solutions(small_limit, rng))?;
+        solutions.validate_for_committee(&small)?;
+        solutions.validate_for_committee(&large)?;
+
+        // Ensure one more solution is rejected by the small committee, and accepted by the large committee.
+        let solutions = Solutions::from_prover_solutions(sample_prover_solutions(small_limit + 1, rng))?;
+        let error = solutions.validate_for_committee(&small).unwrap_err().to_string();
+        assert!(error.contains(&format!("({} > {small_limit})", small_limit + 1)), "Unexpected error: {error}");
+        solutions.validate_for_committee(&large)?;
+
+        // Ensure sampled solutions, and empty solutions, are valid for both committees.
+        for committee in [&small, &large] {
+            sample_solutions(rng).validate_for_committee(committee)?;
+            Solutions::from(None).validate_for_committee(committee)?;
+        }
+        Ok(())
+    }
+}
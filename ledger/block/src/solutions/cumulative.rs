@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns an iterator over `(puzzle commitment, target, cumulative target)` for each prover solution,
+    /// in descending order of target, where the cumulative target includes the current prover solution.
+    ///
+    /// Ties between equal targets are broken by puzzle commitment order.
+    /// The final cumulative target is equal to `to_combined_proof_target`.
+    pub fn iter_with_cumulative_target(&self) -> Result<impl Iterator<Item = (&PuzzleCommitment<N>, u64, u128)> + '_> {
+        // Retrieve the puzzle commitment bytes and target of each prover solution.
+        let mut entries = match &self.solutions {
+            Some(solutions) => solutions
+                .iter()
+                .map(|(commitment, solution)| Ok((commitment, commitment.to_bytes_le()?, solution.to_target()?)))
+                .collect::<Result<Vec<_>>>()?,
+            None => vec![],
+        };
+        // Sort the prover solutions by target in descending order, then by puzzle commitment bytes.
+        entries.sort_unstable_by(|(_, a_bytes, a), (_, b_bytes, b)| b.cmp(a).then_with(|| a_bytes.cmp(b_bytes)));
+
+        // Compute the cumulative target of each prover solution.
+        let mut cumulative_target = 0u128;
+        let entries = entries
+            .into_iter()
+            .map(|(commitment, _, target)| {
+                cumulative_target = cumulative_target
+                    .checked_add(target as u128)
+                    .ok_or_else(|| anyhow!("Cumulative target overflowed"))?;
+                Ok((commitment, target, cumulative_target))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(entries.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_iter_with_cumulative_target() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+
+            let entries = solutions.iter_with_cumulative_target()?.collect::<Vec<_>>();
+            assert_eq!(solutions.len(), entries.len());
+
+            let mut previous: Option<(u64, u128)> = None;
+            for (commitment, target, cumulative_target) in &entries {
+                // Ensure the target matches the prover solution.
+                let solution = solutions.as_ref().unwrap().get_solution(commitment).unwrap();
+                assert_eq!(solution.to_target()?, *target);
+
+                match previous {
+                    // Ensure the first cumulative target is the first target.
+                    None => assert_eq!(*target as u128, *cumulative_target),
+                    Some((previous_target, previous_cumulative_target)) => {
+                        // Ensure the targets are in descending order.
+                        assert!(*target <= previous_target);
+                        // Ensure the cumulative target is monotonic, and includes the current target.
+                        assert!(*cumulative_target >= previous_cumulative_target);
+                        assert_eq!(previous_cumulative_target + *target as u128, *cumulative_target);
+                    }
+                }
+                previous = Some((*target, *cumulative_target));
+            }
+
+            // Ensure the final cumulative target is the combined proof target.
+            assert_eq!(solutions.to_combined_proof_target()?, entries.last().unwrap().2);
+        }
+
+        // Ensure empty solutions return an empty iterator.
+        let solutions = Solutions::<console::network::MainnetV0>::from(None);
+        assert_eq!(0, solutions.iter_with_cumulative_target()?.count());
+        Ok(())
+    }
+}
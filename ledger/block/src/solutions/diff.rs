@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The differences between two sets of solutions.
+///
+/// Each list contains puzzle commitments, sorted by their byte representation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolutionsDiff<N: Network> {
+    /// The puzzle commitments that are only in the other solutions.
+    added: Vec<PuzzleCommitment<N>>,
+    /// The puzzle commitments that are only in these solutions.
+    removed: Vec<PuzzleCommitment<N>>,
+    /// The puzzle commitments that are in both solutions, with differing prover solutions.
+    changed: Vec<PuzzleCommitment<N>>,
+}
+
+impl<N: Network> SolutionsDiff<N> {
+    /// Returns the puzzle commitments that are only in the other solutions.
+    pub fn added(&self) -> &[PuzzleCommitment<N>] {
+        &self.added
+    }
+
+    /// Returns the puzzle commitments that are only in these solutions.
+    pub fn removed(&self) -> &[PuzzleCommitment<N>] {
+        &self.removed
+    }
+
+    /// Returns the puzzle commitments that are in both solutions, with differing prover solutions.
+    pub fn changed(&self) -> &[PuzzleCommitment<N>] {
+        &self.changed
+    }
+
+    /// Returns `true` if there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl<N: Network> Solutions<N> {
+    /// Returns the differences from these solutions to the given solutions.
+    ///
+    /// A puzzle commitment is `added` if it is only in `other`, `removed` if it is only in `self`,
+    /// and `changed` if it is in both, but the bytes of the prover solutions differ.
+    /// Each list is sorted by the byte representation of the puzzle commitments.
+    pub fn diff_detailed(&self, other: &Solutions<N>) -> Result<SolutionsDiff<N>> {
+        // Retrieve the prover solution for the given puzzle commitment, if it exists.
+        let get = |solutions: &Solutions<N>, commitment: &PuzzleCommitment<N>| {
+            solutions.as_ref().and_then(|solutions| solutions.get(commitment).copied())
+        };
+
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        let mut changed = Vec::new();
+
+        // Determine the removed and changed puzzle commitments, in sorted order.
+        for solution in self.to_sorted_solutions()? {
+            let commitment = solution.commitment();
+            match get(other, &commitment) {
+                Some(other_solution) => {
+                    if solution.to_bytes_le()? != other_solution.to_bytes_le()? {
+                        changed.push(commitment);
+                    }
+                }
+                None => removed.push(commitment),
+            }
+        }
+        // Determine the added puzzle commitments, in sorted order.
+        for solution in other.to_sorted_solutions()? {
+            if get(self, &solution.commitment()).is_none() {
+                added.push(solution.commitment());
+            }
+        }
+
+        Ok(SolutionsDiff { added, removed, changed })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+    use ledger_coinbase::{PartialSolution, PuzzleProof};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_diff_detailed() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample at least two solutions.
+            let solutions = sample_solutions(rng).merge(&sample_solutions(rng))?;
+            let mut prover_solutions = solutions.to_sorted_solutions()?;
+
+            // Remove a prover solution.
+            let removed = prover_solutions.remove(0);
+            // Change the proof of a prover solution, keeping its puzzle commitment.
+            let original = prover_solutions[0];
+            let partial_solution = PartialSolution::new(original.address(), original.nonce(), original.commitment());
+            let proof = PuzzleProof::<CurrentNetwork> { w: rng.gen(), random_v: None };
+            prover_solutions[0] = ProverSolution::new(partial_solution, proof);
+            let changed = prover_solutions[0];
+            assert_eq!(original.commitment(), changed.commitment());
+            // Add a prover solution.
+            let added = sample_solutions(rng).to_sorted_solutions()?.pop().unwrap();
+            prover_solutions.push(added);
+            let other = Solutions::from_prover_solutions(prover_solutions)?;
+
+            // Ensure each category is detected.
+            let diff = solutions.diff_detailed(&other)?;
+            assert_eq!(diff.added(), &[added.commitment()]);
+            assert_eq!(diff.removed(), &[removed.commitment()]);
+            assert_eq!(diff.changed(), &[changed.commitment()]);
+            assert!(!diff.is_empty());
+
+            // Ensure the reverse diff swaps the added and removed puzzle commitments.
+            let reverse = other.diff_detailed(&solutions)?;
+            assert_eq!(diff.added(), reverse.removed());
+            assert_eq!(diff.removed(), reverse.added());
+            assert_eq!(diff.changed(), reverse.changed());
+
+            // Ensure there are no differences from the solutions to themselves.
+            assert!(solutions.diff_detailed(&solutions)?.is_empty());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_detailed_is_sorted() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let expected = solutions.to_sorted_solutions()?.iter().map(|s| s.commitment()).collect::<Vec<_>>();
+
+            // Ensure diffing against empty solutions lists every puzzle commitment in sorted order.
+            let empty = Solutions::from(None);
+            let diff = empty.diff_detailed(&solutions)?;
+            assert_eq!(expected, diff.added());
+            assert!(diff.removed().is_empty() && diff.changed().is_empty());
+
+            let diff = solutions.diff_detailed(&empty)?;
+            assert_eq!(expected, diff.removed());
+            assert!(diff.added().is_empty() && diff.changed().is_empty());
+        }
+        Ok(())
+    }
+}
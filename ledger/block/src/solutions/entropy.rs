@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the Shannon entropy, in bits (i.e. base 2), of the share of the combined proof target
+    /// contributed by each prover solution.
+    ///
+    /// The entropy ranges from `0.0`, when a single prover solution dominates the combined proof target,
+    /// to `log2(len())`, when every prover solution has the same target. If there are fewer than two solutions,
+    /// this method returns `0.0`.
+    ///
+    /// Note: This method is intended for analytics, and uses floating-point arithmetic.
+    pub fn target_entropy_bits(&self) -> Result<f64> {
+        match &self.solutions {
+            Some(solutions) => {
+                // Compute the target of each prover solution.
+                let targets = solutions.values().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+                // Compute the entropy of the targets.
+                entropy_bits(&targets)
+            }
+            None => Ok(0.0),
+        }
+    }
+}
+
+/// Returns the Shannon entropy, in bits, of the distribution given by each target's share of the combined target.
+fn entropy_bits(targets: &[u64]) -> Result<f64> {
+    // If there are fewer than two targets, the entropy is zero.
+    if targets.len() < 2 {
+        return Ok(0.0);
+    }
+    // Compute the combined target.
+    let combined_target = targets.iter().try_fold(0u128, |combined, target| {
+        combined.checked_add(*target as u128).ok_or_else(|| anyhow!("Combined target overflowed"))
+    })?;
+    // Ensure the combined target is non-zero.
+    ensure!(combined_target > 0, "Cannot compute the entropy of targets with a combined target of zero");
+
+    // Compute the entropy as `-sum(p * log2(p))`, where a share of zero contributes nothing.
+    let entropy = targets
+        .iter()
+        .filter(|target| **target > 0)
+        .map(|target| {
+            let share = *target as f64 / combined_target as f64;
+            -share * share.log2()
+        })
+        .sum::<f64>();
+    // Clamp the entropy to be non-negative, as rounding errors may yield a negligibly negative value.
+    Ok(entropy.max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    /// The tolerance for floating-point comparisons.
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_entropy_bits() -> Result<()> {
+        // Check the empty and single-target cases.
+        assert_eq!(0.0, entropy_bits(&[])?);
+        assert_eq!(0.0, entropy_bits(&[42])?);
+
+        // Check that a uniform distribution has the maximum entropy.
+        for n in [2u64, 4, 8, 10, 16] {
+            let targets = vec![1_000; n as usize];
+            assert!((entropy_bits(&targets)? - (n as f64).log2()).abs() < EPSILON);
+        }
+        assert!((entropy_bits(&[u64::MAX, u64::MAX])? - 1.0).abs() < EPSILON);
+
+        // Check a known non-uniform distribution, with shares of (1/2, 1/4, 1/4).
+        assert!((entropy_bits(&[2, 1, 1])? - 1.5).abs() < EPSILON);
+
+        // Check that a dominated distribution has a low entropy.
+        let dominated = entropy_bits(&[1_000_000, 1, 1, 1])?;
+        assert!(dominated > 0.0);
+        assert!(dominated < 0.001);
+        // Check that a target of zero contributes nothing.
+        assert_eq!(0.0, entropy_bits(&[1_000, 0, 0])?);
+
+        // Check that a combined target of zero fails.
+        assert!(entropy_bits(&[0, 0]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_target_entropy_bits() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+
+            // Ensure the entropy is within bounds.
+            let entropy = solutions.target_entropy_bits()?;
+            assert!(entropy >= 0.0);
+            assert!(entropy <= (solutions.len() as f64).log2() + EPSILON);
+        }
+
+        // Ensure empty solutions have zero entropy.
+        assert_eq!(0.0, Solutions::<console::network::MainnetV0>::from(None).target_entropy_bits()?);
+        Ok(())
+    }
+}
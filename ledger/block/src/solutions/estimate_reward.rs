@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the estimated block reward attributable to the solutions, by scaling `base_reward`
+    /// by the ratio of the combined proof target of the solutions to the given network target.
+    ///
+    /// The estimate is `floor(base_reward * combined_target / network_target)`, i.e. it rounds down,
+    /// and saturates at `u64::MAX` if the combined proof target exceeds the network target sufficiently.
+    /// If there are no solutions, this method returns `0`.
+    /// This method returns an error if the network target is zero, or if the scaled reward overflows a `u128`.
+    pub fn estimate_reward(&self, base_reward: u64, network_target: u128) -> Result<u64> {
+        // If there are no solutions, return a reward of zero.
+        if self.is_empty() {
+            return Ok(0);
+        }
+        // Ensure the network target is non-zero.
+        ensure!(network_target > 0, "Cannot estimate the reward for a network target of zero");
+
+        // Compute the combined proof target.
+        let combined_target = self.to_combined_proof_target()?;
+        // Scale the base reward by the combined proof target.
+        let scaled_reward = (base_reward as u128)
+            .checked_mul(combined_target)
+            .ok_or_else(|| anyhow!("The scaled reward overflowed ({base_reward} * {combined_target})"))?;
+        // Compute the estimated reward, rounding down, and saturating at `u64::MAX`.
+        Ok(u64::try_from(scaled_reward / network_target).unwrap_or(u64::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_estimate_reward() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let combined_target = solutions.to_combined_proof_target()?;
+
+            // Ensure a network target equal to the combined proof target yields the base reward.
+            assert_eq!(1_000_000, solutions.estimate_reward(1_000_000, combined_target)?);
+            // Ensure the estimate rounds down, for known ratios of the combined proof target.
+            assert_eq!(500_000, solutions.estimate_reward(1_000_000, combined_target * 2)?);
+            assert_eq!(333_333, solutions.estimate_reward(1_000_000, combined_target * 3)?);
+            assert_eq!(3, solutions.estimate_reward(10, combined_target * 3)?);
+            assert_eq!(0, solutions.estimate_reward(2, combined_target * 3)?);
+            // Ensure a zero base reward yields a zero reward.
+            assert_eq!(0, solutions.estimate_reward(0, combined_target)?);
+
+            // Ensure the estimate saturates at `u64::MAX`, or fails if the scaled reward overflows.
+            match (u64::MAX as u128).checked_mul(combined_target) {
+                Some(_) => assert_eq!(u64::MAX, solutions.estimate_reward(u64::MAX, 1)?),
+                None => assert!(solutions.estimate_reward(u64::MAX, 1).is_err()),
+            }
+
+            // Ensure a zero network target fails.
+            assert!(solutions.estimate_reward(1_000_000, 0).is_err());
+        }
+
+        // Ensure empty solutions have a reward of zero, even for a zero network target.
+        let empty = Solutions::<CurrentNetwork>::from(None);
+        assert_eq!(0, empty.estimate_reward(1_000_000, 1)?);
+        assert_eq!(0, empty.estimate_reward(u64::MAX, 0)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the Gini coefficient of the targets of the prover solutions.
+    ///
+    /// For the `n` targets sorted in ascending order as `x_1 <= ... <= x_n`, the Gini coefficient is
+    /// `sum_i (2i - n - 1) * x_i / (n * sum_i x_i)`, which equals the mean absolute difference
+    /// `sum_i sum_j |x_i - x_j| / (2 * n^2 * mean)`. It ranges from `0.0`, when every prover solution
+    /// has the same target, to `(n - 1) / n`, when a single prover solution has a non-zero target.
+    /// If there are fewer than two solutions, this method returns `0.0`.
+    ///
+    /// Note: This method is intended for analytics, and uses floating-point arithmetic.
+    pub fn target_gini(&self) -> Result<f64> {
+        match &self.solutions {
+            Some(solutions) => {
+                // Compute the target of each prover solution.
+                let targets = solutions.values().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+                // Compute the Gini coefficient of the targets.
+                gini(targets)
+            }
+            None => Ok(0.0),
+        }
+    }
+}
+
+/// Returns the Gini coefficient of the given targets.
+fn gini(mut targets: Vec<u64>) -> Result<f64> {
+    // If there are fewer than two targets, the distribution is perfectly equal.
+    if targets.len() < 2 {
+        return Ok(0.0);
+    }
+    // Compute the combined target.
+    let combined_target = targets.iter().try_fold(0u128, |combined, target| {
+        combined.checked_add(*target as u128).ok_or_else(|| anyhow!("Combined target overflowed"))
+    })?;
+    // Ensure the combined target is non-zero.
+    ensure!(combined_target > 0, "Cannot compute the Gini coefficient of targets with a combined target of zero");
+
+    // Sort the targets in ascending order.
+    targets.sort_unstable();
+    // Compute `sum_i (2i - n - 1) * x_i`, for the 1-indexed rank `i` of each target.
+    let n = targets.len() as f64;
+    let weighted_sum = targets
+        .iter()
+        .enumerate()
+        .map(|(index, target)| (2.0 * (index + 1) as f64 - n - 1.0) * *target as f64)
+        .sum::<f64>();
+    // Clamp the Gini coefficient to be non-negative, as rounding errors may yield a negligibly negative value.
+    Ok((weighted_sum / (n * combined_target as f64)).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    /// The tolerance for floating-point comparisons.
+    const EPSILON: f64 = 1e-9;
+
+    /// Returns the Gini coefficient of the given targets, as the mean absolute difference.
+    fn gini_by_mean_absolute_difference(targets: &[u64]) -> f64 {
+        let n = targets.len() as f64;
+        let mean = targets.iter().map(|target| *target as f64).sum::<f64>() / n;
+        let sum = targets.iter().flat_map(|a| targets.iter().map(move |b| (*a as f64 - *b as f64).abs())).sum::<f64>();
+        sum / (2.0 * n * n * mean)
+    }
+
+    #[test]
+    fn test_gini() -> Result<()> {
+        // Check the empty and single-target cases.
+        assert_eq!(0.0, gini(vec![])?);
+        assert_eq!(0.0, gini(vec![42])?);
+
+        // Check that a perfectly equal distribution has a Gini coefficient of zero.
+        for n in [2, 4, 10, 100] {
+            assert!(gini(vec![1_000; n])?.abs() < EPSILON);
+        }
+        assert!(gini(vec![u64::MAX; 3])?.abs() < EPSILON);
+
+        // Check that a maximally concentrated distribution has a Gini coefficient of `(n - 1) / n`.
+        for n in [2usize, 4, 10, 100, 1000] {
+            let mut targets = vec![0; n];
+            targets[n / 2] = 1_000_000;
+            let expected = (n - 1) as f64 / n as f64;
+            assert!((gini(targets)? - expected).abs() < EPSILON);
+        }
+        // Check that a large maximally concentrated distribution has a Gini coefficient near one.
+        let mut targets = vec![0; 10_000];
+        targets[0] = 1;
+        assert!(gini(targets)? > 0.999);
+
+        // Check a known distribution, with a mean absolute difference of 8/9 and a mean of 2.
+        assert!((gini(vec![3, 1, 2])? - 2.0 / 9.0).abs() < EPSILON);
+
+        // Check that a combined target of zero fails.
+        assert!(gini(vec![0, 0]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_target_gini() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+
+            // Ensure the Gini coefficient matches the mean absolute difference formula, and is within bounds.
+            let targets = solutions.as_ref().unwrap().values().map(|s| s.to_target()).collect::<Result<Vec<_>>>()?;
+            let candidate = solutions.target_gini()?;
+            assert!(candidate >= 0.0);
+            assert!(candidate <= (solutions.len() - 1) as f64 / solutions.len() as f64 + EPSILON);
+            if targets.len() > 1 {
+                assert!((gini_by_mean_absolute_difference(&targets) - candidate).abs() < EPSILON);
+            }
+        }
+
+        // Ensure empty solutions have a Gini coefficient of zero.
+        assert_eq!(0.0, Solutions::<console::network::MainnetV0>::from(None).target_gini()?);
+        Ok(())
+    }
+}
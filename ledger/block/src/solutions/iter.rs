@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::map::Keys;
+
+impl<N: Network> Solutions<N> {
+    /// Returns an iterator over the solution IDs, each paired with the target of its prover solution,
+    /// in the same order as `solution_ids`.
+    ///
+    /// The targets are computed upfront, so this method returns an error if any target cannot be computed.
+    #[allow(clippy::type_complexity)]
+    pub fn iter(&self) -> Result<Box<dyn Iterator<Item = (&PuzzleCommitment<N>, u64)> + '_>> {
+        match &self.solutions {
+            Some(solutions) => {
+                // Compute the target of each prover solution.
+                let entries = solutions
+                    .iter()
+                    .map(|(commitment, solution)| Ok((commitment, solution.to_target()?)))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Box::new(entries.into_iter()))
+            }
+            None => Ok(Box::new(std::iter::empty::<(&PuzzleCommitment<N>, u64)>())),
+        }
+    }
+}
+
+impl<'a, N: Network> IntoIterator for &'a Solutions<N> {
+    type IntoIter = core::iter::Flatten<core::option::IntoIter<Keys<'a, PuzzleCommitment<N>, ProverSolution<N>>>>;
+    type Item = &'a PuzzleCommitment<N>;
+
+    /// Returns an iterator over the solution IDs, which is empty if there are no solutions.
+    fn into_iter(self) -> Self::IntoIter {
+        self.solutions.as_ref().map(|solutions| solutions.keys()).into_iter().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_iter() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let entries = solutions.iter()?.collect::<Vec<_>>();
+
+            // Ensure the solution IDs match `solution_ids`, in order.
+            assert!(entries.iter().map(|(commitment, _)| *commitment).eq(solutions.solution_ids()));
+            // Ensure each target matches the target of the prover solution.
+            for (commitment, target) in &entries {
+                let solution = solutions.as_ref().unwrap().get_solution(commitment).unwrap();
+                assert_eq!(solution.to_target()?, *target);
+            }
+            // Ensure the sum of the targets is the combined proof target.
+            let sum = entries.iter().map(|(_, target)| *target as u128).sum::<u128>();
+            assert_eq!(solutions.to_combined_proof_target()?, sum);
+        }
+
+        // Ensure empty solutions return an empty iterator.
+        let solutions = Solutions::<console::network::MainnetV0>::from(None);
+        assert_eq!(0, solutions.iter()?.count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            // Ensure the iterator yields the solution IDs, in order.
+            let mut ids = vec![];
+            for id in &solutions {
+                ids.push(id);
+            }
+            assert_eq!(solutions.len(), ids.len());
+            assert!(ids.into_iter().eq(solutions.solution_ids()));
+            assert!(solutions.as_ref().unwrap().keys().eq(&solutions));
+        }
+
+        // Ensure empty solutions return an empty iterator.
+        let solutions = Solutions::<console::network::MainnetV0>::from(None);
+        assert_eq!(0, (&solutions).into_iter().count());
+    }
+}
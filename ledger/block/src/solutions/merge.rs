@@ -0,0 +1,346 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::cmp::Ordering;
+use indexmap::{map::Entry, IndexMap};
+
+impl<N: Network> Solutions<N> {
+    /// Returns the union of `self` and `other`, with the prover solutions of `self` first.
+    ///
+    /// This method fails if the solutions share a puzzle commitment,
+    /// or if the merged solutions exceed `N::MAX_SOLUTIONS`.
+    pub fn merge(&self, other: &Solutions<N>) -> Result<Solutions<N>> {
+        // Collect the prover solutions of both sets.
+        let solutions = self.iter_prover_solutions().chain(other.iter_prover_solutions()).copied().collect();
+        // Return the merged solutions.
+        Self::from_prover_solutions(solutions)
+    }
+
+    /// Returns the union of the canonically-ordered solutions `a` and `b`, in canonical order.
+    ///
+    /// The solutions are merged in linear time by the bytes of their puzzle commitments, without re-sorting.
+    /// Each input is expected to be canonical (see `is_canonical`), which is only checked in debug builds.
+    /// This method fails if the solutions share a puzzle commitment,
+    /// or if the merged solutions exceed `N::MAX_SOLUTIONS`.
+    pub fn from_sorted_streams(a: Solutions<N>, b: Solutions<N>) -> Result<Solutions<N>> {
+        debug_assert!(a.is_canonical(), "The first solutions are not in canonical order");
+        debug_assert!(b.is_canonical(), "The second solutions are not in canonical order");
+
+        // Ensure the merged solutions do not exceed `N::MAX_SOLUTIONS`.
+        let num_solutions = a.len() + b.len();
+        ensure!(
+            num_solutions <= N::MAX_SOLUTIONS,
+            "The merged solutions exceed the allowed number of solutions ({num_solutions} > {})",
+            N::MAX_SOLUTIONS
+        );
+
+        // Pair each prover solution with the bytes of its puzzle commitment, in storage order.
+        let to_pairs = |solutions: &Solutions<N>| {
+            solutions
+                .iter_prover_solutions()
+                .map(|solution| Ok((solution.commitment().to_bytes_le()?, *solution)))
+                .collect::<Result<Vec<_>>>()
+        };
+        let mut a = to_pairs(&a)?.into_iter().peekable();
+        let mut b = to_pairs(&b)?.into_iter().peekable();
+
+        // Merge the prover solutions, taking the smaller puzzle commitment at each step.
+        let mut solutions = Vec::with_capacity(num_solutions);
+        loop {
+            let next = match (a.peek(), b.peek()) {
+                (Some((first, solution)), Some((second, _))) => match first.cmp(second) {
+                    Ordering::Less => a.next(),
+                    Ordering::Greater => b.next(),
+                    Ordering::Equal => bail!("The solutions share the puzzle commitment '{}'", solution.commitment()),
+                },
+                (Some(_), None) => a.next(),
+                (None, Some(_)) => b.next(),
+                (None, None) => break,
+            };
+            solutions.extend(next.map(|(_, solution)| solution));
+        }
+        // Return the merged solutions.
+        Self::from_prover_solutions(solutions)
+    }
+
+    /// Returns the union of the solutions from the given blocks, in block order, along with a map from each
+    /// puzzle commitment to the index of the block it came from.
+    ///
+    /// This method fails if two blocks share a puzzle commitment,
+    /// or if the aggregated solutions exceed `N::MAX_SOLUTIONS`.
+    #[allow(clippy::type_complexity)]
+    pub fn aggregate(blocks: &[Solutions<N>]) -> Result<(Solutions<N>, IndexMap<PuzzleCommitment<N>, usize>)> {
+        // Ensure the aggregated solutions do not exceed `N::MAX_SOLUTIONS`.
+        let num_solutions = blocks.iter().map(|solutions| solutions.len()).sum::<usize>();
+        ensure!(
+            num_solutions <= N::MAX_SOLUTIONS,
+            "The aggregated solutions exceed the allowed number of solutions ({num_solutions} > {})",
+            N::MAX_SOLUTIONS
+        );
+
+        // Collect the prover solutions, recording the index of the block of each puzzle commitment.
+        let mut provenance = IndexMap::with_capacity(num_solutions);
+        let mut solutions = Vec::with_capacity(num_solutions);
+        for (index, block) in blocks.iter().enumerate() {
+            for solution in block.iter_prover_solutions() {
+                match provenance.entry(solution.commitment()) {
+                    Entry::Vacant(entry) => entry.insert(index),
+                    Entry::Occupied(entry) => bail!(
+                        "Blocks {} and {index} share the puzzle commitment '{}'",
+                        entry.get(),
+                        solution.commitment()
+                    ),
+                };
+                solutions.push(*solution);
+            }
+        }
+        // Return the aggregated solutions.
+        Ok((Self::from_prover_solutions(solutions)?, provenance))
+    }
+
+    /// Merges `other` into `self`, evicting the prover solutions with the lowest targets if the union exceeds
+    /// `N::MAX_SOLUTIONS`, and returns the puzzle commitments of the evicted solutions, from the lowest target.
+    ///
+    /// The remaining prover solutions are kept in merge order, with the prover solutions of `self` first.
+    /// Ties between equal targets evict the later prover solution first.
+    /// This method fails if the solutions share a puzzle commitment, in which case `self` is unchanged.
+    pub fn merge_with_eviction(&mut self, other: Solutions<N>) -> Result<Vec<PuzzleCommitment<N>>> {
+        // Ensure the solutions do not share a puzzle commitment.
+        if let Some(commitment) = other.solution_ids().find(|commitment| self.contains(commitment)) {
+            bail!("The solutions share the puzzle commitment '{commitment}'")
+        }
+        // Collect the prover solutions of both sets.
+        let mut solutions =
+            self.iter_prover_solutions().chain(other.iter_prover_solutions()).copied().collect::<Vec<_>>();
+
+        // If the union exceeds `N::MAX_SOLUTIONS`, evict the prover solutions with the lowest targets.
+        let mut evicted = vec![];
+        if solutions.len() > N::MAX_SOLUTIONS {
+            // Order the indices of the prover solutions by ascending target, evicting later solutions first on ties.
+            let targets = solutions.iter().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+            let mut indices = (0..solutions.len()).collect::<Vec<_>>();
+            indices.sort_unstable_by(|a, b| targets[*a].cmp(&targets[*b]).then_with(|| b.cmp(a)));
+            // Evict the prover solutions with the lowest targets.
+            let evicted_indices = &indices[..solutions.len() - N::MAX_SOLUTIONS];
+            evicted = evicted_indices.iter().map(|index| solutions[*index].commitment()).collect();
+            let mut is_evicted = vec![false; solutions.len()];
+            for index in evicted_indices {
+                is_evicted[*index] = true;
+            }
+            // Retain the remaining prover solutions, in merge order.
+            solutions = solutions.into_iter().enumerate().filter(|(i, _)| !is_evicted[*i]).map(|(_, s)| s).collect();
+        }
+
+        // Update the solutions.
+        *self = Self::from_prover_solutions(solutions)?;
+        // Return the puzzle commitments of the evicted solutions.
+        Ok(evicted)
+    }
+
+    /// Returns an iterator over the prover solutions.
+    fn iter_prover_solutions(&self) -> impl '_ + Iterator<Item = &ProverSolution<N>> {
+        self.solutions.iter().flat_map(|solutions| solutions.values())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::{serialize::tests::sample_solutions, validate::tests::sample_prover_solutions};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_merge() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample two sets of solutions.
+        let first = sample_solutions(rng);
+        let second = sample_solutions(rng);
+
+        // Merge the solutions.
+        let merged = first.merge(&second)?;
+        assert_eq!(first.len() + second.len(), merged.len());
+        for solution_id in first.solution_ids().chain(second.solution_ids()) {
+            assert!(merged.as_ref().unwrap().contains_key(solution_id));
+        }
+
+        // Merging with empty solutions is the identity.
+        let empty = Solutions::from(None);
+        assert_eq!(first, first.merge(&empty)?);
+        assert_eq!(first, empty.merge(&first)?);
+        assert!(empty.merge(&empty)?.is_empty());
+
+        // Merging solutions with themselves fails, due to duplicate puzzle commitments.
+        assert!(first.merge(&first).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_eviction() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample two sets of solutions whose union exceeds `N::MAX_SOLUTIONS`.
+        let max_solutions = CurrentNetwork::MAX_SOLUTIONS;
+        let first = Solutions::from_prover_solutions(sample_prover_solutions(max_solutions - 10, rng))?;
+        let second = Solutions::from_prover_solutions(sample_prover_solutions(40, rng))?;
+
+        // Compute the expected evicted puzzle commitments, as the 30 lowest targets of the union,
+        // evicting the later prover solution first on ties.
+        let mut targets = first
+            .iter_prover_solutions()
+            .chain(second.iter_prover_solutions())
+            .enumerate()
+            .map(|(index, solution)| Ok((solution.to_target()?, index, solution.commitment())))
+            .collect::<Result<Vec<_>>>()?;
+        targets.sort_by_key(|(target, index, _)| (*target, core::cmp::Reverse(*index)));
+        let expected_evicted = targets[..30].iter().map(|(_, _, commitment)| *commitment).collect::<Vec<_>>();
+
+        // Merge the solutions with eviction.
+        let mut merged = first.clone();
+        let evicted = merged.merge_with_eviction(second.clone())?;
+        assert_eq!(expected_evicted, evicted);
+        assert_eq!(max_solutions, merged.len());
+        // Ensure the globally-highest prover solutions are kept, in merge order.
+        let kept = first.solution_ids().chain(second.solution_ids()).filter(|id| !evicted.contains(*id));
+        assert!(kept.eq(merged.solution_ids()));
+        let min_kept = merged.iter()?.map(|(_, target)| target).min().unwrap();
+        assert!(targets[..30].iter().all(|(target, _, _)| *target <= min_kept));
+
+        // Ensure merging within `N::MAX_SOLUTIONS` evicts nothing, and matches `merge`.
+        let third = sample_solutions(rng);
+        let mut candidate = third.clone();
+        assert!(candidate.merge_with_eviction(second.clone())?.is_empty());
+        assert_eq!(third.merge(&second)?, candidate);
+
+        // Ensure duplicate puzzle commitments fail, leaving the solutions unchanged.
+        let mut candidate = first.clone();
+        let error = candidate.merge_with_eviction(first.clone()).unwrap_err().to_string();
+        assert!(error.contains("share the puzzle commitment"), "Unexpected error: {error}");
+        assert_eq!(first, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two blocks of solutions.
+            let first = sample_solutions(rng);
+            let second = sample_solutions(rng);
+
+            // Ensure the aggregated solutions match the merged solutions.
+            let (aggregated, provenance) = Solutions::aggregate(&[first.clone(), second.clone()])?;
+            assert_eq!(first.merge(&second)?, aggregated);
+            // Ensure each puzzle commitment maps to the index of its block.
+            assert_eq!(aggregated.len(), provenance.len());
+            for solution_id in first.solution_ids() {
+                assert_eq!(Some(&0), provenance.get(solution_id));
+            }
+            for solution_id in second.solution_ids() {
+                assert_eq!(Some(&1), provenance.get(solution_id));
+            }
+
+            // Ensure empty blocks are skipped, while preserving the block indices.
+            let empty = Solutions::from(None);
+            let (candidate, provenance) = Solutions::aggregate(&[empty.clone(), first.clone(), empty.clone()])?;
+            assert_eq!(first, candidate);
+            assert!(provenance.values().all(|index| *index == 1));
+
+            // Ensure a puzzle commitment shared across blocks fails, naming both blocks.
+            let error = Solutions::aggregate(&[first.clone(), second.clone(), first.clone()]).unwrap_err().to_string();
+            assert!(error.contains("Blocks 0 and 2"), "Unexpected error: {error}");
+        }
+
+        // Ensure no blocks aggregate to empty solutions.
+        let (aggregated, provenance) = Solutions::<console::network::MainnetV0>::aggregate(&[])?;
+        assert!(aggregated.is_empty());
+        assert!(provenance.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_sorted_streams() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions, and canonicalize them.
+            let mut solutions = sample_solutions(rng);
+            solutions.validate_and_canonicalize()?;
+
+            // Split the prover solutions into two interleaved streams, which are each canonical.
+            let (first, second): (Vec<_>, Vec<_>) =
+                solutions.to_sorted_solutions()?.into_iter().enumerate().partition(|(index, _)| index % 2 == 0);
+            let first = Solutions::from_prover_solutions(first.into_iter().map(|(_, solution)| solution).collect())?;
+            let second = Solutions::from_prover_solutions(second.into_iter().map(|(_, solution)| solution).collect())?;
+            assert!(first.is_canonical() && second.is_canonical());
+
+            // Ensure merging the streams in either order recovers the canonical solutions.
+            for merged in [
+                Solutions::from_sorted_streams(first.clone(), second.clone())?,
+                Solutions::from_sorted_streams(second.clone(), first.clone())?,
+            ] {
+                assert!(merged.is_canonical());
+                assert_eq!(solutions, merged);
+                assert!(solutions.solution_ids().eq(merged.solution_ids()));
+            }
+
+            // Ensure merging with empty solutions is the identity.
+            let empty = Solutions::from(None);
+            assert_eq!(solutions, Solutions::from_sorted_streams(solutions.clone(), empty.clone())?);
+            assert_eq!(solutions, Solutions::from_sorted_streams(empty.clone(), solutions.clone())?);
+            assert!(Solutions::from_sorted_streams(empty.clone(), empty)?.is_empty());
+
+            // Ensure merging two independently sampled sets matches the canonicalized merge.
+            let mut other = sample_solutions(rng);
+            other.validate_and_canonicalize()?;
+            let merged = Solutions::from_sorted_streams(solutions.clone(), other.clone())?;
+            let mut expected = solutions.merge(&other)?;
+            expected.validate_and_canonicalize()?;
+            assert!(expected.solution_ids().eq(merged.solution_ids()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_sorted_streams_rejects_duplicates() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions, and canonicalize them.
+            let mut solutions = sample_solutions(rng);
+            solutions.validate_and_canonicalize()?;
+
+            // Ensure merging the solutions with themselves fails.
+            assert!(Solutions::from_sorted_streams(solutions.clone(), solutions.clone()).is_err());
+
+            // Ensure merging with a stream that shares a single prover solution fails.
+            let shared = solutions.to_sorted_solutions()?.pop().unwrap();
+            let mut other = sample_solutions(rng);
+            other.validate_and_canonicalize()?;
+            let mut other = other.to_sorted_solutions()?;
+            other.push(shared);
+            let mut other = Solutions::from_prover_solutions(other)?;
+            other.validate_and_canonicalize()?;
+            assert!(Solutions::from_sorted_streams(solutions.clone(), other.clone()).is_err());
+            assert!(Solutions::from_sorted_streams(other, solutions).is_err());
+        }
+        Ok(())
+    }
+}
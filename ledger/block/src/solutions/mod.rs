@@ -12,13 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod addresses;
 mod bytes;
+mod canonical;
+mod canonical_digest;
+mod chunks;
+mod coefficient_of_variation;
+mod committee_limit;
+mod cumulative;
+mod diff;
+mod entropy;
+mod estimate_reward;
+mod gini;
+mod iter;
+mod merge;
 mod merkle;
+mod moving_average;
+mod normalize;
+mod penalty;
+mod per_address;
+mod quantiles;
+mod reconstruct;
+mod retain;
+mod reward;
 mod serialize;
+mod shard;
+mod sorted_targets;
+mod split;
 mod string;
+mod target_cdf;
+mod top_k;
+mod validate;
+mod versioned;
+
+pub use diff::SolutionsDiff;
 
 use console::{network::prelude::*, types::Field};
-use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
+use ledger_coinbase::{CoinbaseSolution, ProverSolution, PuzzleCommitment};
 use ledger_committee::Committee;
 use ledger_narwhal_batch_header::BatchHeader;
 
@@ -45,6 +75,9 @@ impl<N: Network> From<Option<CoinbaseSolution<N>>> for Solutions<N> {
 
 impl<N: Network> Solutions<N> {
     /// Initializes a new instance of the solutions.
+    ///
+    /// Note: The number of solutions is bounded by `N::MAX_SOLUTIONS` in `CoinbaseSolution::new`,
+    /// which every constructor and decoder of `CoinbaseSolution` goes through.
     pub fn new(solutions: CoinbaseSolution<N>) -> Result<Self> {
         // Return the solutions.
         Ok(Self { solutions: Some(solutions) })
@@ -67,9 +100,50 @@ impl<N: Network> Solutions<N> {
 impl<N: Network> Solutions<N> {
     /// Returns an iterator over the solution IDs.
     pub fn solution_ids<'a>(&'a self) -> Box<dyn Iterator<Item = &'a PuzzleCommitment<N>> + 'a> {
+        Box::new(self.into_iter())
+    }
+
+    /// Returns the prover solution for the given solution ID, if it exists.
+    pub fn get(&self, id: &PuzzleCommitment<N>) -> Option<&ProverSolution<N>> {
+        match &self.solutions {
+            Some(solutions) => solutions.get_solution(id),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if the solutions contain the given solution ID.
+    pub fn contains(&self, id: &PuzzleCommitment<N>) -> bool {
         match &self.solutions {
-            Some(solutions) => Box::new(solutions.keys()),
-            None => Box::new(std::iter::empty::<&PuzzleCommitment<N>>()),
+            Some(solutions) => solutions.contains_key(id),
+            None => false,
+        }
+    }
+}
+
+impl<N: Network> Solutions<N> {
+    /// Returns the prover solutions, sorted by the byte representation of their puzzle commitment.
+    fn to_sorted_solutions(&self) -> Result<Vec<ProverSolution<N>>> {
+        match &self.solutions {
+            Some(solutions) => {
+                // Pair each prover solution with the bytes of its puzzle commitment.
+                let mut solutions = solutions
+                    .iter()
+                    .map(|(commitment, solution)| Ok((commitment.to_bytes_le()?, *solution)))
+                    .collect::<Result<Vec<_>>>()?;
+                // Sort the prover solutions by their puzzle commitment bytes.
+                solutions.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+                // Return the sorted prover solutions.
+                Ok(solutions.into_iter().map(|(_, solution)| solution).collect())
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Initializes the solutions from the given prover solutions, returning `None` if the list is empty.
+    fn from_prover_solutions(solutions: Vec<ProverSolution<N>>) -> Result<Self> {
+        match solutions.is_empty() {
+            true => Ok(Self::from(None)),
+            false => Self::new(CoinbaseSolution::new(solutions)?),
         }
     }
 }
@@ -92,3 +166,51 @@ impl<N: Network> Deref for Solutions<N> {
         &self.solutions
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_get() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            // Ensure each solution ID returns its prover solution.
+            for (id, solution) in solutions.as_ref().unwrap().iter() {
+                assert_eq!(Some(solution), solutions.get(id));
+            }
+            // Ensure a solution ID from other solutions is absent.
+            let other = sample_solutions(rng);
+            let id = other.solution_ids().next().unwrap();
+            assert_eq!(None, solutions.get(id));
+            // Ensure empty solutions contain no solution IDs.
+            assert_eq!(None, Solutions::<CurrentNetwork>::from(None).get(id));
+        }
+    }
+
+    #[test]
+    fn test_contains() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            // Ensure each solution ID is contained.
+            assert!(solutions.solution_ids().all(|id| solutions.contains(id)));
+            // Ensure a solution ID from other solutions is not contained.
+            let other = sample_solutions(rng);
+            let id = other.solution_ids().next().unwrap();
+            assert!(!solutions.contains(id));
+            // Ensure empty solutions contain no solution IDs.
+            assert!(!Solutions::<CurrentNetwork>::from(None).contains(id));
+        }
+    }
+}
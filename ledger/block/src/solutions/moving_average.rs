@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the moving average of the targets of the prover solutions, sorted in ascending order,
+    /// over each window of `window` consecutive targets, rounded down.
+    ///
+    /// This method returns an error if `window` is zero. If `window` exceeds the number of solutions,
+    /// this method returns an empty list. Otherwise, it returns `len() - window + 1` averages.
+    pub fn target_moving_average(&self, window: usize) -> Result<Vec<u128>> {
+        // Ensure the window is non-zero.
+        ensure!(window > 0, "The window of the moving average must be non-zero");
+        match &self.solutions {
+            Some(solutions) => {
+                // Compute the target of each prover solution.
+                let mut targets =
+                    solutions.values().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+                // Sort the targets in ascending order.
+                targets.sort_unstable();
+                // Compute the moving average of the targets.
+                Ok(moving_average(&targets, window))
+            }
+            None => Ok(vec![]),
+        }
+    }
+}
+
+/// Returns the average of each window of `window` consecutive targets, rounded down.
+///
+/// Note: The sum of a window cannot overflow, as the sum of up to `usize::MAX` targets fits in a `u128`.
+fn moving_average(targets: &[u64], window: usize) -> Vec<u128> {
+    // If the window is empty or exceeds the number of targets, there are no averages.
+    if window == 0 || window > targets.len() {
+        return vec![];
+    }
+    // Compute the sum of the first window.
+    let mut sum = targets[..window].iter().map(|target| *target as u128).sum::<u128>();
+    let mut averages = Vec::with_capacity(targets.len() - window + 1);
+    averages.push(sum / window as u128);
+    // Slide the window, adding the next target and removing the first target of the previous window.
+    for (next, previous) in targets[window..].iter().zip(targets) {
+        sum = sum + *next as u128 - *previous as u128;
+        averages.push(sum / window as u128);
+    }
+    averages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_moving_average() {
+        // Check a known sequence.
+        let targets = [1, 2, 3, 4, 5, 6];
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], moving_average(&targets, 1));
+        assert_eq!(vec![1, 2, 3, 4, 5], moving_average(&targets, 2));
+        assert_eq!(vec![2, 3, 4, 5], moving_average(&targets, 3));
+        assert_eq!(vec![3], moving_average(&targets, 6));
+
+        // Check that the averages are rounded down.
+        assert_eq!(vec![5, 15, 33], moving_average(&[0, 10, 20, 46], 2));
+
+        // Check that the averages do not overflow.
+        assert_eq!(vec![u64::MAX as u128; 2], moving_average(&[u64::MAX; 4], 3));
+
+        // Check the edge cases.
+        assert!(moving_average(&targets, 7).is_empty());
+        assert!(moving_average(&targets, 0).is_empty());
+        assert!(moving_average(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn test_target_moving_average() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+
+            // Compute the sorted targets.
+            let mut targets =
+                solutions.as_ref().unwrap().values().map(|s| s.to_target()).collect::<Result<Vec<_>>>()?;
+            targets.sort_unstable();
+
+            for window in 1..=solutions.len() {
+                // Ensure each average matches the average of its window.
+                let averages = solutions.target_moving_average(window)?;
+                assert_eq!(solutions.len() - window + 1, averages.len());
+                for (average, targets) in averages.iter().zip_eq(targets.windows(window)) {
+                    let sum = targets.iter().map(|target| *target as u128).sum::<u128>();
+                    assert_eq!(sum / window as u128, *average);
+                }
+                // Ensure the averages are non-decreasing, as the targets are sorted.
+                assert!(averages.windows(2).all(|pair| pair[0] <= pair[1]));
+            }
+
+            // Ensure a window exceeding the number of solutions returns no averages.
+            assert!(solutions.target_moving_average(solutions.len() + 1)?.is_empty());
+            // Ensure a window of zero fails.
+            assert!(solutions.target_moving_average(0).is_err());
+        }
+
+        // Ensure empty solutions return no averages, and a window of zero fails.
+        let solutions = Solutions::<console::network::MainnetV0>::from(None);
+        assert!(solutions.target_moving_average(1)?.is_empty());
+        assert!(solutions.target_moving_average(0).is_err());
+        Ok(())
+    }
+}
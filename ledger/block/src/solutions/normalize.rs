@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the target of each prover solution, scaled to `0..=scale` relative to the maximum target,
+    /// ordered by puzzle commitment.
+    ///
+    /// Each prover solution maps to `floor(target * scale / max_target)`, so the prover solution(s)
+    /// with the maximum target map to `scale`. If there are no solutions, this method returns an empty map.
+    pub fn normalized_targets(&self, scale: u32) -> Result<IndexMap<PuzzleCommitment<N>, u32>> {
+        // Retrieve the prover solutions, sorted by puzzle commitment.
+        let solutions = self.to_sorted_solutions()?;
+        // Compute the target of each prover solution.
+        let targets = solutions.iter().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+        // Scale the targets.
+        let normalized = normalize_targets(&targets, scale)?;
+        // Return the normalized target for each prover solution.
+        Ok(solutions.iter().map(|solution| solution.commitment()).zip_eq(normalized).collect())
+    }
+}
+
+/// Returns each target scaled to `0..=scale` relative to the maximum target.
+fn normalize_targets(targets: &[u64], scale: u32) -> Result<Vec<u32>> {
+    // If there are no targets, return an empty list.
+    let Some(max_target) = targets.iter().max().copied() else {
+        return Ok(vec![]);
+    };
+    // Ensure the maximum target is non-zero.
+    ensure!(max_target > 0, "Cannot normalize targets with a maximum target of zero");
+
+    // Note: The product cannot overflow, as `target` and `scale` are at most `u64::MAX` and `u32::MAX`.
+    // Note: The quotient is at most `scale`, as `target` is at most `max_target`.
+    targets
+        .iter()
+        .map(|target| u32::try_from(*target as u128 * scale as u128 / max_target as u128).map_err(Into::into))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_normalize_targets() -> Result<()> {
+        // Check a known set of targets.
+        assert_eq!(normalize_targets(&[1, 250, 500, 999, 1000], 1000)?, vec![1, 250, 500, 999, 1000]);
+        assert_eq!(normalize_targets(&[1, 250, 500, 999, 1000], 100)?, vec![0, 25, 50, 99, 100]);
+        assert_eq!(normalize_targets(&[3, 7, 1, 7], 1000)?, vec![428, 1000, 142, 1000]);
+        assert_eq!(normalize_targets(&[1, u64::MAX], u32::MAX)?, vec![0, u32::MAX]);
+        assert_eq!(normalize_targets(&[u64::MAX - 1, u64::MAX], 1000)?, vec![999, 1000]);
+        assert_eq!(normalize_targets(&[5, 10], 0)?, vec![0, 0]);
+
+        // Check that equal targets all map to the scale.
+        assert_eq!(normalize_targets(&[42, 42, 42], 1000)?, vec![1000, 1000, 1000]);
+        assert_eq!(normalize_targets(&[u64::MAX], 1000)?, vec![1000]);
+
+        // Check the empty set.
+        assert!(normalize_targets(&[], 1000)?.is_empty());
+        // Check that a maximum target of zero fails.
+        assert!(normalize_targets(&[0, 0], 1000).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalized_targets() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let max_target = solutions
+                .as_ref()
+                .unwrap()
+                .values()
+                .map(|solution| solution.to_target())
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .max()
+                .unwrap();
+
+            // Normalize the targets.
+            let normalized = solutions.normalized_targets(1000)?;
+            assert_eq!(solutions.len(), normalized.len());
+            // Ensure the normalized targets are ordered by puzzle commitment.
+            let expected_order = solutions.to_sorted_solutions()?.iter().map(|s| s.commitment()).collect::<Vec<_>>();
+            assert_eq!(expected_order, normalized.keys().copied().collect::<Vec<_>>());
+
+            // Ensure each target is scaled relative to the maximum target.
+            for (commitment, value) in &normalized {
+                let target = solutions.as_ref().unwrap().get_solution(commitment).unwrap().to_target()?;
+                assert_eq!(*value as u128, target as u128 * 1000 / max_target as u128);
+            }
+            // Ensure the maximum target maps to the scale.
+            assert_eq!(Some(&1000), normalized.values().max());
+        }
+
+        // Ensure empty solutions return an empty map.
+        assert!(Solutions::<console::network::MainnetV0>::from(None).normalized_targets(1000)?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::types::Address;
+use std::collections::HashSet;
+
+impl<N: Network> Solutions<N> {
+    /// Removes the prover solutions whose prover address is in the given set of penalized addresses,
+    /// preserving the order of the remaining solutions, and returns the combined proof target that was removed.
+    ///
+    /// If no solutions remain, the solutions become empty (i.e. `None`).
+    /// If the combined proof target of the removed solutions cannot be computed, the solutions are unchanged.
+    pub fn apply_penalty(&mut self, penalized: &HashSet<Address<N>>) -> Result<u128> {
+        let Some(solutions) = &self.solutions else {
+            return Ok(0);
+        };
+        // Partition the prover solutions into the penalized and remaining solutions.
+        let (removed, remaining): (Vec<ProverSolution<N>>, Vec<ProverSolution<N>>) =
+            solutions.values().copied().partition(|solution| penalized.contains(&solution.address()));
+        // Compute the combined proof target of the penalized prover solutions.
+        let penalized_target = removed.iter().try_fold(0u128, |combined, solution| {
+            combined.checked_add(solution.to_target()? as u128).ok_or_else(|| anyhow!("Combined target overflowed"))
+        })?;
+        // Update the solutions, if any prover solutions were removed.
+        if !removed.is_empty() {
+            *self = Self::from_prover_solutions(remaining)?;
+        }
+        Ok(penalized_target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_apply_penalty() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions, where each prover solution has a distinct address.
+            let solutions = sample_solutions(rng);
+            let prover_solutions = solutions.as_ref().unwrap().values().copied().collect::<Vec<_>>();
+
+            // Penalize every other address.
+            let penalized =
+                prover_solutions.iter().step_by(2).map(|solution| solution.address()).collect::<HashSet<_>>();
+            let expected_target = prover_solutions
+                .iter()
+                .step_by(2)
+                .map(|solution| solution.to_target().map(|target| target as u128))
+                .sum::<Result<u128>>()?;
+            let expected = prover_solutions.iter().skip(1).step_by(2).copied().collect::<Vec<_>>();
+
+            // Ensure the removed target is the sum of the targets of the penalized solutions.
+            let mut candidate = solutions.clone();
+            assert_eq!(expected_target, candidate.apply_penalty(&penalized)?);
+            // Ensure the non-penalized solutions remain, in order.
+            match expected.is_empty() {
+                true => assert!(candidate.is_empty()),
+                false => assert_eq!(expected, candidate.as_ref().unwrap().values().copied().collect::<Vec<_>>()),
+            }
+            // Ensure the combined proof target is conserved.
+            assert_eq!(solutions.to_combined_proof_target()?, expected_target + candidate.to_combined_proof_target()?);
+
+            // Ensure penalizing all of the addresses collapses the solutions to `None`.
+            let penalized = prover_solutions.iter().map(|solution| solution.address()).collect::<HashSet<_>>();
+            let mut candidate = solutions.clone();
+            assert_eq!(solutions.to_combined_proof_target()?, candidate.apply_penalty(&penalized)?);
+            assert!(candidate.is_empty());
+
+            // Ensure penalizing none of the addresses leaves the solutions unchanged.
+            let mut candidate = solutions.clone();
+            assert_eq!(0, candidate.apply_penalty(&HashSet::new())?);
+            assert_eq!(solutions, candidate);
+        }
+
+        // Ensure empty solutions remain empty.
+        let mut empty = Solutions::<CurrentNetwork>::from(None);
+        assert_eq!(0, empty.apply_penalty(&HashSet::new())?);
+        assert!(empty.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::types::Address;
+use indexmap::IndexMap;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the number of prover solutions contributed by each prover address, ordered by address.
+    ///
+    /// Note: As `Address` does not implement `Ord`, the addresses are ordered by their little-endian bytes,
+    /// so the map is independent of the order in which the prover solutions are stored.
+    /// If there are no solutions, this method returns an empty map.
+    pub fn solutions_per_address(&self) -> Result<IndexMap<Address<N>, usize>> {
+        match &self.solutions {
+            Some(solutions) => count_per_address(solutions.values().map(|solution| solution.address())),
+            None => Ok(IndexMap::new()),
+        }
+    }
+
+    /// Returns the top `n` prover addresses by the combined proof target of their prover solutions,
+    /// in descending order of combined target, as `(address, combined_target)` pairs.
+    ///
+    /// Ties between equal combined targets are broken by the little-endian bytes of the address.
+    /// This method returns an empty list if `n == 0`, and every address if `n` is at least the number of addresses.
+    pub fn top_contributors(&self, n: usize) -> Result<Vec<(Address<N>, u128)>> {
+        let solutions = match &self.solutions {
+            Some(solutions) => solutions,
+            None => return Ok(vec![]),
+        };
+        // Sum the targets of the prover solutions of each address.
+        let mut totals = IndexMap::<Address<N>, u128>::new();
+        for solution in solutions.values() {
+            let total = totals.entry(solution.address()).or_default();
+            *total = total
+                .checked_add(solution.to_target()? as u128)
+                .ok_or_else(|| anyhow!("Combined target overflowed"))?;
+        }
+        // Return the top `n` addresses.
+        rank_contributors(totals, n)
+    }
+}
+
+/// Returns the number of occurrences of each address, ordered by the little-endian bytes of the address.
+fn count_per_address<N: Network>(addresses: impl Iterator<Item = Address<N>>) -> Result<IndexMap<Address<N>, usize>> {
+    // Count the occurrences of each address.
+    let mut counts = IndexMap::<Address<N>, usize>::new();
+    for address in addresses {
+        *counts.entry(address).or_default() += 1;
+    }
+    // Pair each address with its bytes.
+    let mut counts = counts
+        .into_iter()
+        .map(|(address, count)| Ok((address.to_bytes_le()?, address, count)))
+        .collect::<Result<Vec<_>>>()?;
+    // Sort the addresses by their bytes.
+    counts.sort_unstable_by(|(a, ..), (b, ..)| a.cmp(b));
+    // Return the counts, ordered by address.
+    Ok(counts.into_iter().map(|(_, address, count)| (address, count)).collect())
+}
+
+/// Returns the top `n` addresses in descending order of their combined target,
+/// breaking ties by the little-endian bytes of the address.
+fn rank_contributors<N: Network>(totals: IndexMap<Address<N>, u128>, n: usize) -> Result<Vec<(Address<N>, u128)>> {
+    // Pair each address with its bytes.
+    let mut totals = totals
+        .into_iter()
+        .map(|(address, total)| Ok((address.to_bytes_le()?, address, total)))
+        .collect::<Result<Vec<_>>>()?;
+    // Sort the addresses by their combined target in descending order, and then by their bytes.
+    totals.sort_unstable_by(|(a_bytes, _, a), (b_bytes, _, b)| b.cmp(a).then_with(|| a_bytes.cmp(b_bytes)));
+    // Return the top `n` addresses.
+    Ok(totals.into_iter().take(n).map(|(_, address, total)| (address, total)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+    use console::account::PrivateKey;
+    use ledger_coinbase::{PartialSolution, PuzzleProof};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    /// Samples a prover solution for the given address.
+    fn sample_prover_solution(address: Address<CurrentNetwork>, rng: &mut TestRng) -> ProverSolution<CurrentNetwork> {
+        let commitment = PuzzleCommitment::from_g1_affine(rng.gen());
+        let partial_solution = PartialSolution::new(address, u64::rand(rng), commitment);
+        let proof = PuzzleProof::<CurrentNetwork> { w: rng.gen(), random_v: None };
+        ProverSolution::new(partial_solution, proof)
+    }
+
+    /// Returns `true` if the addresses are in strictly ascending order of their bytes.
+    fn is_ordered_by_bytes(counts: &IndexMap<Address<CurrentNetwork>, usize>) -> bool {
+        let bytes = counts.keys().map(|address| address.to_bytes_le().unwrap()).collect::<Vec<_>>();
+        bytes.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    #[test]
+    fn test_solutions_per_address() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions, where each prover solution has a distinct address.
+            let solutions = sample_solutions(rng);
+
+            // Ensure each address contributes exactly one prover solution.
+            let counts = solutions.solutions_per_address()?;
+            assert_eq!(solutions.len(), counts.len());
+            assert!(counts.values().all(|count| *count == 1));
+            assert!(is_ordered_by_bytes(&counts));
+        }
+
+        // Ensure empty solutions return an empty map.
+        assert!(Solutions::<CurrentNetwork>::from(None).solutions_per_address()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_solutions_per_address_with_known_counts() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample prover addresses, with the number of prover solutions each contributes.
+        let expected = [3, 1, 4, 2]
+            .into_iter()
+            .map(|count| (Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap(), count))
+            .collect::<Vec<_>>();
+
+        // Construct the prover solutions, interleaving the addresses.
+        let mut prover_solutions = vec![];
+        for round in 0..4 {
+            for (address, count) in &expected {
+                if round < *count {
+                    prover_solutions.push(sample_prover_solution(*address, rng));
+                }
+            }
+        }
+        let solutions = Solutions::from_prover_solutions(prover_solutions)?;
+
+        // Ensure the histogram matches the expected counts.
+        let counts = solutions.solutions_per_address()?;
+        assert_eq!(expected.len(), counts.len());
+        for (address, count) in &expected {
+            assert_eq!(Some(count), counts.get(address));
+        }
+        assert!(is_ordered_by_bytes(&counts));
+        assert_eq!(solutions.len(), counts.values().sum::<usize>());
+
+        // Ensure the histogram is independent of the storage order.
+        let mut reversed = solutions.to_sorted_solutions()?;
+        reversed.reverse();
+        let reversed_counts = Solutions::from_prover_solutions(reversed)?.solutions_per_address()?;
+        assert!(counts.iter().eq(reversed_counts.iter()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solutions_per_address_with_a_single_address() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a prover address that contributes all of the prover solutions.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let prover_solutions = (0..5).map(|_| sample_prover_solution(address, rng)).collect();
+        let solutions = Solutions::from_prover_solutions(prover_solutions)?;
+
+        // Ensure the histogram has a single entry with every prover solution.
+        let counts = solutions.solutions_per_address()?;
+        assert_eq!(1, counts.len());
+        assert_eq!(Some(&5), counts.get(&address));
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_contributors() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample prover addresses, each contributing a different number of prover solutions.
+        let addresses = (0..4)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        let mut prover_solutions = vec![];
+        for (i, address) in addresses.iter().enumerate() {
+            prover_solutions.extend((0..=i).map(|_| sample_prover_solution(*address, rng)));
+        }
+        let solutions = Solutions::from_prover_solutions(prover_solutions.clone())?;
+
+        // Compute the expected combined target of each address.
+        let mut expected = addresses
+            .iter()
+            .map(|address| {
+                let total = prover_solutions
+                    .iter()
+                    .filter(|solution| solution.address() == *address)
+                    .map(|solution| solution.to_target().unwrap() as u128)
+                    .sum::<u128>();
+                (*address, total)
+            })
+            .collect::<Vec<_>>();
+        expected.sort_by(|(a_address, a), (b_address, b)| {
+            b.cmp(a).then_with(|| a_address.to_bytes_le().unwrap().cmp(&b_address.to_bytes_le().unwrap()))
+        });
+
+        // Ensure the ranking and the combined targets match, for each `n`.
+        assert!(solutions.top_contributors(0)?.is_empty());
+        for n in 1..=addresses.len() {
+            assert_eq!(expected[..n], solutions.top_contributors(n)?);
+        }
+        // Ensure `n` beyond the number of addresses returns every address.
+        assert_eq!(expected, solutions.top_contributors(addresses.len() + 1)?);
+        assert_eq!(expected, solutions.top_contributors(usize::MAX)?);
+        // Ensure the combined targets sum to the combined proof target.
+        let combined = expected.iter().map(|(_, total)| *total).sum::<u128>();
+        assert_eq!(solutions.to_combined_proof_target()?, combined);
+
+        // Ensure empty solutions return an empty list.
+        assert!(Solutions::<CurrentNetwork>::from(None).top_contributors(1)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_contributors_with_tied_targets() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample prover addresses, where every other address shares the same combined target.
+        // Note: As the target of a prover solution is determined by its puzzle commitment, which is unique
+        // within the solutions, the ranking is checked directly on the combined targets.
+        let totals = (0..6)
+            .map(|i| (Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap(), (i % 2) as u128))
+            .collect::<IndexMap<_, _>>();
+
+        // Compute the expected ranking, with the tied addresses ordered by their bytes.
+        let mut expected = totals.iter().map(|(address, total)| (*address, *total)).collect::<Vec<_>>();
+        expected.sort_by_key(|(address, total)| (u128::MAX - total, address.to_bytes_le().unwrap()));
+
+        // Ensure the ranking is independent of the insertion order.
+        assert_eq!(expected, rank_contributors(totals.clone(), 6)?);
+        assert_eq!(expected, rank_contributors(totals.into_iter().rev().collect(), 6)?);
+        assert_eq!(expected[..2], rank_contributors(expected.iter().copied().collect(), 2)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the target of the prover solutions at the given percentile, in `[0, 100]`,
+    /// using the nearest-rank method (see `target_quantiles`).
+    ///
+    /// This method returns an error if the percentile is out of range, and `None` if there are no solutions.
+    pub fn target_at_percentile(&self, p: f64) -> Result<Option<u64>> {
+        // Ensure the percentile is in range.
+        ensure_percentile(p)?;
+        // Compute the targets, sorted in ascending order.
+        let targets = self.sorted_targets()?;
+        // Return the target at the percentile.
+        Ok(match targets.is_empty() {
+            true => None,
+            false => Some(target_at_rank(&targets, p)),
+        })
+    }
+
+    /// Returns the target of the prover solutions at each of the given percentiles, in `[0, 100]`.
+    ///
+    /// The targets are sorted once, and each percentile `p` selects the target of the nearest rank
+    /// `max(1, ceil(p / 100 * len()))` in ascending order, so `0` selects the minimum target and `100` the maximum.
+    /// This method returns an error if any percentile is out of range, and an empty list if there are no solutions.
+    pub fn target_quantiles(&self, ps: &[f64]) -> Result<Vec<u64>> {
+        // Ensure each percentile is in range.
+        ps.iter().try_for_each(|p| ensure_percentile(*p))?;
+        // Compute the targets, sorted in ascending order.
+        let targets = self.sorted_targets()?;
+        // Return the target at each percentile.
+        Ok(match targets.is_empty() {
+            true => vec![],
+            false => ps.iter().map(|p| target_at_rank(&targets, *p)).collect(),
+        })
+    }
+}
+
+/// Ensures the given percentile is in `[0, 100]`.
+fn ensure_percentile(p: f64) -> Result<()> {
+    ensure!((0.0..=100.0).contains(&p), "Percentile must be in [0, 100], found {p}");
+    Ok(())
+}
+
+/// Returns the target of the nearest rank for the given percentile, from the non-empty sorted targets.
+fn target_at_rank(targets: &[u64], p: f64) -> u64 {
+    // Compute the nearest rank, which is 1-indexed.
+    let rank = (p / 100.0 * targets.len() as f64).ceil() as usize;
+    // Clamp the rank to the targets, as a percentile of zero yields a rank of zero.
+    targets[rank.clamp(1, targets.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    const PERCENTILES: &[f64] = &[0.0, 1.0, 10.0, 25.0, 33.3, 50.0, 66.7, 75.0, 90.0, 99.0, 100.0];
+
+    #[test]
+    fn test_target_at_rank() {
+        // Check a known sequence, where each target is its rank.
+        let targets = (1..=10).collect::<Vec<u64>>();
+        assert_eq!(1, target_at_rank(&targets, 0.0));
+        assert_eq!(1, target_at_rank(&targets, 5.0));
+        assert_eq!(1, target_at_rank(&targets, 10.0));
+        assert_eq!(2, target_at_rank(&targets, 10.1));
+        assert_eq!(5, target_at_rank(&targets, 50.0));
+        assert_eq!(9, target_at_rank(&targets, 90.0));
+        assert_eq!(10, target_at_rank(&targets, 99.0));
+        assert_eq!(10, target_at_rank(&targets, 100.0));
+
+        // Check a single target.
+        for p in PERCENTILES {
+            assert_eq!(42, target_at_rank(&[42], *p));
+        }
+    }
+
+    #[test]
+    fn test_target_quantiles() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+
+            // Ensure the quantiles match the individual percentiles.
+            let quantiles = solutions.target_quantiles(PERCENTILES)?;
+            assert_eq!(PERCENTILES.len(), quantiles.len());
+            for (p, quantile) in PERCENTILES.iter().zip_eq(&quantiles) {
+                assert_eq!(Some(*quantile), solutions.target_at_percentile(*p)?);
+            }
+            // Ensure the quantiles are non-decreasing, as the percentiles are.
+            assert!(quantiles.windows(2).all(|pair| pair[0] <= pair[1]));
+
+            // Ensure the extreme percentiles select the minimum and maximum targets.
+            let targets = solutions.as_ref().unwrap().values().map(|s| s.to_target()).collect::<Result<Vec<_>>>()?;
+            assert_eq!(targets.iter().min(), quantiles.first());
+            assert_eq!(targets.iter().max(), quantiles.last());
+
+            // Ensure no percentiles return no quantiles.
+            assert!(solutions.target_quantiles(&[])?.is_empty());
+            // Ensure an out-of-range percentile fails.
+            for p in [-0.1, 100.1, f64::NAN, f64::INFINITY] {
+                assert!(solutions.target_quantiles(&[50.0, p]).is_err());
+                assert!(solutions.target_at_percentile(p).is_err());
+            }
+        }
+
+        // Ensure empty solutions return no quantiles, and no target at each percentile.
+        let solutions = Solutions::<console::network::MainnetV0>::from(None);
+        assert!(solutions.target_quantiles(PERCENTILES)?.is_empty());
+        assert_eq!(None, solutions.target_at_percentile(50.0)?);
+        assert!(solutions.target_quantiles(&[101.0]).is_err());
+        Ok(())
+    }
+}
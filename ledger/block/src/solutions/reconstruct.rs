@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::{HashMap, HashSet};
+
+impl<N: Network> Solutions<N> {
+    /// Reconstructs the solutions from the given puzzle commitments, in the given order,
+    /// using the prover solutions from the given pool, which may be in any order and contain extra solutions.
+    ///
+    /// Each prover solution is matched to a puzzle commitment by the commitment of its partial solution,
+    /// as the commitment may not be recomputed without the coinbase puzzle proving key.
+    /// This method returns an error on the first puzzle commitment with no prover solution in the pool,
+    /// or with conflicting prover solutions in the pool. If there are no puzzle commitments,
+    /// the solutions are empty (i.e. `None`).
+    pub fn reconstruct(ids: &[PuzzleCommitment<N>], pool: &[ProverSolution<N>]) -> Result<Self> {
+        // Index the prover solutions in the pool by their puzzle commitment.
+        let mut candidates = HashMap::with_capacity(pool.len());
+        // Track the puzzle commitments with distinct prover solutions in the pool.
+        let mut conflicting = HashSet::new();
+        for solution in pool {
+            if let Some(previous) = candidates.insert(solution.commitment(), solution) {
+                if previous != solution {
+                    conflicting.insert(solution.commitment());
+                }
+            }
+        }
+        // Match each puzzle commitment to its prover solution, in order.
+        let solutions = ids
+            .iter()
+            .map(|id| {
+                // Ensure the prover solution is not ambiguous.
+                ensure!(!conflicting.contains(id), "Mismatched prover solutions for puzzle commitment '{id}'");
+                match candidates.get(id) {
+                    Some(solution) => Ok(**solution),
+                    None => bail!("Missing prover solution for puzzle commitment '{id}'"),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_prover_solutions(solutions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+    use ledger_coinbase::{PartialSolution, PuzzleProof};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_reconstruct() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let solutions = sample_solutions(rng);
+            let prover_solutions = solutions.as_ref().unwrap().values().copied().collect::<Vec<_>>();
+            let ids = solutions.solution_ids().copied().collect::<Vec<_>>();
+
+            // Ensure the solutions are reconstructed from a reordered pool, with extra solutions.
+            let mut pool = sample_solutions(rng).as_ref().unwrap().values().copied().collect::<Vec<_>>();
+            pool.extend(prover_solutions.iter().rev().copied());
+            assert_eq!(solutions, Solutions::reconstruct(&ids, &pool)?);
+
+            // Ensure the solutions are reconstructed in the given order.
+            let reversed = ids.iter().rev().copied().collect::<Vec<_>>();
+            let candidate = Solutions::reconstruct(&reversed, &pool)?;
+            assert_eq!(reversed, candidate.solution_ids().copied().collect::<Vec<_>>());
+
+            // Ensure a missing prover solution fails, naming the first missing puzzle commitment.
+            let missing = *ids.last().unwrap();
+            let pool = prover_solutions.iter().filter(|solution| solution.commitment() != missing).copied();
+            let error = Solutions::reconstruct(&ids, &pool.collect::<Vec<_>>()).unwrap_err().to_string();
+            assert!(error.contains("Missing") && error.contains(&missing.to_string()), "Unexpected error: {error}");
+
+            // Ensure a prover solution that conflicts with the one for the same puzzle commitment fails.
+            let original = prover_solutions[0];
+            let partial_solution = PartialSolution::new(original.address(), original.nonce(), original.commitment());
+            let proof = PuzzleProof::<CurrentNetwork> { w: rng.gen(), random_v: None };
+            let mut pool = prover_solutions.clone();
+            pool.push(ProverSolution::new(partial_solution, proof));
+            let error = Solutions::reconstruct(&ids, &pool).unwrap_err().to_string();
+            assert!(error.contains("Mismatched") && error.contains(&ids[0].to_string()), "Unexpected error: {error}");
+
+            // Ensure a duplicate prover solution in the pool does not conflict.
+            let mut pool = prover_solutions.clone();
+            pool.push(original);
+            assert_eq!(solutions, Solutions::reconstruct(&ids, &pool)?);
+        }
+
+        // Ensure no puzzle commitments reconstruct empty solutions.
+        let solutions = sample_solutions(rng);
+        let pool = solutions.as_ref().unwrap().values().copied().collect::<Vec<_>>();
+        assert!(Solutions::<CurrentNetwork>::reconstruct(&[], &pool)?.is_empty());
+        Ok(())
+    }
+}
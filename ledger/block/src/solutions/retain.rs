@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::types::Address;
+use std::collections::HashSet;
+
+impl<N: Network> Solutions<N> {
+    /// Retains only the prover solutions whose prover address is in the given set of allowed addresses,
+    /// preserving the order of the retained solutions, and returns the number of solutions removed.
+    ///
+    /// If no solutions are retained, the solutions become empty (i.e. `None`).
+    pub fn retain_addresses(&mut self, allowed: &HashSet<Address<N>>) -> Result<usize> {
+        let Some(solutions) = &self.solutions else {
+            return Ok(0);
+        };
+        // Collect the prover solutions from the allowed addresses.
+        let retained = solutions
+            .values()
+            .filter(|solution| allowed.contains(&solution.address()))
+            .copied()
+            .collect::<Vec<ProverSolution<N>>>();
+        // Compute the number of removed prover solutions.
+        let num_removed = solutions.len() - retained.len();
+        // Update the solutions, if any prover solutions were removed.
+        if num_removed > 0 {
+            *self = Self::from_prover_solutions(retained)?;
+        }
+        Ok(num_removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_retain_addresses() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions, where each prover solution has a distinct address.
+            let solutions = sample_solutions(rng);
+            let prover_solutions = solutions.as_ref().unwrap().values().copied().collect::<Vec<_>>();
+            let num_solutions = prover_solutions.len();
+
+            // Ensure keeping a subset of the addresses retains exactly their solutions, in order.
+            let allowed = prover_solutions.iter().step_by(2).map(|solution| solution.address()).collect::<HashSet<_>>();
+            let mut candidate = solutions.clone();
+            assert_eq!(num_solutions - allowed.len(), candidate.retain_addresses(&allowed)?);
+            let expected = prover_solutions.iter().step_by(2).copied().collect::<Vec<_>>();
+            assert_eq!(expected, candidate.as_ref().unwrap().values().copied().collect::<Vec<_>>());
+
+            // Ensure keeping none of the addresses collapses the solutions to `None`.
+            let mut candidate = solutions.clone();
+            assert_eq!(num_solutions, candidate.retain_addresses(&HashSet::new())?);
+            assert!(candidate.is_empty());
+
+            // Ensure keeping all of the addresses leaves the solutions unchanged.
+            let allowed = prover_solutions.iter().map(|solution| solution.address()).collect::<HashSet<_>>();
+            let mut candidate = solutions.clone();
+            assert_eq!(0, candidate.retain_addresses(&allowed)?);
+            assert_eq!(solutions, candidate);
+        }
+
+        // Ensure empty solutions remain empty.
+        let mut empty = Solutions::<CurrentNetwork>::from(None);
+        assert_eq!(0, empty.retain_addresses(&HashSet::new())?);
+        assert!(empty.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the reward for each prover solution, in proportion to its target, ordered by puzzle commitment.
+    ///
+    /// Each prover solution receives `floor(total * target / combined_target)`. The rounding remainder
+    /// is then distributed one unit at a time to the prover solutions with the highest targets first,
+    /// with ties broken by puzzle commitment order, so that the rewards sum exactly to `total`.
+    /// If there are no solutions, this method returns an empty map.
+    pub fn split_reward(&self, total: u64) -> Result<IndexMap<PuzzleCommitment<N>, u64>> {
+        // Retrieve the prover solutions, sorted by puzzle commitment.
+        let solutions = self.to_sorted_solutions()?;
+        // Compute the target of each prover solution.
+        let targets = solutions.iter().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+        // Compute the combined proof target.
+        let combined_target = targets.iter().try_fold(0u128, |combined, target| {
+            combined.checked_add(*target as u128).ok_or_else(|| anyhow!("Combined target overflowed"))
+        })?;
+
+        // If there are no solutions, return an empty map.
+        if solutions.is_empty() {
+            return Ok(IndexMap::new());
+        }
+        // Ensure the combined proof target is non-zero.
+        ensure!(combined_target > 0, "Cannot split the reward over solutions with a combined target of zero");
+
+        // Compute the proportional reward of each prover solution, rounding down.
+        // Note: The product cannot overflow, as `total` and `target` are each at most `u64::MAX`.
+        let mut rewards = targets
+            .iter()
+            .map(|target| u64::try_from(total as u128 * *target as u128 / combined_target).map_err(Into::into))
+            .collect::<Result<Vec<u64>>>()?;
+
+        // Compute the rounding remainder, which is strictly less than the number of solutions.
+        let distributed = rewards.iter().try_fold(0u64, |sum, reward| {
+            sum.checked_add(*reward).ok_or_else(|| anyhow!("Distributed reward overflowed"))
+        })?;
+        let remainder =
+            total.checked_sub(distributed).ok_or_else(|| anyhow!("Distributed reward exceeds the total"))?;
+
+        // Order the prover solutions by target in descending order, preserving the puzzle commitment order on ties.
+        let mut order = (0..solutions.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| targets[*b].cmp(&targets[*a]));
+        // Distribute the remainder to the prover solutions with the highest targets first.
+        for index in order.into_iter().take(remainder as usize) {
+            rewards[index] += 1;
+        }
+
+        // Return the reward for each prover solution.
+        Ok(solutions.iter().map(|solution| solution.commitment()).zip_eq(rewards).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_split_reward() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let combined_target = solutions.to_combined_proof_target()?;
+
+            for total in [0, 1, solutions.len() as u64 - 1, 1_000_003, u64::rand(rng), u64::MAX] {
+                // Split the reward.
+                let rewards = solutions.split_reward(total)?;
+                assert_eq!(solutions.len(), rewards.len());
+                // Ensure the rewards sum exactly to the total.
+                assert_eq!(total as u128, rewards.values().map(|reward| *reward as u128).sum::<u128>());
+
+                // Ensure each reward is proportional to its target, and collect the rounded-up solutions.
+                let mut rounded_up = vec![];
+                for (commitment, reward) in &rewards {
+                    let target = solutions.as_ref().unwrap().get_solution(commitment).unwrap().to_target()?;
+                    let expected = (total as u128 * target as u128 / combined_target) as u64;
+                    match *reward == expected {
+                        true => (),
+                        false => {
+                            assert_eq!(expected + 1, *reward);
+                            rounded_up.push(target);
+                        }
+                    }
+                }
+                // Ensure the remainder was distributed to the highest targets first.
+                let mut targets = solutions
+                    .as_ref()
+                    .unwrap()
+                    .values()
+                    .map(|solution| solution.to_target())
+                    .collect::<Result<Vec<_>>>()?;
+                targets.sort_unstable_by(|a, b| b.cmp(a));
+                rounded_up.sort_unstable_by(|a, b| b.cmp(a));
+                assert_eq!(targets[..rounded_up.len()], rounded_up[..]);
+            }
+        }
+
+        // Ensure empty solutions return an empty map.
+        assert!(Solutions::<console::network::MainnetV0>::from(None).split_reward(100)?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::types::Address;
+
+impl<N: Network> Solutions<N> {
+    /// Partitions the solutions into `num_shards` sets, by the hash of the prover address of each solution.
+    ///
+    /// Each prover solution is assigned to shard `hash(address) % num_shards`, where the hash is BHP256
+    /// on the bits of the address, reduced to its lower 64 bits. As such, the prover solutions from
+    /// one address are always in the same shard. Within each shard, the prover solutions are ordered
+    /// by puzzle commitment, and an empty shard is `None`.
+    ///
+    /// This method returns an error if `num_shards` is zero.
+    pub fn shard_by_address(&self, num_shards: usize) -> Result<Vec<Solutions<N>>> {
+        // Ensure there is at least one shard.
+        ensure!(num_shards > 0, "The number of shards must be greater than zero");
+
+        // Assign each prover solution to its shard.
+        let mut shards = vec![Vec::new(); num_shards];
+        for solution in self.to_sorted_solutions()? {
+            shards[shard_index(&solution.address(), num_shards)?].push(solution);
+        }
+        // Return the shards.
+        shards.into_iter().map(Self::from_prover_solutions).collect()
+    }
+}
+
+/// Returns the shard of the given address, as `hash(address) % num_shards`.
+fn shard_index<N: Network>(address: &Address<N>, num_shards: usize) -> Result<usize> {
+    // Hash the address, and take the lower 64 bits of the hash.
+    let hash = N::hash_bhp256(&address.to_bits_le())?.to_bytes_le()?;
+    let hash = u64::from_le_bytes(hash[..8].try_into()?);
+    // Reduce the hash to the number of shards.
+    Ok((hash % num_shards as u64) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_shard_by_address() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+
+            for num_shards in [1, 2, 3, 7] {
+                // Shard the solutions.
+                let shards = solutions.shard_by_address(num_shards)?;
+                assert_eq!(num_shards, shards.len());
+
+                // Ensure every prover solution lands in exactly one shard, which is the shard of its address.
+                for solution_id in solutions.solution_ids() {
+                    let solution = solutions.as_ref().unwrap().get(solution_id).unwrap();
+                    let expected = shard_index(&solution.address(), num_shards)?;
+                    for (index, shard) in shards.iter().enumerate() {
+                        let is_in_shard = shard.as_ref().is_some_and(|shard| shard.contains_key(solution_id));
+                        assert_eq!(index == expected, is_in_shard);
+                    }
+                }
+                assert_eq!(solutions.len(), shards.iter().map(|shard| shard.len()).sum::<usize>());
+
+                // Ensure recombining the shards reproduces the original solutions.
+                let mut merged = Solutions::from(None);
+                for shard in &shards {
+                    merged = merged.merge(shard)?;
+                }
+                assert_eq!(solutions, merged);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_shard_by_address_empty() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure zero shards fails.
+        assert!(sample_solutions(rng).shard_by_address(0).is_err());
+        assert!(Solutions::<CurrentNetwork>::from(None).shard_by_address(0).is_err());
+
+        // Ensure sharding empty solutions returns empty shards.
+        let shards = Solutions::<CurrentNetwork>::from(None).shard_by_address(3)?;
+        assert_eq!(3, shards.len());
+        assert!(shards.iter().all(|shard| shard.is_empty()));
+        Ok(())
+    }
+}
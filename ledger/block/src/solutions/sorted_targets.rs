@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the target of each prover solution, sorted in ascending order.
+    ///
+    /// The sorted targets are independent of the order of the solutions, and are empty if there are no solutions.
+    pub fn sorted_targets(&self) -> Result<Vec<u64>> {
+        let mut targets = match &self.solutions {
+            Some(solutions) => solutions.values().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?,
+            None => vec![],
+        };
+        targets.sort_unstable();
+        Ok(targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_sorted_targets() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let targets = solutions.sorted_targets()?;
+
+            // Ensure there is one target per solution, in ascending order.
+            assert_eq!(solutions.len(), targets.len());
+            assert!(targets.windows(2).all(|pair| pair[0] <= pair[1]));
+
+            // Ensure the targets match the targets of the solutions.
+            let mut expected =
+                solutions.as_ref().unwrap().values().map(|s| s.to_target()).collect::<Result<Vec<_>>>()?;
+            expected.sort_unstable();
+            assert_eq!(expected, targets);
+
+            // Ensure the sum of the targets is the combined proof target.
+            let sum = targets.iter().map(|target| *target as u128).sum::<u128>();
+            assert_eq!(solutions.to_combined_proof_target()?, sum);
+
+            // Ensure the targets are independent of the order of the solutions.
+            let mut reversed = solutions.to_sorted_solutions()?;
+            reversed.reverse();
+            assert_eq!(targets, Solutions::from_prover_solutions(reversed)?.sorted_targets()?);
+        }
+
+        // Ensure empty solutions have no targets.
+        let solutions = Solutions::<console::network::MainnetV0>::from(None);
+        assert!(solutions.sorted_targets()?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Splits the solutions into two sets, ordered by puzzle commitment.
+    ///
+    /// The first `n` prover solutions (in sorted puzzle commitment order) are returned on the left,
+    /// and the remaining prover solutions are returned on the right. An empty side is `None`.
+    /// If `n` is greater than or equal to the number of solutions, all solutions are returned on the left.
+    pub fn split_at_count(&self, n: usize) -> Result<(Solutions<N>, Solutions<N>)> {
+        // Retrieve the sorted prover solutions.
+        let mut left = self.to_sorted_solutions()?;
+        // Split the prover solutions at the given count.
+        let right = left.split_off(n.min(left.len()));
+        // Return the split solutions.
+        Ok((Self::from_prover_solutions(left)?, Self::from_prover_solutions(right)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_split_at_count() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let num_solutions = solutions.len();
+            // Compute the expected order of the puzzle commitments.
+            let expected = solutions.to_sorted_solutions()?.iter().map(|s| s.commitment()).collect::<Vec<_>>();
+
+            for n in 0..=num_solutions + 1 {
+                // Split the solutions.
+                let (left, right) = solutions.split_at_count(n)?;
+                assert_eq!(n.min(num_solutions), left.len());
+                assert_eq!(num_solutions.saturating_sub(n), right.len());
+                // Ensure an empty side is `None`.
+                assert_eq!(n == 0 || num_solutions == 0, left.is_empty());
+                assert_eq!(n >= num_solutions, right.is_empty());
+                // Ensure the split point follows the sorted order.
+                let candidate = left.solution_ids().chain(right.solution_ids()).copied().collect::<Vec<_>>();
+                assert_eq!(expected, candidate);
+                // Ensure recombining the split reproduces the original solutions.
+                assert_eq!(solutions, left.merge(&right)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_at_count_empty() -> Result<()> {
+        // Split empty solutions.
+        let (left, right) = Solutions::<console::network::MainnetV0>::from(None).split_at_count(0)?;
+        assert!(left.is_empty());
+        assert!(right.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the empirical cumulative distribution of the targets of the prover solutions,
+    /// as `(target, fraction)` pairs sorted by target, where `fraction` is the fraction of solutions
+    /// whose target is at most `target`.
+    ///
+    /// Duplicate targets share a single point, and the final fraction is exactly `1.0`.
+    /// This method returns an empty list if there are no solutions.
+    pub fn target_cdf(&self) -> Result<Vec<(u64, f64)>> {
+        Ok(cdf_from_sorted_targets(&self.sorted_targets()?))
+    }
+}
+
+/// Returns the empirical cumulative distribution of the given targets, which are sorted in ascending order.
+fn cdf_from_sorted_targets(targets: &[u64]) -> Vec<(u64, f64)> {
+    let mut points: Vec<(u64, f64)> = Vec::new();
+    for (index, target) in targets.iter().enumerate() {
+        // Note: The final fraction is `len / len`, which is exactly `1.0`.
+        let fraction = (index + 1) as f64 / targets.len() as f64;
+        // Combine duplicate targets into the point of their last occurrence.
+        match points.last_mut() {
+            Some((previous, previous_fraction)) if previous == target => *previous_fraction = fraction,
+            _ => points.push((*target, fraction)),
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_target_cdf() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample random solutions.
+            let solutions = sample_solutions(rng);
+            let cdf = solutions.target_cdf()?;
+            let targets = solutions.sorted_targets()?;
+
+            // Ensure the targets are strictly increasing, and the fractions are increasing up to exactly `1.0`.
+            assert!(cdf.windows(2).all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1));
+            assert_eq!(Some(1.0), cdf.last().map(|(_, fraction)| *fraction));
+            // Ensure each fraction is the fraction of targets at most the point.
+            for (target, fraction) in &cdf {
+                let count = targets.iter().filter(|candidate| *candidate <= target).count();
+                assert_eq!(count as f64 / targets.len() as f64, *fraction);
+            }
+        }
+
+        // Ensure empty solutions have no points.
+        let solutions = Solutions::<console::network::MainnetV0>::from(None);
+        assert!(solutions.target_cdf()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cdf_from_sorted_targets() {
+        // Ensure a known distribution yields the expected points, combining the duplicate targets.
+        let cdf = cdf_from_sorted_targets(&[1, 2, 2, 3, 5, 5, 5, 8]);
+        assert_eq!(vec![(1, 0.125), (2, 0.375), (3, 0.5), (5, 0.875), (8, 1.0)], cdf);
+
+        // Ensure the final fraction is exactly `1.0`, even if the fractions are not exactly representable.
+        let cdf = cdf_from_sorted_targets(&[4, 7, 9]);
+        assert_eq!(vec![(4, 1.0 / 3.0), (7, 2.0 / 3.0), (9, 1.0)], cdf);
+        // Ensure a single repeated target yields a single point.
+        assert_eq!(vec![(6, 1.0)], cdf_from_sorted_targets(&[6, 6, 6]));
+        // Ensure no targets yield no points.
+        assert!(cdf_from_sorted_targets(&[]).is_empty());
+    }
+}
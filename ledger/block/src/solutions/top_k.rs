@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// Returns the combined proof target of the `k` prover solutions with the highest individual targets.
+    ///
+    /// Ties between equal targets are broken by puzzle commitment order.
+    /// This method returns `0` if `k == 0`, and the combined proof target of all solutions if `k >= len()`.
+    pub fn combined_target_of_top(&self, k: usize) -> Result<u128> {
+        // Retrieve the prover solutions, sorted by puzzle commitment.
+        let solutions = self.to_sorted_solutions()?;
+        // Compute the target of each prover solution.
+        let mut targets = solutions.iter().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+        // Sort the targets in descending order, preserving the puzzle commitment order on ties.
+        targets.sort_by(|a, b| b.cmp(a));
+        // Compute the combined proof target of the top `k` targets.
+        targets.into_iter().take(k).try_fold(0u128, |combined, target| {
+            combined.checked_add(target as u128).ok_or_else(|| anyhow!("Combined target overflowed"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    #[test]
+    fn test_combined_target_of_top() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample random solutions.
+        let solutions = sample_solutions(rng);
+        let num_solutions = solutions.len();
+
+        // Compute the expected targets, in descending order.
+        let mut targets =
+            solutions.as_ref().unwrap().values().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+        targets.sort_unstable();
+        targets.reverse();
+
+        // Check the combined target for several values of `k`.
+        assert_eq!(0, solutions.combined_target_of_top(0)?);
+        assert_eq!(targets[0] as u128, solutions.combined_target_of_top(1)?);
+        for k in 0..=num_solutions {
+            let expected = targets[..k].iter().map(|target| *target as u128).sum::<u128>();
+            assert_eq!(expected, solutions.combined_target_of_top(k)?);
+        }
+        // Ensure `k >= len()` returns the combined proof target of all solutions.
+        let combined = solutions.to_combined_proof_target()?;
+        assert_eq!(combined, solutions.combined_target_of_top(num_solutions)?);
+        assert_eq!(combined, solutions.combined_target_of_top(num_solutions + 1)?);
+        assert_eq!(combined, solutions.combined_target_of_top(usize::MAX)?);
+
+        // Ensure empty solutions have a combined target of zero.
+        let empty = Solutions::<console::network::MainnetV0>::from(None);
+        assert_eq!(0, empty.combined_target_of_top(0)?);
+        assert_eq!(0, empty.combined_target_of_top(1)?);
+        Ok(())
+    }
+}
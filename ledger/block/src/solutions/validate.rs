@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashSet;
+
+impl<N: Network> Solutions<N> {
+    /// Validates the solutions, and reorders the prover solutions by puzzle commitment.
+    ///
+    /// This method fails on the first violation of the following invariants:
+    ///   1. The number of solutions does not exceed `N::MAX_SOLUTIONS`.
+    ///   2. Each puzzle commitment matches the commitment of its prover solution.
+    ///   3. The puzzle commitments are unique.
+    ///
+    /// On failure, the solutions are left unchanged.
+    pub fn validate_and_canonicalize(&mut self) -> Result<()> {
+        if let Some(solutions) = &self.solutions {
+            // Ensure the solutions are well-formed.
+            check_solutions::<N>(solutions.len(), solutions.iter())?;
+            // Reorder the prover solutions by puzzle commitment.
+            *self = Self::from_prover_solutions(self.to_sorted_solutions()?)?;
+        }
+        Ok(())
+    }
+}
+
+/// Ensures the given `(puzzle commitment, prover solution)` pairs are well-formed.
+fn check_solutions<'a, N: Network>(
+    num_solutions: usize,
+    solutions: impl Iterator<Item = (&'a PuzzleCommitment<N>, &'a ProverSolution<N>)>,
+) -> Result<()> {
+    // Ensure the number of solutions does not exceed `N::MAX_SOLUTIONS`.
+    ensure!(
+        num_solutions <= N::MAX_SOLUTIONS,
+        "The solutions exceed the allowed number of solutions ({num_solutions} > {})",
+        N::MAX_SOLUTIONS
+    );
+    // Initialize a set to track the puzzle commitments.
+    let mut seen = HashSet::with_capacity(num_solutions);
+    for (index, (puzzle_commitment, solution)) in solutions.enumerate() {
+        // Ensure the puzzle commitment matches the commitment of the prover solution.
+        ensure!(
+            *puzzle_commitment == solution.commitment(),
+            "Solution {index} is keyed by '{puzzle_commitment}', but commits to '{}'",
+            solution.commitment()
+        );
+        // Ensure the puzzle commitment is unique.
+        ensure!(
+            seen.insert(puzzle_commitment),
+            "Solution {index} contains a duplicate puzzle commitment '{puzzle_commitment}'"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+    use console::account::{Address, PrivateKey};
+    use ledger_coinbase::{PartialSolution, PuzzleProof};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Samples the given number of prover solutions for a single address.
+    pub(crate) fn sample_prover_solutions(
+        num_solutions: usize,
+        rng: &mut TestRng,
+    ) -> Vec<ProverSolution<CurrentNetwork>> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+
+        (0..num_solutions)
+            .map(|_| {
+                let commitment = PuzzleCommitment::from_g1_affine(rng.gen());
+                let partial_solution = PartialSolution::new(address, u64::rand(rng), commitment);
+                let proof = PuzzleProof::<CurrentNetwork> { w: rng.gen(), random_v: None };
+                ProverSolution::new(partial_solution, proof)
+            })
+            .collect()
+    }
+
+    /// Returns the `(puzzle commitment, prover solution)` pairs for the given prover solutions.
+    fn to_pairs(
+        solutions: &[ProverSolution<CurrentNetwork>],
+    ) -> Vec<(PuzzleCommitment<CurrentNetwork>, ProverSolution<CurrentNetwork>)> {
+        solutions.iter().map(|solution| (solution.commitment(), *solution)).collect()
+    }
+
+    /// Checks the given `(puzzle commitment, prover solution)` pairs.
+    fn check_pairs(pairs: &[(PuzzleCommitment<CurrentNetwork>, ProverSolution<CurrentNetwork>)]) -> Result<()> {
+        let pairs = pairs.iter().map(|(commitment, solution)| (commitment, solution));
+        check_solutions::<CurrentNetwork>(pairs.len(), pairs)
+    }
+
+    #[test]
+    fn test_validate_and_canonicalize() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample random solutions.
+        let expected = sample_solutions(rng);
+        let mut candidate = expected.clone();
+
+        // Validate and canonicalize the solutions.
+        candidate.validate_and_canonicalize()?;
+        assert_eq!(expected, candidate);
+        // Ensure the solutions are sorted by puzzle commitment.
+        let sorted = expected.to_sorted_solutions()?.iter().map(|s| s.commitment()).collect::<Vec<_>>();
+        assert_eq!(sorted, candidate.solution_ids().copied().collect::<Vec<_>>());
+
+        // Ensure canonicalization is idempotent.
+        let mut again = candidate.clone();
+        again.validate_and_canonicalize()?;
+        assert_eq!(candidate.solution_ids().collect::<Vec<_>>(), again.solution_ids().collect::<Vec<_>>());
+
+        // Ensure empty solutions remain empty.
+        let mut empty = Solutions::<CurrentNetwork>::from(None);
+        empty.validate_and_canonicalize()?;
+        assert!(empty.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_solutions_exceeds_max() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure the maximum number of solutions is accepted.
+        let solutions = sample_prover_solutions(CurrentNetwork::MAX_SOLUTIONS + 1, rng);
+        check_pairs(&to_pairs(&solutions[..CurrentNetwork::MAX_SOLUTIONS]))?;
+        // Ensure exceeding the maximum number of solutions fails.
+        assert!(check_pairs(&to_pairs(&solutions)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_solutions_mismatched_commitment() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Key a prover solution by the puzzle commitment of another prover solution.
+        let solutions = sample_prover_solutions(3, rng);
+        let mut pairs = to_pairs(&solutions);
+        check_pairs(&pairs)?;
+        pairs[1].0 = solutions[2].commitment();
+        assert!(check_pairs(&pairs).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_solutions_duplicate_commitment() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Repeat a prover solution.
+        let solutions = sample_prover_solutions(3, rng);
+        let mut pairs = to_pairs(&solutions);
+        check_pairs(&pairs)?;
+        pairs.push(pairs[0]);
+        assert!(check_pairs(&pairs).is_err());
+        Ok(())
+    }
+}
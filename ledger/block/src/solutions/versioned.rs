@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Solutions<N> {
+    /// The latest format version of the versioned byte representation.
+    const LATEST_FORMAT_VERSION: u8 = 1;
+
+    /// Returns the versioned byte representation of the solutions.
+    ///
+    /// The byte representation is prefixed with a one-byte format version, so that nodes
+    /// on different formats detect a mismatch instead of misparsing the solutions.
+    pub fn to_bytes_versioned(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![Self::LATEST_FORMAT_VERSION];
+        self.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the solutions from the given versioned byte representation.
+    ///
+    /// This method errors if the format version is unknown, or if any bytes remain after parsing.
+    pub fn from_bytes_versioned(bytes: &[u8]) -> Result<Self> {
+        // Split the format version from the remaining bytes.
+        let Some((format_version, mut reader)) = bytes.split_first() else {
+            bail!("Missing the format version of the solutions")
+        };
+        // Parse the solutions in the matching format.
+        let solutions = match *format_version {
+            1 => Self::read_le(&mut reader)?,
+            _ => bail!("Unknown format version for the solutions ({format_version})"),
+        };
+        // Ensure all of the bytes were consumed.
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the solutions", reader.len());
+        Ok(solutions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::serialize::tests::sample_solutions;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_bytes_versioned() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [sample_solutions(rng), Solutions::<CurrentNetwork>::from(None)] {
+            // Ensure the versioned bytes are the unversioned bytes, prefixed with the format version.
+            let bytes = expected.to_bytes_versioned()?;
+            assert_eq!(1, bytes[0]);
+            assert_eq!(expected.to_bytes_le()?, bytes[1..]);
+
+            // Check the round trip.
+            assert_eq!(expected, Solutions::from_bytes_versioned(&bytes)?);
+
+            // Ensure trailing bytes are rejected.
+            let mut trailing = bytes.clone();
+            trailing.push(0);
+            assert!(Solutions::<CurrentNetwork>::from_bytes_versioned(&trailing).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_versioned_rejects_unknown_version() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure every format version, other than the current one, is rejected.
+        let bytes = sample_solutions(rng).to_bytes_versioned()?;
+        for format_version in (0..=u8::MAX).filter(|version| *version != 1) {
+            let mut candidate = bytes.clone();
+            candidate[0] = format_version;
+            assert!(Solutions::<CurrentNetwork>::from_bytes_versioned(&candidate).is_err());
+        }
+
+        // Ensure a missing format version is rejected.
+        assert!(Solutions::<CurrentNetwork>::from_bytes_versioned(&[]).is_err());
+        Ok(())
+    }
+}
@@ -96,3 +96,35 @@ impl<N: Network> Deref for CoinbaseSolution<N> {
         &self.solutions
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_new_with_max_solutions() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample one more prover solution than the maximum number of solutions.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let prover_solutions = (0..CurrentNetwork::MAX_SOLUTIONS + 1)
+            .map(|_| {
+                let partial_solution = PartialSolution::new(address, u64::rand(rng), KZGCommitment(rng.gen()));
+                ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v: None })
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the maximum number of solutions is allowed.
+        let solutions = CoinbaseSolution::new(prover_solutions[..CurrentNetwork::MAX_SOLUTIONS].to_vec())?;
+        assert_eq!(CurrentNetwork::MAX_SOLUTIONS, solutions.len());
+
+        // Ensure one solution over the maximum number of solutions fails.
+        let error = CoinbaseSolution::new(prover_solutions).unwrap_err().to_string();
+        let expected = format!("({} > {})", CurrentNetwork::MAX_SOLUTIONS + 1, CurrentNetwork::MAX_SOLUTIONS);
+        assert!(error.contains(&expected), "Unexpected error: {error}");
+        Ok(())
+    }
+}